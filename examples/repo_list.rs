@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
 use authentic::credential::TokenCredential;
-use hyper::body::HttpBody as _;
-use hyper::StatusCode;
+use futures_util::{pin_mut, TryStreamExt};
 use hyper_tls::HttpsConnector;
 use jinxapi_github::v1_1_4 as github_api;
-use serde_json::Value;
+use github_api::schema::MinimalRepository;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
@@ -25,43 +24,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
 
     let github = github_api::hyper::Caller::new(hyper_client, config, tokio::time::sleep);
 
-    for page in 1.. {
-        let mut response = github
-            .repos_list_for_user(
-                &owner,
-                None,
-                &jinxapi_github::types::Sort::Default,
-                None,
-                Some(page),
-            )
-            .await?;
-
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let mut output: Vec<u8> = Vec::new();
-        while let Some(chunk) = response.body_mut().data().await {
-            let bytes = chunk?;
-            output.extend(&bytes[..]);
-        }
-        let v: Value = serde_json::from_slice(&output)?;
-        let repos = v.as_array().unwrap();
-        if repos.is_empty() {
-            break;
+    let repos = github
+        .paginate(github.repos_list_for_user(
+            &owner,
+            None,
+            &jinxapi_github::types::Sort::Default,
+            Some(100),
+            None,
+        ))
+        .items::<MinimalRepository>();
+    pin_mut!(repos);
+
+    while let Some(repo) = repos.try_next().await? {
+        print!("{}", repo.full_name);
+        if repo.fork {
+            print!(" (fork)");
         }
-        for repo in repos {
-            if let Some(fork) = repo.get("fork") {
-                let full_name = repo.get("full_name").unwrap().as_str().unwrap();
-                print!("{}", full_name);
-                if fork.as_bool().unwrap() {
-                    print!(" (fork)");
-                }
-                let description = repo.get("description").unwrap().as_str().unwrap_or("");
-                if description.is_empty() {
-                    println!();
-                } else {
-                    println!(": {}", description);
-                }
-            }
+        match repo.description.as_deref() {
+            None | Some("") => println!(),
+            Some(description) => println!(": {}", description),
         }
     }
 
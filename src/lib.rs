@@ -21,6 +21,10 @@
 //! jinxapi-github = { version = "0.1", default-features = false, features = ["reqwest-blocking"] }
 //! ```
 //!
+//! Each of these is a thin [`v1_1_4::transport::Transport`] used by the generic
+//! [`v1_1_4::caller::Caller`]. Another HTTP client, or a test double, can be used by
+//! implementing `Transport` for it.
+//!
 //! # Using the API
 //!
 //! Broadly, the steps to use the API are:
@@ -30,7 +34,14 @@
 //! for sleeping (e.g. `std::thread::sleep` or `tokio::time::sleep`).
 //! - For requests with a body, create the body type.
 //! - Call a method on the `Caller`, passing in operation parameters and, if required, a body.
-//! - Handle the response returned from the method.
+//!   The blocking `Caller` calls the method using `call`, e.g.
+//!   `github.call(|github| github.repos_get(owner, repo))`.
+//! - Handle the response returned from the method. For the operations used by the helpers of this
+//!   crate and other common operations, the `response` module for the operation (e.g.
+//!   [`v1_1_4::request::repos_list_for_user::response`]) decodes the status code and body into a typed
+//!   `Response` enum. Other operations have no `response` module yet, and their body is decoded by the
+//!   caller, e.g. using `serde_json`. For list operations, the `Caller`'s `paginate` method follows the
+//!   `Link` header to return the items from every page.
 //!
 //! See the [test](https://github.com/jinxapi/github-rs/tree/main/tests) and [example](https://github.com/jinxapi/github-rs/tree/main/examples) code for examples of these steps.
 
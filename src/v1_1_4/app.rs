@@ -0,0 +1,117 @@
+//! Acting as a GitHub App across many installations.
+//!
+//! An app installed on many accounts usually knows the owner or repository that an event
+//! refers to, rather than the ID of the installation. The `AppClient` of each client looks
+//! up the installation for a repository, organization or user, using the app JWT, and
+//! returns a `Caller` authenticated as that installation:
+//!
+//! ```ignore
+//! let app = AppClient::new(client, config, tokio::time::sleep, app_id, &private_key_pem)?;
+//! let github = app.for_repo("octocat", "hello-world").await?;
+//! let response = github.issues_create("octocat", "hello-world", &issue).await?;
+//! ```
+//!
+//! Installation IDs and installation access tokens are cached by the `AppClient`, so that
+//! each installation is looked up once and its token is shared by every `Caller` for that
+//! installation.
+
+use ::std::collections::HashMap;
+use ::std::sync::{Arc, Mutex};
+
+use ::authentic::credential::JsonWebTokenCredential;
+
+use crate::v1_1_4::config::{Authentication, Configuration};
+use crate::v1_1_4::installation::{app_credential, InstallationCredential};
+use crate::v1_1_4::ApiError;
+
+/// Account that an app installation is looked up for. GitHub account and repository names
+/// are case-insensitive, so names are stored in lowercase.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Account {
+    Repo(String, String),
+    Org(String),
+    User(String),
+}
+
+impl Account {
+    pub(crate) fn repo(owner: &str, repo: &str) -> Self {
+        Account::Repo(owner.to_lowercase(), repo.to_lowercase())
+    }
+
+    pub(crate) fn org(org: &str) -> Self {
+        Account::Org(org.to_lowercase())
+    }
+
+    pub(crate) fn user(username: &str) -> Self {
+        Account::User(username.to_lowercase())
+    }
+}
+
+#[derive(::serde::Deserialize)]
+struct InstallationId {
+    id: i64,
+}
+
+/// Installation IDs and credentials of an app.
+pub(crate) struct Installations {
+    app: Arc<JsonWebTokenCredential>,
+    ids: Mutex<HashMap<Account, i64>>,
+    credentials: Mutex<HashMap<i64, Arc<InstallationCredential>>>,
+}
+
+impl Installations {
+    pub(crate) fn new(app_id: i64, private_key_pem: &[u8]) -> Result<Self, ApiError> {
+        Ok(Self {
+            app: Arc::new(app_credential(app_id, private_key_pem)?),
+            ids: Mutex::new(HashMap::new()),
+            credentials: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Configuration authenticating as the app, based on `config`.
+    pub(crate) fn app_config(&self, config: Configuration) -> Configuration {
+        Configuration {
+            authentication: Authentication::JWT(self.app.clone()),
+            ..config
+        }
+    }
+
+    /// Configuration authenticating as installation `installation_id`, based on `config`.
+    pub(crate) fn installation_config(&self, config: &Configuration, installation_id: i64) -> Configuration {
+        Configuration {
+            authentication: Authentication::Installation(self.credential(installation_id)),
+            ..config.clone()
+        }
+    }
+
+    fn credential(&self, installation_id: i64) -> Arc<InstallationCredential> {
+        let mut credentials = self.credentials.lock().unwrap_or_else(|poison| poison.into_inner());
+        credentials
+            .entry(installation_id)
+            .or_insert_with(|| Arc::new(InstallationCredential::from_app(self.app.clone(), installation_id)))
+            .clone()
+    }
+
+    pub(crate) fn cached(&self, account: &Account) -> Option<i64> {
+        self.ids.lock().ok()?.get(account).copied()
+    }
+
+    /// Decode and cache the installation returned from a successful lookup for `account`.
+    pub(crate) fn found(&self, account: Account, body: &[u8]) -> Result<i64, ApiError> {
+        let InstallationId { id } = ::serde_json::from_slice(body)?;
+        if let Ok(mut ids) = self.ids.lock() {
+            ids.insert(account, id);
+        }
+        Ok(id)
+    }
+
+    /// Forget the ID and token of an installation, e.g. after the app is uninstalled.
+    pub(crate) fn forget(&self, installation_id: i64) {
+        if let Ok(mut ids) = self.ids.lock() {
+            ids.retain(|_, id| *id != installation_id);
+        }
+        if let Ok(mut credentials) = self.credentials.lock() {
+            credentials.remove(&installation_id);
+        }
+    }
+}
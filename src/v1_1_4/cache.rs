@@ -0,0 +1,264 @@
+//! Caching responses using conditional requests.
+//!
+//! GitHub returns an `ETag` or `Last-Modified` header with most responses. Sending the
+//! value back in an `If-None-Match` or `If-Modified-Since` header returns `304 Not Modified`
+//! if the resource has not changed, and does not count against the primary rate limit.
+//!
+//! Set [`Configuration::cache`](crate::v1_1_4::config::Configuration::cache) to a
+//! [`ResponseCache`] to store successful `GET` responses that have a validator. Subsequent
+//! `GET` requests for the same URL send the validator, and a `304 Not Modified` response is
+//! replaced with the cached `200 OK` response, with any headers sent with the `304` response.
+//!
+//! ```ignore
+//! let config = Configuration {
+//!     cache: Some(Arc::new(LruCache::new(1000))),
+//!     ..Default::default()
+//! };
+//! ```
+//!
+//! Responses are cached separately for each `Accept` header and each credential. Keys
+//! include a hash of the `Authorization` header, rather than the header itself.
+
+use ::std::collections::{BTreeMap, HashMap};
+use ::std::io::{self, BufRead, Read, Write};
+use ::std::path::PathBuf;
+use ::std::sync::atomic::{AtomicU64, Ordering};
+use ::std::sync::Mutex;
+
+use ::http::header::{HeaderMap, HeaderName, HeaderValue};
+use ::sha2::{Digest, Sha256};
+
+/// Successful response stored in a [`ResponseCache`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl CachedResponse {
+    /// Headers of the cached response, updated with the headers of a `304 Not Modified`
+    /// response.
+    pub(crate) fn refresh(self, not_modified: &HeaderMap) -> (HeaderMap, Vec<u8>) {
+        let mut headers = self.headers;
+        for name in not_modified.keys() {
+            if name != ::http::header::CONTENT_LENGTH && name != ::http::header::TRANSFER_ENCODING {
+                headers.remove(name);
+                for value in not_modified.get_all(name) {
+                    headers.append(name.clone(), value.clone());
+                }
+            }
+        }
+        (headers, self.body)
+    }
+}
+
+/// Storage for responses to `GET` requests.
+///
+/// Implementations should handle their own errors, e.g. by logging them, as a failure
+/// to cache a response does not affect the request.
+pub trait ResponseCache: Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// Cache lookup for a request.
+pub(crate) struct Lookup {
+    pub(crate) key: String,
+    pub(crate) cached: Option<CachedResponse>,
+}
+
+/// Look up a `GET` request in the cache, adding validators to the request headers if a
+/// response is cached.
+///
+/// Returns `None` if the request cannot be cached.
+pub(crate) fn lookup(
+    cache: &dyn ResponseCache,
+    method: &::http::Method,
+    url: &str,
+    headers: &mut HeaderMap,
+) -> Option<Lookup> {
+    if method != ::http::Method::GET
+        || headers.contains_key(::http::header::IF_NONE_MATCH)
+        || headers.contains_key(::http::header::IF_MODIFIED_SINCE)
+    {
+        return None;
+    }
+    let key = cache_key(url, headers);
+    let cached = cache.get(&key);
+    if let Some(cached) = &cached {
+        if let Some(etag) = cached.headers.get(::http::header::ETAG) {
+            headers.insert(::http::header::IF_NONE_MATCH, etag.clone());
+        } else if let Some(last_modified) = cached.headers.get(::http::header::LAST_MODIFIED) {
+            headers.insert(::http::header::IF_MODIFIED_SINCE, last_modified.clone());
+        }
+    }
+    Some(Lookup { key, cached })
+}
+
+/// Can a response be cached?
+pub(crate) fn is_cacheable(status: ::http::StatusCode, headers: &HeaderMap) -> bool {
+    status == ::http::StatusCode::OK
+        && (headers.contains_key(::http::header::ETAG) || headers.contains_key(::http::header::LAST_MODIFIED))
+}
+
+fn cache_key(url: &str, headers: &HeaderMap) -> String {
+    let accept = headers
+        .get(::http::header::ACCEPT)
+        .map(HeaderValue::as_bytes)
+        .unwrap_or_default();
+    let authorization = headers
+        .get(::http::header::AUTHORIZATION)
+        .map(|authorization| ::hex::encode(Sha256::digest(authorization.as_bytes())))
+        .unwrap_or_default();
+    format!("{} {} {}", url, String::from_utf8_lossy(accept), authorization)
+}
+
+/// In-memory cache, discarding the least recently used response when full.
+pub struct LruCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    tick: u64,
+    entries: HashMap<String, (u64, CachedResponse)>,
+    order: BTreeMap<u64, String>,
+}
+
+impl LruCache {
+    /// Create a cache holding up to `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+}
+
+impl ResponseCache for LruCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut state = self.state.lock().ok()?;
+        state.tick += 1;
+        let tick = state.tick;
+        let LruState { entries, order, .. } = &mut *state;
+        let (used, response) = entries.get_mut(key)?;
+        order.remove(used);
+        order.insert(tick, key.to_owned());
+        *used = tick;
+        Some(response.clone())
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Ok(mut state) = self.state.lock() {
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some((used, _)) = state.entries.insert(key.to_owned(), (tick, response)) {
+                state.order.remove(&used);
+            }
+            state.order.insert(tick, key.to_owned());
+            while state.entries.len() > self.capacity {
+                match state.order.pop_first() {
+                    Some((_, oldest)) => state.entries.remove(&oldest),
+                    None => break,
+                };
+            }
+        }
+    }
+}
+
+/// Counter distinguishing temporary files written concurrently by [`DiskCache`].
+static TEMPORARY: AtomicU64 = AtomicU64::new(0);
+
+/// Cache storing each response in a file in a directory.
+///
+/// Files are named using a hash of the cache key, and the directory is created if
+/// required. Entries are never removed, so the directory should be cleared periodically.
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(::hex::encode(Sha256::digest(key.as_bytes())))
+    }
+
+    fn read(&self, key: &str) -> io::Result<Option<CachedResponse>> {
+        let mut file = match ::std::fs::File::open(self.path(key)) {
+            Ok(file) => io::BufReader::new(file),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Invalid cache entry");
+        let mut line = Vec::new();
+        file.read_until(b'\n', &mut line)?;
+        if line.strip_suffix(b"\n") != Some(key.as_bytes()) {
+            // A different key with the same hash.
+            return Ok(None);
+        }
+        let mut headers = HeaderMap::new();
+        loop {
+            line.clear();
+            file.read_until(b'\n', &mut line)?;
+            let header = line.strip_suffix(b"\n").ok_or_else(invalid)?;
+            if header.is_empty() {
+                break;
+            }
+            let colon = header.iter().position(|&b| b == b':').ok_or_else(invalid)?;
+            let name = HeaderName::from_bytes(&header[..colon]).map_err(|_| invalid())?;
+            let value = HeaderValue::from_bytes(&header[colon + 1..]).map_err(|_| invalid())?;
+            headers.append(name, value);
+        }
+        let mut body = Vec::new();
+        file.read_to_end(&mut body)?;
+        Ok(Some(CachedResponse { headers, body }))
+    }
+
+    fn write(&self, key: &str, response: &CachedResponse) -> io::Result<()> {
+        ::std::fs::create_dir_all(&self.directory)?;
+        let path = self.path(key);
+        // Write to a temporary file and rename it, so that readers never see a partial entry.
+        let temporary = path.with_extension(format!(
+            "{}-{}.tmp",
+            ::std::process::id(),
+            TEMPORARY.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = io::BufWriter::new(::std::fs::File::create(&temporary)?);
+        file.write_all(key.as_bytes())?;
+        file.write_all(b"\n")?;
+        for (name, value) in &response.headers {
+            file.write_all(name.as_str().as_bytes())?;
+            file.write_all(b":")?;
+            file.write_all(value.as_bytes())?;
+            file.write_all(b"\n")?;
+        }
+        file.write_all(b"\n")?;
+        file.write_all(&response.body)?;
+        file.into_inner()?.sync_all()?;
+        ::std::fs::rename(&temporary, &path)
+    }
+}
+
+impl ResponseCache for DiskCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.read(key).unwrap_or_else(|err| {
+            ::log::warn!("Failed to read cached response: {}", err);
+            None
+        })
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if let Err(err) = self.write(key, &response) {
+            ::log::warn!("Failed to cache response: {}", err);
+        }
+    }
+}
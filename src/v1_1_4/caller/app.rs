@@ -0,0 +1,105 @@
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use crate::v1_1_4::app::{Account, Installations};
+use crate::v1_1_4::config::Configuration;
+use crate::v1_1_4::rate_limit::RateLimitPolicy;
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+/// Client for a GitHub App, returning a [`Caller`] for each installation of the app.
+///
+/// Installation IDs and installation access tokens are cached, so that a long-running
+/// service can act across many installations. See [`crate::v1_1_4::app`].
+pub struct AppClient<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport + Clone,
+    Sleep: Fn(Duration) -> SleepFut + Clone,
+    SleepFut: Future<Output = SleepOut>
+{
+    app: Caller<T, Sleep, SleepFut, SleepOut>,
+    installations: Installations,
+}
+
+impl<T, Sleep, SleepFut, SleepOut> AppClient<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport + Clone,
+    Sleep: Fn(Duration) -> SleepFut + Clone,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Create a client for the app `app_id`, using the app's RSA private key in PEM format.
+    ///
+    /// The `authentication` field of `config` is ignored. Other fields are used for every
+    /// `Caller`.
+    pub fn new(
+        client: T,
+        config: Configuration,
+        sleep: Sleep,
+        app_id: i64,
+        private_key_pem: &[u8],
+    ) -> Result<Self, ApiError> {
+        let installations = Installations::new(app_id, private_key_pem)?;
+        let app = Caller::new(client, installations.app_config(config), sleep);
+        Ok(AppClient { app, installations })
+    }
+
+    /// Wait for and retry rate limited requests from every `Caller`, according to `policy`.
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.app.rate_limit_policy = Some(policy);
+        self
+    }
+
+    /// `Caller` authenticated as the app itself, for operations such as `apps_list_installations`.
+    pub fn app(&self) -> &Caller<T, Sleep, SleepFut, SleepOut> {
+        &self.app
+    }
+
+    /// `Caller` authenticated as installation `installation_id`.
+    pub fn for_installation(&self, installation_id: i64) -> Caller<T, Sleep, SleepFut, SleepOut> {
+        let config = self.installations.installation_config(&self.app.config, installation_id);
+        Caller::new(self.app.client.clone(), config, self.app.sleep.clone()).share_with(&self.app)
+    }
+
+    /// `Caller` authenticated as the installation that can access repository `owner/repo`.
+    pub async fn for_repo(&self, owner: &str, repo: &str) -> Result<Caller<T, Sleep, SleepFut, SleepOut>, ApiError> {
+        let account = Account::repo(owner, repo);
+        let installation_id = match self.installations.cached(&account) {
+            Some(installation_id) => installation_id,
+            None => self.found(account, self.app.apps_get_repo_installation(owner, repo).await?).await?,
+        };
+        Ok(self.for_installation(installation_id))
+    }
+
+    /// `Caller` authenticated as the installation on organization `org`.
+    pub async fn for_org(&self, org: &str) -> Result<Caller<T, Sleep, SleepFut, SleepOut>, ApiError> {
+        let account = Account::org(org);
+        let installation_id = match self.installations.cached(&account) {
+            Some(installation_id) => installation_id,
+            None => self.found(account, self.app.apps_get_org_installation(org).await?).await?,
+        };
+        Ok(self.for_installation(installation_id))
+    }
+
+    /// `Caller` authenticated as the installation on the account of user `username`.
+    pub async fn for_user(&self, username: &str) -> Result<Caller<T, Sleep, SleepFut, SleepOut>, ApiError> {
+        let account = Account::user(username);
+        let installation_id = match self.installations.cached(&account) {
+            Some(installation_id) => installation_id,
+            None => self.found(account, self.app.apps_get_user_installation(username).await?).await?,
+        };
+        Ok(self.for_installation(installation_id))
+    }
+
+    /// Forget the cached ID and token of installation `installation_id`, e.g. after
+    /// receiving an `installation` webhook event with action `deleted`.
+    pub fn forget_installation(&self, installation_id: i64) {
+        self.installations.forget(installation_id);
+    }
+
+    async fn found(&self, account: Account, response: T::Response) -> Result<i64, ApiError> {
+        let response = error_for_status(response).await?.into_bytes().await?;
+        self.installations.found(account, response.body())
+    }
+}
@@ -0,0 +1,148 @@
+use ::std::borrow::Cow;
+use ::std::sync::Arc;
+
+use ::authentic::credential::{AuthenticationCredential, FetchedToken, FetchedUsernamePassword};
+use ::authentic::{AuthenticError, AuthenticationProtocol, AuthenticationProtocolConfigure, AuthenticationStep};
+use ::base64::engine::general_purpose::STANDARD;
+use ::base64::Engine;
+use ::http::header::{HeaderValue, AUTHORIZATION};
+
+/// Authenticate an `http` request builder using a protocol, whichever client sends the
+/// request.
+pub trait WithAuthentication: Sized {
+    fn with_authentication<Configure>(self, protocol: &Configure) -> Result<Self, AuthenticError>
+    where
+        Configure: AuthenticationProtocolConfigure<Self>,
+    {
+        protocol.configure(self)
+    }
+}
+
+impl WithAuthentication for ::http::request::Builder {}
+
+/// Wait until the credential can be used.
+fn credential_step<Credential: AuthenticationCredential>(
+    credential: &Credential,
+) -> Result<Option<AuthenticationStep<::http::Request<Vec<u8>>>>, AuthenticError> {
+    match credential.auth_step()? {
+        duration if duration.is_zero() => Ok(None),
+        duration => Ok(Some(AuthenticationStep::WaitFor(duration))),
+    }
+}
+
+fn sensitive(value: Vec<u8>) -> Result<HeaderValue, AuthenticError> {
+    let mut value = HeaderValue::try_from(value)?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Protocol sending requests without credentials.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoAuthentication;
+
+impl NoAuthentication {
+    pub fn new() -> Self {
+        NoAuthentication
+    }
+}
+
+impl AuthenticationProtocol for NoAuthentication {
+    type Request = ::http::Request<Vec<u8>>;
+    type Response = ::http::Response<Vec<u8>>;
+    type Error = crate::v1_1_4::ApiError;
+}
+
+impl AuthenticationProtocolConfigure<::http::request::Builder> for NoAuthentication {}
+
+/// Protocol sending a token in the `Authorization` header.
+pub struct BearerAuthentication<Credential> {
+    auth_scheme: Cow<'static, str>,
+    credential: Arc<Credential>,
+}
+
+impl<Credential> BearerAuthentication<Credential>
+where
+    Credential: AuthenticationCredential,
+    <Credential as AuthenticationCredential>::Fetch: FetchedToken,
+{
+    pub fn new(credential: Arc<Credential>) -> Self {
+        Self {
+            auth_scheme: "Bearer".into(),
+            credential,
+        }
+    }
+
+    /// Use `auth_scheme` instead of `Bearer`, e.g. `token` for access tokens.
+    pub fn with_auth_scheme(mut self, auth_scheme: impl Into<Cow<'static, str>>) -> Self {
+        self.auth_scheme = auth_scheme.into();
+        self
+    }
+}
+
+impl<Credential> AuthenticationProtocol for BearerAuthentication<Credential>
+where
+    Credential: AuthenticationCredential,
+    <Credential as AuthenticationCredential>::Fetch: FetchedToken,
+{
+    type Request = ::http::Request<Vec<u8>>;
+    type Response = ::http::Response<Vec<u8>>;
+    type Error = crate::v1_1_4::ApiError;
+
+    fn step(&self) -> Result<Option<AuthenticationStep<Self::Request>>, AuthenticError> {
+        credential_step(self.credential.as_ref())
+    }
+}
+
+impl<Credential> AuthenticationProtocolConfigure<::http::request::Builder> for BearerAuthentication<Credential>
+where
+    Credential: AuthenticationCredential,
+    <Credential as AuthenticationCredential>::Fetch: FetchedToken,
+{
+    fn configure(&self, builder: ::http::request::Builder) -> Result<::http::request::Builder, AuthenticError> {
+        let fetched = self.credential.fetch()?;
+        let token = fetched.token();
+        let mut value = Vec::with_capacity(self.auth_scheme.len() + 1 + token.len());
+        value.extend(self.auth_scheme.as_bytes());
+        value.push(b' ');
+        value.extend(token);
+        Ok(builder.header(AUTHORIZATION, sensitive(value)?))
+    }
+}
+
+/// Protocol sending a username and password using HTTP Basic authentication, without
+/// waiting for a challenge.
+pub struct BasicAuthentication<Credential> {
+    credential: Arc<Credential>,
+}
+
+impl<Credential> BasicAuthentication<Credential> {
+    pub fn new(credential: Arc<Credential>) -> Self {
+        Self { credential }
+    }
+}
+
+impl<Credential> AuthenticationProtocol for BasicAuthentication<Credential>
+where
+    Credential: AuthenticationCredential,
+    <Credential as AuthenticationCredential>::Fetch: FetchedUsernamePassword,
+{
+    type Request = ::http::Request<Vec<u8>>;
+    type Response = ::http::Response<Vec<u8>>;
+    type Error = crate::v1_1_4::ApiError;
+
+    fn step(&self) -> Result<Option<AuthenticationStep<Self::Request>>, AuthenticError> {
+        credential_step(self.credential.as_ref())
+    }
+}
+
+impl<Credential> AuthenticationProtocolConfigure<::http::request::Builder> for BasicAuthentication<Credential>
+where
+    Credential: AuthenticationCredential,
+    <Credential as AuthenticationCredential>::Fetch: FetchedUsernamePassword,
+{
+    fn configure(&self, builder: ::http::request::Builder) -> Result<::http::request::Builder, AuthenticError> {
+        let fetched = self.credential.fetch()?;
+        let encoded = STANDARD.encode(format!("{}:{}", fetched.username(), fetched.password()));
+        Ok(builder.header(AUTHORIZATION, sensitive(format!("Basic {}", encoded).into_bytes())?))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::v1_1_4::cache::{self, Lookup};
+use crate::v1_1_4::config::Configuration;
+use crate::v1_1_4::transport::TransportResponse;
+use crate::v1_1_4::ApiError;
+
+/// Look up a request in the configured cache, adding validators to the request.
+pub(super) fn lookup<Body>(config: &Configuration, request: &mut ::http::Request<Body>) -> Option<Lookup> {
+    let cache = config.cache.as_ref()?;
+    let method = request.method().clone();
+    let url = request.uri().to_string();
+    cache::lookup(cache.as_ref(), &method, &url, request.headers_mut())
+}
+
+/// Replace a `304 Not Modified` response with the cached response, or cache a new response.
+pub(super) async fn revalidate<Response: TransportResponse>(
+    config: &Configuration,
+    lookup: Option<Lookup>,
+    response: Response,
+) -> Result<Response, ApiError> {
+    let (cache, lookup) = match (&config.cache, lookup) {
+        (Some(cache), Some(lookup)) => (cache, lookup),
+        _ => return Ok(response),
+    };
+    match lookup.cached {
+        Some(cached) if response.status() == ::http::StatusCode::NOT_MODIFIED => {
+            let (headers, body) = cached.refresh(response.headers());
+            let mut builder = ::http::Response::builder().status(::http::StatusCode::OK);
+            if let Some(builder_headers) = builder.headers_mut() {
+                *builder_headers = headers;
+            }
+            Response::from_bytes(builder.body(body)?)
+        }
+        _ if cache::is_cacheable(response.status(), response.headers()) => {
+            let response = response.into_bytes().await?;
+            cache.put(
+                &lookup.key,
+                cache::CachedResponse {
+                    headers: response.headers().clone(),
+                    body: response.body().clone(),
+                },
+            );
+            Response::from_bytes(response)
+        }
+        _ => Ok(response),
+    }
+}
@@ -0,0 +1,181 @@
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use crate::v1_1_4::checks::{
+    truncate_output, update_annotation, Annotation, AnnotationCounts, Conclusion, MAX_ANNOTATIONS_PER_REQUEST,
+};
+use crate::v1_1_4::request::{checks_create, checks_update};
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+/// An `in_progress` check run, sending its annotations in batches.
+///
+/// Created by [`Caller::start_check_run`].
+pub struct CheckRunReporter<'c, T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    caller: &'c Caller<T, Sleep, SleepFut, SleepOut>,
+    owner: String,
+    repo: String,
+    check_run_id: i64,
+    title: String,
+    summary: Option<String>,
+    text: Option<String>,
+    pending: Vec<Annotation<'static>>,
+    counts: AnnotationCounts,
+}
+
+impl<'c, T, Sleep, SleepFut, SleepOut> CheckRunReporter<'c, T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    pub fn check_run_id(&self) -> i64 {
+        self.check_run_id
+    }
+
+    /// Number of annotations of each level added so far.
+    pub fn counts(&self) -> AnnotationCounts {
+        self.counts
+    }
+
+    /// Title of the output. Defaults to the name of the check run.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// Summary of the output, truncated to 65535 characters. Defaults to the number of
+    /// annotations of each level.
+    pub fn set_summary(&mut self, summary: impl Into<String>) {
+        self.summary = Some(summary.into());
+    }
+
+    /// Details of the output, truncated to 65535 characters.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = Some(text.into());
+    }
+
+    /// Add `annotation`, sending a batch of annotations once 50 are pending.
+    pub async fn annotate(&mut self, annotation: Annotation<'static>) -> Result<(), ApiError> {
+        self.counts.add(&annotation.annotation_level);
+        self.pending.push(annotation);
+        if self.pending.len() >= MAX_ANNOTATIONS_PER_REQUEST {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Add each of `annotations`, sending batches of 50 annotations.
+    pub async fn annotate_all<I>(&mut self, annotations: I) -> Result<(), ApiError>
+    where
+        I: IntoIterator<Item = Annotation<'static>>,
+    {
+        for annotation in annotations {
+            self.annotate(annotation).await?;
+        }
+        Ok(())
+    }
+
+    /// Send the pending annotations.
+    pub async fn flush(&mut self) -> Result<(), ApiError> {
+        while !self.pending.is_empty() {
+            self.update(None).await?;
+        }
+        Ok(())
+    }
+
+    /// Complete the check run with the conclusion derived from the annotation levels:
+    /// `failure` if any annotation is a failure, `neutral` if any is a warning, and
+    /// `success` otherwise.
+    pub async fn finish(self) -> Result<T::Response, ApiError> {
+        let conclusion = self.counts.conclusion();
+        self.finish_with(conclusion).await
+    }
+
+    /// Complete the check run with `conclusion`, sending the pending annotations.
+    pub async fn finish_with(mut self, conclusion: Conclusion) -> Result<T::Response, ApiError> {
+        while self.pending.len() > MAX_ANNOTATIONS_PER_REQUEST {
+            self.update(None).await?;
+        }
+        self.update(Some(conclusion)).await
+    }
+
+    /// Send the output, with the next batch of pending annotations, completing the check
+    /// run if `conclusion` is given.
+    async fn update(&mut self, conclusion: Option<Conclusion>) -> Result<T::Response, ApiError> {
+        // The annotations stay pending until the request succeeds, so that the batch is
+        // sent again by the next update.
+        let count = self.pending.len().min(MAX_ANNOTATIONS_PER_REQUEST);
+        let response = {
+            let annotations: Vec<_> = self.pending[..count].iter().map(update_annotation).collect();
+            let summary = match &self.summary {
+                Some(summary) => truncate_output(summary),
+                None => self.counts.summary().into(),
+            };
+            let body = checks_update::body::Json {
+                conclusion: conclusion.map(|conclusion| conclusion.as_str().into()),
+                output: Some(checks_update::body::json::Output {
+                    title: Some(self.title.as_str().into()),
+                    summary,
+                    text: self.text.as_deref().map(truncate_output),
+                    annotations: (!annotations.is_empty()).then(|| annotations.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let response = self.caller.checks_update(&self.owner, &self.repo, self.check_run_id, &body).await?;
+            error_for_status(response).await?
+        };
+        self.pending.drain(..count);
+        Ok(response)
+    }
+}
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Create the `in_progress` check run `name` for the commit `head_sha`, returning a
+    /// reporter sending its annotations.
+    pub async fn start_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        head_sha: &str,
+    ) -> Result<CheckRunReporter<'_, T, Sleep, SleepFut, SleepOut>, ApiError> {
+        #[derive(::serde::Deserialize)]
+        struct Created {
+            id: i64,
+        }
+
+        let body = checks_create::body::Json {
+            name: name.into(),
+            head_sha: head_sha.into(),
+            status: Some("in_progress".into()),
+            ..Default::default()
+        };
+        let response = self.checks_create(owner, repo, &body).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let created: Created = ::serde_json::from_slice(response.body())?;
+        Ok(CheckRunReporter {
+            caller: self,
+            owner: owner.to_owned(),
+            repo: repo.to_owned(),
+            check_run_id: created.id,
+            title: name.to_owned(),
+            summary: None,
+            text: None,
+            pending: Vec::new(),
+            counts: AnnotationCounts::default(),
+        })
+    }
+}
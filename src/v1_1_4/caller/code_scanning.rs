@@ -0,0 +1,88 @@
+use ::std::future::Future;
+use ::std::io::Read;
+use ::std::time::Duration;
+
+use crate::v1_1_4::code_scanning::{encode_sarif, SarifOptions, SarifStatus};
+use crate::v1_1_4::request::code_scanning_upload_sarif;
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Upload the SARIF document `sarif` as the analysis of the commit `commit_sha` on the
+    /// Git reference `ref`, and wait until it is processed.
+    ///
+    /// The document is checked, compressed and encoded before uploading. The processing
+    /// status is polled every `options.poll_interval`, which must not be zero, using the
+    /// sleep function of the caller.
+    /// Failed processing returns [`ApiError::SarifProcessing`] with the errors reported by
+    /// GitHub.
+    pub async fn upload_sarif<R: Read>(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        r#ref: &str,
+        sarif: R,
+        options: &SarifOptions<'_>,
+    ) -> Result<SarifStatus, ApiError> {
+        #[derive(::serde::Deserialize)]
+        struct Receipt {
+            id: String,
+        }
+
+        if options.poll_interval.is_zero() {
+            return Err(ApiError::Other("SARIF poll interval must not be zero".to_owned()));
+        }
+        let body = code_scanning_upload_sarif::body::Json {
+            commit_sha: commit_sha.into(),
+            r#ref: r#ref.into(),
+            sarif: encode_sarif(sarif)?.into(),
+            checkout_uri: options.checkout_uri.map(Into::into),
+            started_at: options.started_at.map(Into::into),
+            tool_name: options.tool_name.map(Into::into),
+            additionalProperties: Default::default(),
+        };
+        let response = self.code_scanning_upload_sarif(owner, repo, &body).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let receipt: Receipt = ::serde_json::from_slice(response.body())?;
+        ::log::debug!("Uploaded SARIF {}", receipt.id);
+
+        let mut waited = Duration::ZERO;
+        while waited < options.timeout {
+            (self.sleep)(options.poll_interval).await;
+            waited += options.poll_interval;
+            let response = self.code_scanning_get_sarif(owner, repo, &receipt.id).await?;
+            let response = match error_for_status(response).await {
+                Ok(response) => response.into_bytes().await?,
+                // The upload may not be visible immediately.
+                Err(ApiError::NotFound { .. }) => continue,
+                Err(err) => return Err(err),
+            };
+            let status: SarifStatus = ::serde_json::from_slice(response.body())?;
+            match status.processing_status.as_str() {
+                "pending" => continue,
+                "failed" => {
+                    return Err(ApiError::SarifProcessing {
+                        sarif_id: receipt.id,
+                        errors: status.errors.unwrap_or_default(),
+                    })
+                }
+                "complete" => return Ok(status),
+                other => {
+                    return Err(ApiError::Other(format!(
+                        "Unknown processing status {:?} of SARIF {}",
+                        other, receipt.id,
+                    )))
+                }
+            }
+        }
+        Err(ApiError::Other(format!("Timed out waiting for processing of SARIF {}", receipt.id)))
+    }
+}
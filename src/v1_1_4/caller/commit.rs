@@ -0,0 +1,273 @@
+use ::std::collections::HashMap;
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use crate::v1_1_4::commit::{CommitBuilder, FileMode, Source, Staged};
+use crate::v1_1_4::contents;
+use crate::v1_1_4::request::{git_create_blob, git_create_commit, git_create_ref, git_create_tree, git_update_ref};
+use crate::v1_1_4::schema::GitCommit;
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+/// Number of blobs uploaded at the same time.
+#[cfg(feature = "futures-util")]
+const MAX_CONCURRENT_UPLOADS: usize = 8;
+
+#[derive(::serde::Deserialize)]
+struct Sha {
+    sha: String,
+}
+
+#[derive(::serde::Deserialize)]
+struct Ref {
+    object: Sha,
+}
+
+#[derive(::serde::Deserialize)]
+struct Commit {
+    tree: Sha,
+}
+
+#[derive(::serde::Deserialize)]
+struct Tree {
+    tree: Vec<TreeEntry>,
+}
+
+#[derive(Clone, ::serde::Deserialize)]
+struct TreeEntry {
+    path: String,
+    mode: String,
+    #[serde(rename = "type")]
+    kind: String,
+    sha: String,
+}
+
+fn not_found(message: String) -> ApiError {
+    ApiError::NotFound {
+        message,
+        documentation_url: None,
+    }
+}
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Commit the staged `changes` on top of their branch, returning the new commit.
+    ///
+    /// The contents of the files are uploaded in parallel. The tree of the commit is built
+    /// on top of the tree of the head of the branch, which is then fast-forwarded to the new
+    /// commit, or created from the base branch if it does not exist. If the branch moves
+    /// meanwhile, the commit is created again on top of the new head.
+    pub async fn commit_changes(&self, owner: &str, repo: &str, changes: &CommitBuilder<'_>) -> Result<GitCommit<'static>, ApiError> {
+        let blobs = self.create_blobs(owner, repo, changes).await?;
+        let branch = format!("heads/{}", changes.branch);
+        let mut head = self.ref_head(owner, repo, &branch).await?;
+        let mut retries = 0;
+        loop {
+            let parent = match (&head, changes.base) {
+                (Some(head), _) => head.clone(),
+                (None, Some(base)) => self
+                    .ref_head(owner, repo, &format!("heads/{}", base))
+                    .await?
+                    .ok_or_else(|| not_found(format!("Branch {} does not exist", base)))?,
+                (None, None) => return Err(not_found(format!("Branch {} does not exist", changes.branch))),
+            };
+            let commit = self.create_commit_on(owner, repo, changes, &blobs, &parent).await?;
+            let response = if head.is_some() {
+                let body = git_update_ref::body::Json {
+                    sha: commit.sha.as_ref().into(),
+                    force: Some(false),
+                    additionalProperties: Default::default(),
+                };
+                self.git_update_ref(owner, repo, &branch, &body).await?
+            } else {
+                let body = git_create_ref::body::Json {
+                    r#ref: format!("refs/{}", branch).into(),
+                    sha: commit.sha.as_ref().into(),
+                    key: None,
+                    additionalProperties: Default::default(),
+                };
+                self.git_create_ref(owner, repo, &body).await?
+            };
+            let status = response.status();
+            if status.is_success() {
+                return Ok(commit);
+            }
+            // Not a fast-forward, or the branch was created meanwhile.
+            if retries < changes.max_retries
+                && matches!(status, ::http::StatusCode::CONFLICT | ::http::StatusCode::UNPROCESSABLE_ENTITY)
+            {
+                let current = self.ref_head(owner, repo, &branch).await?;
+                if current.is_some() && current != head {
+                    retries += 1;
+                    head = current;
+                    continue;
+                }
+            }
+            let response = response.into_bytes().await?;
+            return Err(ApiError::from_response(status, response.headers(), response.body()));
+        }
+    }
+
+    /// Create a commit of `changes` on top of `parent`, using the uploaded `blobs`.
+    async fn create_commit_on(
+        &self,
+        owner: &str,
+        repo: &str,
+        changes: &CommitBuilder<'_>,
+        blobs: &HashMap<String, String>,
+        parent: &str,
+    ) -> Result<GitCommit<'static>, ApiError> {
+        let response = self.git_get_commit(owner, repo, parent).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let base_tree = ::serde_json::from_slice::<Commit>(response.body())?.tree.sha;
+
+        let mut trees = HashMap::new();
+        let mut entries = Vec::with_capacity(changes.changes.len());
+        for (path, staged) in &changes.changes {
+            let (mode, kind, sha) = match staged {
+                Staged::Delete => (FileMode::Regular.as_str().to_owned(), "blob".to_owned(), None),
+                Staged::File { source: Source::Content(_), mode } => {
+                    let mode = match mode {
+                        Some(mode) => mode.as_str().to_owned(),
+                        None => self
+                            .tree_entry(owner, repo, &base_tree, path, &mut trees)
+                            .await?
+                            .map_or_else(|| FileMode::Regular.as_str().to_owned(), |entry| entry.mode),
+                    };
+                    (mode, "blob".to_owned(), blobs.get(path).cloned())
+                }
+                Staged::File { source: Source::Existing(from), mode } => {
+                    let entry = self
+                        .tree_entry(owner, repo, &base_tree, from, &mut trees)
+                        .await?
+                        .ok_or_else(|| not_found(format!("{} does not exist", from)))?;
+                    let mode = mode.map_or(entry.mode, |mode| mode.as_str().to_owned());
+                    (mode, entry.kind, Some(entry.sha))
+                }
+            };
+            entries.push(git_create_tree::body::json::Tree {
+                path: Some(path.as_str().into()),
+                mode: Some(mode.into()),
+                r#type: Some(kind.into()),
+                // A null sha deletes the path.
+                sha: Some(sha.map(Into::into)),
+                ..Default::default()
+            });
+        }
+
+        let body = git_create_tree::body::Json {
+            tree: entries.into(),
+            base_tree: Some(base_tree.into()),
+            additionalProperties: Default::default(),
+        };
+        let response = self.git_create_tree(owner, repo, &body).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let tree = ::serde_json::from_slice::<Sha>(response.body())?.sha;
+
+        let parents = [parent.into()];
+        let body = git_create_commit::body::Json {
+            message: changes.message.into(),
+            tree: tree.into(),
+            parents: Some(parents[..].into()),
+            author: changes.author.map(|author| git_create_commit::body::json::Author {
+                name: author.name.into(),
+                email: author.email.into(),
+                ..Default::default()
+            }),
+            committer: changes.committer.map(|committer| git_create_commit::body::json::Committer {
+                name: Some(committer.name.into()),
+                email: Some(committer.email.into()),
+                ..Default::default()
+            }),
+            signature: None,
+            additionalProperties: Default::default(),
+        };
+        let response = self.git_create_commit(owner, repo, &body).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        Ok(::serde_json::from_slice(response.body())?)
+    }
+
+    /// The commit at the head of `r#ref`, or `None` if the ref does not exist.
+    async fn ref_head(&self, owner: &str, repo: &str, r#ref: &str) -> Result<Option<String>, ApiError> {
+        let response = self.git_get_ref(owner, repo, r#ref).await?;
+        if response.status() == ::http::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = error_for_status(response).await?.into_bytes().await?;
+        Ok(Some(::serde_json::from_slice::<Ref>(response.body())?.object.sha))
+    }
+
+    /// The entry at `path` of the tree `root`, reading each tree once into `trees`.
+    async fn tree_entry(
+        &self,
+        owner: &str,
+        repo: &str,
+        root: &str,
+        path: &str,
+        trees: &mut HashMap<String, Vec<TreeEntry>>,
+    ) -> Result<Option<TreeEntry>, ApiError> {
+        let mut tree = root.to_owned();
+        let mut names = path.split('/').peekable();
+        while let Some(name) = names.next() {
+            if !trees.contains_key(&tree) {
+                let response = self.git_get_tree(owner, repo, &tree, None).await?;
+                let response = error_for_status(response).await?.into_bytes().await?;
+                let entries = ::serde_json::from_slice::<Tree>(response.body())?.tree;
+                trees.insert(tree.clone(), entries);
+            }
+            match trees[&tree].iter().find(|entry| entry.path == name) {
+                Some(entry) if names.peek().is_none() => return Ok(Some(entry.clone())),
+                Some(entry) if entry.kind == "tree" => tree = entry.sha.clone(),
+                _ => break,
+            }
+        }
+        Ok(None)
+    }
+
+    /// Upload the new contents of `changes`, returning the sha of each blob by path.
+    #[cfg(feature = "futures-util")]
+    async fn create_blobs(&self, owner: &str, repo: &str, changes: &CommitBuilder<'_>) -> Result<HashMap<String, String>, ApiError> {
+        use ::futures_util::stream::{self, StreamExt, TryStreamExt};
+
+        stream::iter(staged_contents(changes))
+            .map(|(path, content)| async move { Ok((path.to_owned(), self.create_blob(owner, repo, content).await?)) })
+            .buffer_unordered(MAX_CONCURRENT_UPLOADS)
+            .try_collect()
+            .await
+    }
+
+    /// Upload the new contents of `changes`, returning the sha of each blob by path.
+    #[cfg(not(feature = "futures-util"))]
+    async fn create_blobs(&self, owner: &str, repo: &str, changes: &CommitBuilder<'_>) -> Result<HashMap<String, String>, ApiError> {
+        let mut blobs = HashMap::new();
+        for (path, content) in staged_contents(changes) {
+            blobs.insert(path.to_owned(), self.create_blob(owner, repo, content).await?);
+        }
+        Ok(blobs)
+    }
+
+    async fn create_blob(&self, owner: &str, repo: &str, content: &[u8]) -> Result<String, ApiError> {
+        let body = git_create_blob::body::Json {
+            content: contents::encode(content).into(),
+            encoding: Some("base64".into()),
+            additionalProperties: Default::default(),
+        };
+        let response = self.git_create_blob(owner, repo, &body).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        Ok(::serde_json::from_slice::<Sha>(response.body())?.sha)
+    }
+}
+
+/// The paths of `changes` with new contents.
+fn staged_contents<'a>(changes: &'a CommitBuilder<'_>) -> impl Iterator<Item = (&'a str, &'a [u8])> {
+    changes.changes.iter().filter_map(|(path, staged)| match staged {
+        Staged::File { source: Source::Content(content), .. } => Some((path.as_str(), content.as_ref())),
+        _ => None,
+    })
+}
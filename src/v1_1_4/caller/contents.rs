@@ -0,0 +1,195 @@
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use crate::v1_1_4::contents::{self, CommitOptions, Contents, File, MAX_CONTENT_SIZE};
+use crate::v1_1_4::request::{repos_create_or_update_file_contents, repos_delete_file};
+use crate::v1_1_4::schema::{Blob, ContentFile, FileCommit};
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Read the file at `path`, at the branch, tag or commit `ref`, or the default branch.
+    ///
+    /// Files larger than 1 MB are read using `git_get_blob`.
+    pub async fn read_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        r#ref: Option<&str>,
+    ) -> Result<File, ApiError> {
+        let entry = match self.contents(owner, repo, path, r#ref).await? {
+            Contents::File(entry) if entry.r#type == "file" => *entry,
+            Contents::Dir(_) => return Err(ApiError::Other(format!("{} is a directory", path))),
+            Contents::Symlink(symlink) => {
+                return Err(ApiError::Other(format!("{} is a symbolic link to {}", path, symlink.target)))
+            }
+            Contents::Submodule(submodule) => {
+                return Err(ApiError::Other(format!("{} is a submodule of {}", path, submodule.submodule_git_url)))
+            }
+            Contents::File(_) => return Err(ApiError::Other(format!("{} is not a file", path))),
+        };
+        let content = match entry.encoding.as_ref() {
+            "base64" if entry.size <= MAX_CONTENT_SIZE && !(entry.content.is_empty() && entry.size > 0) => {
+                contents::decode(&entry.content)?
+            }
+            _ => self.read_blob(owner, repo, &entry.sha).await?,
+        };
+        Ok(File { entry, content })
+    }
+
+    /// The entries of the directory at `path`, at the branch, tag or commit `ref`, or the
+    /// default branch.
+    ///
+    /// Entries are returned without their `content`, and their `type` is one of `file`,
+    /// `dir`, `symlink` or `submodule`.
+    pub async fn list_dir(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        r#ref: Option<&str>,
+    ) -> Result<Vec<ContentFile<'static>>, ApiError> {
+        match self.contents(owner, repo, path, r#ref).await? {
+            Contents::Dir(entries) => Ok(entries),
+            _ => Err(ApiError::Other(format!("{} is not a directory", path))),
+        }
+    }
+
+    /// Create or update the file at `path` with `content`.
+    ///
+    /// Unless `options` gives the expected `sha` of the file, the current `sha` is looked up,
+    /// and a `409 Conflict` response, returned if the file changed meanwhile, is retried
+    /// with the new `sha`. Likewise, if the file was created meanwhile, the `422
+    /// Unprocessable Entity` response asking for its `sha` is retried once.
+    pub async fn write_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &[u8],
+        options: &CommitOptions<'_>,
+    ) -> Result<FileCommit<'static>, ApiError> {
+        let content = contents::encode(content);
+        let mut sha = match options.sha {
+            Some(sha) => Some(sha.to_owned()),
+            None => self.file_sha(owner, repo, path, options.branch).await?,
+        };
+        let mut retries = 0;
+        loop {
+            let body = repos_create_or_update_file_contents::body::Json {
+                message: options.message.into(),
+                content: content.as_str().into(),
+                sha: sha.as_deref().map(Into::into),
+                branch: options.branch.map(Into::into),
+                committer: options.committer.map(|committer| repos_create_or_update_file_contents::body::json::Committer {
+                    name: committer.name.into(),
+                    email: committer.email.into(),
+                    ..Default::default()
+                }),
+                author: options.author.map(|author| repos_create_or_update_file_contents::body::json::Author {
+                    name: author.name.into(),
+                    email: author.email.into(),
+                    ..Default::default()
+                }),
+                additionalProperties: Default::default(),
+            };
+            let response = self.repos_create_or_update_file_contents(owner, repo, path, &body).await?;
+            if response.status() == ::http::StatusCode::CONFLICT && options.sha.is_none() && retries < options.max_retries {
+                retries += 1;
+                sha = self.file_sha(owner, repo, path, options.branch).await?;
+                continue;
+            }
+            if response.status() == ::http::StatusCode::UNPROCESSABLE_ENTITY && options.sha.is_none() && sha.is_none() {
+                sha = self.file_sha(owner, repo, path, options.branch).await?;
+                if sha.is_some() {
+                    continue;
+                }
+            }
+            let response = error_for_status(response).await?.into_bytes().await?;
+            return Ok(::serde_json::from_slice(response.body())?);
+        }
+    }
+
+    /// Delete the file at `path`.
+    ///
+    /// Unless `options` gives the expected `sha` of the file, the current `sha` is looked up,
+    /// and a `409 Conflict` response is retried as by [`Caller::write_file`].
+    pub async fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        options: &CommitOptions<'_>,
+    ) -> Result<FileCommit<'static>, ApiError> {
+        let mut retries = 0;
+        loop {
+            let sha = match options.sha {
+                Some(sha) => sha.to_owned(),
+                None => self.file_sha(owner, repo, path, options.branch).await?.ok_or_else(|| ApiError::NotFound {
+                    message: format!("{} does not exist", path),
+                    documentation_url: None,
+                })?,
+            };
+            let body = repos_delete_file::body::Json {
+                message: options.message.into(),
+                sha: sha.into(),
+                branch: options.branch.map(Into::into),
+                committer: options.committer.map(|committer| repos_delete_file::body::json::Committer {
+                    name: Some(committer.name.into()),
+                    email: Some(committer.email.into()),
+                    ..Default::default()
+                }),
+                author: options.author.map(|author| repos_delete_file::body::json::Author {
+                    name: Some(author.name.into()),
+                    email: Some(author.email.into()),
+                    ..Default::default()
+                }),
+                additionalProperties: Default::default(),
+            };
+            let response = self.repos_delete_file(owner, repo, path, &body).await?;
+            if response.status() == ::http::StatusCode::CONFLICT && options.sha.is_none() && retries < options.max_retries {
+                retries += 1;
+                continue;
+            }
+            let response = error_for_status(response).await?.into_bytes().await?;
+            return Ok(::serde_json::from_slice(response.body())?);
+        }
+    }
+
+    async fn contents(&self, owner: &str, repo: &str, path: &str, r#ref: Option<&str>) -> Result<Contents, ApiError> {
+        let response = self.repos_get_content(owner, repo, path, r#ref).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        Contents::from_slice(response.body())
+    }
+
+    /// The blob `sha` of the file at `path`, or `None` if there is no such file.
+    async fn file_sha(&self, owner: &str, repo: &str, path: &str, branch: Option<&str>) -> Result<Option<String>, ApiError> {
+        let response = self.repos_get_content(owner, repo, path, branch).await?;
+        if response.status() == ::http::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = error_for_status(response).await?.into_bytes().await?;
+        match Contents::from_slice(response.body())? {
+            Contents::File(entry) if entry.r#type == "file" => Ok(Some(entry.sha.into_owned())),
+            _ => Err(ApiError::Other(format!("{} is not a file", path))),
+        }
+    }
+
+    async fn read_blob(&self, owner: &str, repo: &str, sha: &str) -> Result<Vec<u8>, ApiError> {
+        let response = self.git_get_blob(owner, repo, sha).await?;
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let blob: Blob<'static> = ::serde_json::from_slice(response.body())?;
+        match blob.encoding.as_ref() {
+            "base64" => contents::decode(&blob.content),
+            _ => Ok(blob.content.into_owned().into_bytes()),
+        }
+    }
+}
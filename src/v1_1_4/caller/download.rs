@@ -0,0 +1,346 @@
+//! Downloads of archives and logs.
+//!
+//! Operations such as `actions_download_artifact`, `actions_download_workflow_run_logs`,
+//! `repos_download_tarball_archive`, `repos_download_zipball_archive` and
+//! `migrations_download_archive_for_org` respond with a redirect to a short-lived URL on
+//! another host. [`Caller::download`] follows the redirect, without sending the credentials
+//! of the caller to the other host, and streams the body to a writer.
+
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use ::http::header::{HeaderMap, ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, LOCATION, RANGE, USER_AGENT};
+use ::http::uri::Uri;
+use ::http::StatusCode;
+
+use crate::v1_1_4::transport::{StreamingResponse, Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, same_origin, Caller, WithAuthentication};
+
+/// Maximum number of redirects followed by a download.
+const MAX_REDIRECTS: usize = 10;
+
+/// Progress of a download, passed to the callback set using [`DownloadOptions::on_progress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Bytes downloaded, including the offset the download resumed from.
+    pub downloaded: u64,
+    /// Size of the file, if the server reported it.
+    pub total: Option<u64>,
+}
+
+/// Options of [`Caller::download`].
+pub struct DownloadOptions<'a> {
+    offset: u64,
+    max_resumes: usize,
+    progress: Option<Box<dyn FnMut(Progress) + Send + 'a>>,
+}
+
+impl<'a> Default for DownloadOptions<'a> {
+    fn default() -> Self {
+        DownloadOptions {
+            offset: 0,
+            max_resumes: 3,
+            progress: None,
+        }
+    }
+}
+
+impl<'a> DownloadOptions<'a> {
+    /// Resume a previous download, whose first `offset` bytes have already been written.
+    ///
+    /// The remaining bytes are requested using a `Range` header. If the server ignores the
+    /// range, the first `offset` bytes of the body are skipped.
+    #[must_use]
+    pub fn resume_from(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Request the remaining bytes again, up to `max_resumes` times, if the connection fails
+    /// during the download and the server accepts ranges. Defaults to 3.
+    #[must_use]
+    pub fn max_resumes(mut self, max_resumes: usize) -> Self {
+        self.max_resumes = max_resumes;
+        self
+    }
+
+    /// Call `progress` after each chunk written.
+    #[must_use]
+    pub fn on_progress<F>(mut self, progress: F) -> Self
+    where
+        F: FnMut(Progress) + Send + 'a,
+    {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+}
+
+/// Destination of a download, written one chunk at a time.
+pub(crate) trait DownloadWriter {
+    type Write<'a>: Future<Output = ::std::io::Result<()>> + 'a
+    where
+        Self: 'a;
+    type Flush<'a>: Future<Output = ::std::io::Result<()>> + 'a
+    where
+        Self: 'a;
+
+    fn write_chunk<'a>(&'a mut self, chunk: &'a [u8]) -> Self::Write<'a>;
+
+    fn flush(&mut self) -> Self::Flush<'_>;
+}
+
+/// [`DownloadWriter`] using a blocking `std::io::Write`.
+#[cfg(feature = "reqwest-blocking")]
+pub(crate) struct BlockingWriter<W>(pub(crate) W);
+
+#[cfg(feature = "reqwest-blocking")]
+impl<W: ::std::io::Write> DownloadWriter for BlockingWriter<W> {
+    type Write<'a> = ::std::future::Ready<::std::io::Result<()>> where W: 'a;
+    type Flush<'a> = ::std::future::Ready<::std::io::Result<()>> where W: 'a;
+
+    fn write_chunk<'a>(&'a mut self, chunk: &'a [u8]) -> Self::Write<'a> {
+        ::std::future::ready(self.0.write_all(chunk))
+    }
+
+    fn flush(&mut self) -> Self::Flush<'_> {
+        ::std::future::ready(self.0.flush())
+    }
+}
+
+/// [`DownloadWriter`] using an `AsyncWrite`.
+#[cfg(feature = "futures-util")]
+struct AsyncWriter<W>(W);
+
+#[cfg(feature = "futures-util")]
+impl<W: ::futures_util::io::AsyncWrite + Unpin> DownloadWriter for AsyncWriter<W> {
+    type Write<'a> = ::futures_util::io::WriteAll<'a, W> where W: 'a;
+    type Flush<'a> = ::futures_util::io::Flush<'a, W> where W: 'a;
+
+    fn write_chunk<'a>(&'a mut self, chunk: &'a [u8]) -> Self::Write<'a> {
+        ::futures_util::io::AsyncWriteExt::write_all(&mut self.0, chunk)
+    }
+
+    fn flush(&mut self) -> Self::Flush<'_> {
+        ::futures_util::io::AsyncWriteExt::flush(&mut self.0)
+    }
+}
+
+/// Resolve the `Location` header of a redirect against the URL that was requested.
+///
+/// Only HTTP(S) URLs are followed, and never from HTTPS to HTTP.
+fn redirect_location(current: &Uri, headers: &HeaderMap) -> Result<Uri, ApiError> {
+    let location = headers
+        .get(LOCATION)
+        .ok_or_else(|| ApiError::Other("Redirect without Location header".to_owned()))?
+        .to_str()
+        .map_err(|_| ApiError::Other("Invalid Location header".to_owned()))?;
+    let invalid = || ApiError::Other(format!("Invalid redirect location {}", location));
+    // The fragment is never sent.
+    let reference = location.split_once('#').map_or(location, |(reference, _)| reference);
+    let location: Uri = if reference.contains("://") {
+        reference.parse().map_err(|_| invalid())?
+    } else if let Some(reference) = reference.strip_prefix("//") {
+        format!("{}://{}", current.scheme_str().unwrap_or("https"), reference)
+            .parse()
+            .map_err(|_| invalid())?
+    } else {
+        let mut parts = current.clone().into_parts();
+        parts.path_and_query = Some(resolve_path(current, reference).parse().map_err(|_| invalid())?);
+        Uri::from_parts(parts).map_err(|_| invalid())?
+    };
+    match (current.scheme_str(), location.scheme_str()) {
+        (_, Some("https")) | (Some("http"), Some("http")) => Ok(location),
+        _ => Err(ApiError::Other(format!("Refusing to follow redirect to {}", location))),
+    }
+}
+
+/// Resolve a reference without scheme or host, e.g. `/path`, `file?query` or `../file`,
+/// against the path of `base`, as described in RFC 3986 section 5.2.
+fn resolve_path(base: &Uri, reference: &str) -> String {
+    let (path, query) = match reference.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (reference, None),
+    };
+    let (path, query) = if path.is_empty() {
+        (base.path().to_owned(), query.or(base.query()))
+    } else if path.starts_with('/') {
+        (path.to_owned(), query)
+    } else {
+        let directory = base.path().rfind('/').map_or("/", |end| &base.path()[..=end]);
+        (format!("{}{}", directory, path), query)
+    };
+
+    // Remove the `.` and `..` segments.
+    let segments = path.split('/').skip(1).collect::<Vec<_>>();
+    let mut resolved = Vec::with_capacity(segments.len());
+    for (index, segment) in segments.iter().enumerate() {
+        match *segment {
+            "." | ".." => {
+                if *segment == ".." {
+                    resolved.pop();
+                }
+                if index + 1 == segments.len() {
+                    resolved.push("");
+                }
+            }
+            segment => resolved.push(segment),
+        }
+    }
+    let path = format!("/{}", resolved.join("/"));
+    match query {
+        Some(query) => format!("{}?{}", path, query),
+        None => path,
+    }
+}
+
+/// Offset of the first byte of the body, and the size of the file, if known.
+fn body_range(status: StatusCode, headers: &HeaderMap) -> (u64, Option<u64>) {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    if status == StatusCode::PARTIAL_CONTENT {
+        // Content-Range: bytes 100-999/1000
+        if let Some((range, total)) = header(CONTENT_RANGE)
+            .and_then(|value| value.strip_prefix("bytes "))
+            .and_then(|value| value.split_once('/'))
+        {
+            let start = range.split_once('-').and_then(|(start, _)| start.parse().ok()).unwrap_or(0);
+            return (start, total.parse().ok());
+        }
+    }
+    (0, header(CONTENT_LENGTH).and_then(|value| value.parse().ok()))
+}
+
+/// Whether a response is a redirect to its `Location`.
+///
+/// Other 3xx statuses, such as `304 Not Modified`, are not followed.
+fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+fn accepts_ranges(status: StatusCode, headers: &HeaderMap) -> bool {
+    status == StatusCode::PARTIAL_CONTENT
+        || headers.get(ACCEPT_RANGES).is_some_and(|value| value == "bytes")
+}
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    T::Response: StreamingResponse,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Stream the file returned by a download operation to `writer`, returning the size of
+    /// the file.
+    ///
+    /// `response` is the response of the operation. A redirect is followed, sending the
+    /// credentials of the caller only if the redirect stays on the host of the API. If the
+    /// client has already followed the redirect, the body of `response` is written, but the
+    /// download cannot be resumed.
+    ///
+    /// ```ignore
+    /// let response = github.actions_download_artifact(owner, repo, artifact_id, "zip").await?;
+    /// let file = tokio::fs::File::create("artifact.zip").await?;
+    /// let options = DownloadOptions::default().on_progress(|progress| println!("{:?}", progress));
+    /// github.download(response, file.compat_write(), options).await?;
+    /// ```
+    #[cfg(feature = "futures-util")]
+    pub async fn download<W>(
+        &self,
+        response: T::Response,
+        writer: W,
+        options: DownloadOptions<'_>,
+    ) -> Result<u64, ApiError>
+    where
+        W: ::futures_util::io::AsyncWrite + Unpin,
+    {
+        self.download_to(response, AsyncWriter(writer), options).await
+    }
+
+    pub(crate) async fn download_to<W: DownloadWriter>(
+        &self,
+        mut response: T::Response,
+        mut writer: W,
+        mut options: DownloadOptions<'_>,
+    ) -> Result<u64, ApiError> {
+        let api_url = self.api_url()?;
+
+        let mut url: Option<Uri> = None;
+        let mut redirects = 0;
+        while is_redirect(response.status()) {
+            if redirects == MAX_REDIRECTS {
+                return Err(ApiError::Other("Too many redirects".to_owned()));
+            }
+            let current = url.clone().or_else(|| response.request_uri()).unwrap_or_else(|| api_url.clone());
+            let location = redirect_location(&current, response.headers())?;
+            ::log::debug!("Download redirected to {}://{}", location.scheme_str().unwrap_or(""), location.host().unwrap_or(""));
+            response = self.fetch(&api_url, &location, options.offset).await?;
+            url = Some(location);
+            redirects += 1;
+        }
+        let mut response = error_for_status(response).await?;
+
+        let mut downloaded = options.offset;
+        let (mut position, mut total) = body_range(response.status(), response.headers());
+        let mut resumable = accepts_ranges(response.status(), response.headers());
+        let mut resumes = 0;
+        loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => break,
+                Err(err) => match &url {
+                    Some(location) if resumable && resumes < options.max_resumes => {
+                        ::log::info!("Download interrupted ({:?}), resuming from byte {}", err, downloaded);
+                        response = error_for_status(self.fetch(&api_url, location, downloaded).await?).await?;
+                        let (start, size) = body_range(response.status(), response.headers());
+                        position = start;
+                        total = total.or(size);
+                        resumable = accepts_ranges(response.status(), response.headers());
+                        resumes += 1;
+                        continue;
+                    }
+                    _ => return Err(err),
+                },
+            };
+            // Skip any bytes before the offset, if the server ignored the range.
+            let end = position + chunk.len() as u64;
+            if end > downloaded {
+                let skip = downloaded.saturating_sub(position) as usize;
+                writer.write_chunk(&chunk[skip..]).await?;
+                downloaded = end;
+                if let Some(progress) = &mut options.progress {
+                    progress(Progress { downloaded, total });
+                }
+            }
+            position = end;
+        }
+        writer.flush().await?;
+        Ok(downloaded)
+    }
+
+    /// Request a download URL, from byte `offset`.
+    async fn fetch(&self, api_url: &Uri, location: &Uri, offset: u64) -> Result<T::Response, ApiError> {
+        let builder = || {
+            let mut builder = ::http::Request::get(location.clone())
+                .header(USER_AGENT, self.config.user_agent.as_ref());
+            if offset > 0 {
+                builder = builder.header(RANGE, format!("bytes={}-", offset));
+            }
+            builder
+        };
+        if same_origin(api_url, location) {
+            // Don't cache the file in memory.
+            self.execute_with(|theScheme| Ok(builder().with_authentication(theScheme)?.body(Vec::new())?), false)
+                .await
+        } else {
+            self.client.send(builder().body(Vec::new())?).await
+        }
+    }
+}
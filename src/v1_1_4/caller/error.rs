@@ -0,0 +1,16 @@
+use crate::v1_1_4::transport::TransportResponse;
+use crate::v1_1_4::ApiError;
+
+/// Return the response if its status is successful, or an error decoded from the response.
+///
+/// ```ignore
+/// let response = error_for_status(github.repos_get(owner, repo).await?).await?;
+/// ```
+pub async fn error_for_status<Response: TransportResponse>(response: Response) -> Result<Response, ApiError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let response = response.into_bytes().await?;
+    Err(ApiError::from_response(status, response.headers(), response.body()))
+}
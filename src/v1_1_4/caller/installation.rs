@@ -0,0 +1,126 @@
+use ::std::borrow::Cow;
+use ::std::sync::atomic::{AtomicBool, Ordering};
+use ::std::sync::Arc;
+
+use ::authentic::credential::JsonWebTokenCredential;
+use ::authentic::{AuthenticError, AuthenticationProtocol, AuthenticationProtocolConfigure, AuthenticationStep};
+
+use crate::v1_1_4::config::Configuration;
+use crate::v1_1_4::installation::{InstallationCredential, Renewal};
+use crate::v1_1_4::request::apps_create_installation_access_token;
+use crate::v1_1_4::ApiError;
+
+use super::{BearerAuthentication, WithAuthentication};
+
+/// Protocol authenticating as a GitHub App installation.
+///
+/// When the installation access token needs renewing, the `step` method returns a request
+/// for a new token, authenticated using the app JWT. The response must be passed to
+/// `respond` with its body already read into memory.
+pub struct InstallationAuthentication {
+    credential: Arc<InstallationCredential>,
+    app: BearerAuthentication<JsonWebTokenCredential>,
+    token: BearerAuthentication<InstallationCredential>,
+    base_url: Cow<'static, str>,
+    user_agent: Cow<'static, str>,
+    accept: Option<Cow<'static, str>>,
+    renewing: AtomicBool,
+    renewed: bool,
+    failure: Option<String>,
+}
+
+impl InstallationAuthentication {
+    pub fn new(credential: Arc<InstallationCredential>, config: &Configuration) -> Self {
+        Self {
+            app: BearerAuthentication::new(credential.app().clone()),
+            token: BearerAuthentication::new(credential.clone()).with_auth_scheme("token"),
+            credential,
+            base_url: config.base_url.clone(),
+            user_agent: config.user_agent.clone(),
+            accept: config.accept.clone(),
+            renewing: AtomicBool::new(false),
+            renewed: false,
+            failure: None,
+        }
+    }
+
+    fn renewal_request(&self) -> Result<::http::Request<Vec<u8>>, ApiError> {
+        let builder = apps_create_installation_access_token::http_builder(
+            self.base_url.as_ref(),
+            self.credential.installation_id(),
+            self.user_agent.as_ref(),
+            self.accept.as_deref(),
+        )?
+        .with_authentication(&self.app)?;
+        apps_create_installation_access_token::http_request(
+            builder,
+            (&self.credential.request_body()).try_into()?,
+        )
+    }
+}
+
+impl AuthenticationProtocol for InstallationAuthentication {
+    type Request = ::http::Request<Vec<u8>>;
+    type Response = ::http::Response<Vec<u8>>;
+    type Error = ApiError;
+
+    fn step(&self) -> Result<Option<AuthenticationStep<Self::Request>>, AuthenticError> {
+        if let Some(failure) = &self.failure {
+            return Err(AuthenticError::Other(failure.clone()));
+        }
+        if self.renewed {
+            // Use the token renewed by this request, even if it is due for renewal.
+            return Ok(None);
+        }
+        if !self.renewing.load(Ordering::Acquire) {
+            match self.credential.renewal()? {
+                Renewal::Ready => return Ok(None),
+                Renewal::Wait(duration) => return Ok(Some(AuthenticationStep::WaitFor(duration))),
+                Renewal::Renew => self.renewing.store(true, Ordering::Release),
+            }
+        }
+        if let Some(step) = self.app.step()? {
+            return Ok(Some(step));
+        }
+        let request = self
+            .renewal_request()
+            .map_err(|err| AuthenticError::Other(err.to_string()))?;
+        Ok(Some(AuthenticationStep::Request(request)))
+    }
+
+    fn respond(&mut self, response: Result<Self::Response, Self::Error>) {
+        let token = match response {
+            Ok(response) => self.credential.token_from_response(response.status(), response.headers(), response.body()),
+            Err(err) => Err(format!("Failed to create installation access token: {}", err)),
+        };
+        self.renewing.store(false, Ordering::Release);
+        match token {
+            Ok(token) => {
+                self.credential.renewed(Some(token));
+                self.renewed = true;
+            }
+            Err(failure) => {
+                self.credential.renewed(None);
+                self.failure = Some(failure);
+            }
+        }
+    }
+}
+
+impl AuthenticationProtocolConfigure<::http::request::Builder> for InstallationAuthentication {
+    fn configure(
+        &self,
+        builder: ::http::request::Builder,
+    ) -> Result<::http::request::Builder, AuthenticError> {
+        self.token.configure(builder)
+    }
+}
+
+impl Drop for InstallationAuthentication {
+    fn drop(&mut self) {
+        // Allow another request to renew the token if this request was abandoned.
+        if *self.renewing.get_mut() {
+            self.credential.renewed(None);
+        }
+    }
+}
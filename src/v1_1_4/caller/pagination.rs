@@ -0,0 +1,188 @@
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use ::futures_util::stream::{self, Stream, TryStreamExt};
+use ::http::header::{ACCEPT, USER_AGENT};
+use ::http::Uri;
+
+use crate::v1_1_4::pagination::{next_url, page_items, should_continue, truncate};
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, same_origin, Caller, WithAuthentication};
+
+/// Requests successive pages of a list operation.
+///
+/// Created by [`Caller::paginate`].
+pub struct Paginator<'c, T, Sleep, SleepFut, SleepOut, First>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    caller: &'c Caller<T, Sleep, SleepFut, SleepOut>,
+    first: First,
+    max_items: Option<usize>,
+}
+
+/// The request of the next page.
+enum Page<First> {
+    First(First),
+    Next(String),
+}
+
+impl<'c, T, Sleep, SleepFut, SleepOut, First> Paginator<'c, T, Sleep, SleepFut, SleepOut, First>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>,
+    First: Future<Output = Result<T::Response, ApiError>>,
+{
+    /// Stop after returning `max_items` items in total.
+    #[must_use]
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Stream the items of each page, decoded as `U`, one page at a time.
+    pub fn pages<U>(self) -> impl Stream<Item = Result<Vec<U>, ApiError>> + 'c
+    where
+        U: ::serde::de::DeserializeOwned,
+        First: 'c,
+    {
+        let caller = self.caller;
+        stream::try_unfold(
+            (Some(Page::First(self.first)), self.max_items),
+            move |(page, mut remaining)| async move {
+                let (items, next) = match should_continue(page, remaining) {
+                    Some(Page::First(first)) => fetch_page(first, &mut remaining).await?,
+                    Some(Page::Next(url)) => fetch_page(caller.get_page(&url), &mut remaining).await?,
+                    None => return Ok(None),
+                };
+                Ok(Some((items, (next.map(Page::Next), remaining))))
+            },
+        )
+    }
+
+    /// Stream individual items, decoded as `U`, across all pages.
+    pub fn items<U>(self) -> impl Stream<Item = Result<U, ApiError>> + 'c
+    where
+        U: ::serde::de::DeserializeOwned + 'c,
+        First: 'c,
+    {
+        self.pages()
+            .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Iterate over the items of each page, decoded as `U`, completing each request on the
+    /// current thread. Used with a blocking transport.
+    #[cfg(feature = "futures-executor")]
+    pub fn blocking_pages<U>(self) -> impl Iterator<Item = Result<Vec<U>, ApiError>> + 'c
+    where
+        U: ::serde::de::DeserializeOwned,
+        First: 'c,
+    {
+        let Paginator { caller, first, max_items: mut remaining } = self;
+        let mut page = Some(Page::First(first));
+        ::std::iter::from_fn(move || {
+            let fetched = match should_continue(page.take(), remaining)? {
+                Page::First(first) => ::futures_executor::block_on(fetch_page(first, &mut remaining)),
+                Page::Next(url) => ::futures_executor::block_on(fetch_page(caller.get_page(&url), &mut remaining)),
+            };
+            Some(fetched.map(|(items, next)| {
+                page = next.map(Page::Next);
+                items
+            }))
+        })
+    }
+
+    /// Iterate over individual items, decoded as `U`, across all pages, completing each
+    /// request on the current thread. Used with a blocking transport.
+    #[cfg(feature = "futures-executor")]
+    pub fn blocking_items<U>(self) -> impl Iterator<Item = Result<U, ApiError>> + 'c
+    where
+        U: ::serde::de::DeserializeOwned + 'c,
+        First: 'c,
+    {
+        self.blocking_pages().flat_map(|page| match page {
+            Ok(items) => items.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        })
+    }
+}
+
+/// Complete the request of a page, returning its items and the URL of the next page.
+async fn fetch_page<U, ResponseFut, Response>(
+    response: ResponseFut,
+    remaining: &mut Option<usize>,
+) -> Result<(Vec<U>, Option<String>), ApiError>
+where
+    U: ::serde::de::DeserializeOwned,
+    ResponseFut: Future<Output = Result<Response, ApiError>>,
+    Response: TransportResponse,
+{
+    let response = error_for_status(response.await?).await?;
+    let next = next_url(response.headers())?;
+    let response = response.into_bytes().await?;
+    let mut items = page_items(response.body())?;
+    truncate(&mut items, remaining);
+    Ok((items, next))
+}
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Follow the `Link: rel="next"` header across the pages of a list operation.
+    ///
+    /// `first_page` is the call of the list operation returning the first page. Later pages
+    /// are requested using the `rel="next"` URL as given, so operations paginated using
+    /// `page`, `since`, or cursors such as `after` are all followed.
+    ///
+    /// ```ignore
+    /// let repos = github
+    ///     .paginate(github.repos_list_for_org(org, None, &Sort::Default, Some(100), None))
+    ///     .max_items(250)
+    ///     .items::<MinimalRepository>();
+    /// futures_util::pin_mut!(repos);
+    /// while let Some(repo) = repos.try_next().await? {
+    ///     println!("{}", repo.full_name);
+    /// }
+    /// ```
+    pub fn paginate<First>(&self, first_page: First) -> Paginator<'_, T, Sleep, SleepFut, SleepOut, First>
+    where
+        First: Future<Output = Result<T::Response, ApiError>>,
+    {
+        Paginator {
+            caller: self,
+            first: first_page,
+            max_items: None,
+        }
+    }
+
+    /// Request the page at `url`, taken from a `Link` header.
+    ///
+    /// The credentials of the caller are only sent to the host of the API, so a next link
+    /// to another scheme, host or port is rejected.
+    async fn get_page(&self, url: &str) -> Result<T::Response, ApiError> {
+        let uri: Uri = url
+            .parse()
+            .map_err(|err| ApiError::Other(format!("Invalid next link {:?}: {}", url, err)))?;
+        if !same_origin(&self.api_url()?, &uri) {
+            return Err(ApiError::Other(format!("Refusing to follow next link to another host: {}", url)));
+        }
+        self.execute(|theScheme| {
+            let mut builder = ::http::Request::get(uri.clone())
+                .header(USER_AGENT, self.config.user_agent.as_ref());
+            if let Some(accept) = self.config.accept.as_deref() {
+                builder = builder.header(ACCEPT, accept);
+            }
+            Ok(builder.with_authentication(theScheme)?.body(Vec::new())?)
+        })
+        .await
+    }
+}
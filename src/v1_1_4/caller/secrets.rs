@@ -0,0 +1,116 @@
+use ::std::future::Future;
+use ::std::time::Duration;
+
+use crate::v1_1_4::request::{
+    actions_create_or_update_environment_secret, actions_create_or_update_org_secret,
+    actions_create_or_update_repo_secret, codespaces_create_or_update_repo_secret,
+    dependabot_create_or_update_repo_secret,
+};
+use crate::v1_1_4::secrets::{PublicKey, SecretScope, Visibility};
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+use super::{error_for_status, Caller};
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// The public key used to encrypt the secrets of `scope`.
+    ///
+    /// The key is cached, until setting a secret using the key fails.
+    pub async fn secret_public_key(&self, scope: &SecretScope<'_>) -> Result<PublicKey, ApiError> {
+        let cache_key = scope.cache_key();
+        if let Some(key) = self.public_keys.lock().ok().and_then(|keys| keys.get(&cache_key).cloned()) {
+            return Ok(key);
+        }
+        let response = match *scope {
+            SecretScope::Repository { owner, repo } => self.actions_get_repo_public_key(owner, repo).await?,
+            SecretScope::Organization { org, .. } => self.actions_get_org_public_key(org).await?,
+            SecretScope::Environment { repository_id, environment_name } => {
+                self.actions_get_environment_public_key(repository_id, environment_name).await?
+            }
+            SecretScope::DependabotRepository { owner, repo } => self.dependabot_get_repo_public_key(owner, repo).await?,
+            SecretScope::CodespacesRepository { owner, repo } => self.codespaces_get_repo_public_key(owner, repo).await?,
+        };
+        let response = error_for_status(response).await?.into_bytes().await?;
+        let key: PublicKey = ::serde_json::from_slice(response.body())?;
+        if let Ok(mut keys) = self.public_keys.lock() {
+            keys.insert(cache_key, key.clone());
+        }
+        Ok(key)
+    }
+
+    /// Create or update the secret `name` of `scope`, encrypting `plaintext` using the public
+    /// key of the scope.
+    ///
+    /// For an organization secret, the visibility of the scope sets the repositories that can
+    /// access the secret.
+    pub async fn set_secret(
+        &self,
+        scope: &SecretScope<'_>,
+        name: &str,
+        plaintext: &[u8],
+    ) -> Result<T::Response, ApiError> {
+        let key = self.secret_public_key(scope).await?;
+        let encrypted_value = key.seal(plaintext)?;
+        let response = match *scope {
+            SecretScope::Repository { owner, repo } => {
+                let body = actions_create_or_update_repo_secret::body::Json {
+                    encrypted_value: Some(encrypted_value.into()),
+                    key_id: Some(key.key_id.into()),
+                    additionalProperties: Default::default(),
+                };
+                self.actions_create_or_update_repo_secret(owner, repo, name, &body).await?
+            }
+            SecretScope::Organization { org, visibility } => {
+                let mut body = actions_create_or_update_org_secret::body::Json {
+                    encrypted_value: Some(encrypted_value.into()),
+                    key_id: Some(key.key_id.into()),
+                    visibility: visibility.as_str().into(),
+                    selected_repository_ids: None,
+                    additionalProperties: Default::default(),
+                };
+                if let Visibility::Selected(ids) = visibility {
+                    // Repository ids are integers, although described as strings.
+                    body.additionalProperties.insert("selected_repository_ids".into(), ids.into());
+                }
+                self.actions_create_or_update_org_secret(org, name, &body).await?
+            }
+            SecretScope::Environment { repository_id, environment_name } => {
+                let body = actions_create_or_update_environment_secret::body::Json {
+                    encrypted_value: encrypted_value.into(),
+                    key_id: key.key_id.into(),
+                    additionalProperties: Default::default(),
+                };
+                self.actions_create_or_update_environment_secret(repository_id, environment_name, name, &body)
+                    .await?
+            }
+            SecretScope::DependabotRepository { owner, repo } => {
+                let body = dependabot_create_or_update_repo_secret::body::Json {
+                    encrypted_value: Some(encrypted_value.into()),
+                    key_id: Some(key.key_id.into()),
+                    additionalProperties: Default::default(),
+                };
+                self.dependabot_create_or_update_repo_secret(owner, repo, name, &body).await?
+            }
+            SecretScope::CodespacesRepository { owner, repo } => {
+                let body = codespaces_create_or_update_repo_secret::body::Json {
+                    encrypted_value: Some(encrypted_value.into()),
+                    key_id: Some(key.key_id.into()),
+                    additionalProperties: Default::default(),
+                };
+                self.codespaces_create_or_update_repo_secret(owner, repo, name, &body).await?
+            }
+        };
+        if !response.status().is_success() {
+            // The key may have been rotated.
+            if let Ok(mut keys) = self.public_keys.lock() {
+                keys.remove(&scope.cache_key());
+            }
+        }
+        Ok(response)
+    }
+}
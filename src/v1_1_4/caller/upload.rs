@@ -0,0 +1,167 @@
+//! Streaming uploads of release assets.
+
+use ::std::borrow::Cow;
+use ::std::future::Future;
+use ::std::path::Path;
+use ::std::sync::Mutex;
+use ::std::time::Duration;
+
+use ::http::header::{CONTENT_LENGTH, CONTENT_TYPE};
+
+use crate::v1_1_4::transport::{Transport, UploadTransport};
+use crate::v1_1_4::ApiError;
+
+use super::{AuthScheme, Caller, WithAuthentication};
+
+/// Contents of a release asset, read from `Reader` while uploading.
+pub struct Asset<Reader> {
+    reader: Reader,
+    len: u64,
+    content_type: Option<Cow<'static, str>>,
+}
+
+impl<Reader> Asset<Reader> {
+    /// Asset of `len` bytes, read from `reader`.
+    pub fn new(reader: Reader, len: u64) -> Self {
+        Asset {
+            reader,
+            len,
+            content_type: None,
+        }
+    }
+
+    /// Set the media type of the asset. By default, the media type is guessed from the
+    /// extension of the asset name, or is `application/octet-stream`.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<Cow<'static, str>>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Size of the asset in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the asset has no contents.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Asset<::std::fs::File> {
+    /// Asset read from the file at `path`, with a media type guessed from its extension.
+    ///
+    /// The file is read using blocking reads, for the blocking caller. An asynchronous caller
+    /// uses `Asset::open_async`, or wraps the reader of an asynchronous file using
+    /// [`Asset::new`].
+    pub fn open(path: impl AsRef<Path>) -> ::std::io::Result<Self> {
+        let path = path.as_ref();
+        let file = ::std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        Ok(Asset::new(file, len).with_content_type(guess_content_type(path)))
+    }
+}
+
+/// Reader of a Tokio file, as a `futures::io::AsyncRead` for an asynchronous caller.
+#[cfg(all(feature = "tokio", feature = "futures-util"))]
+pub struct TokioFile(::tokio::fs::File);
+
+#[cfg(all(feature = "tokio", feature = "futures-util"))]
+impl ::futures_util::io::AsyncRead for TokioFile {
+    fn poll_read(
+        self: ::std::pin::Pin<&mut Self>,
+        cx: &mut ::std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> ::std::task::Poll<::std::io::Result<usize>> {
+        let mut read = ::tokio::io::ReadBuf::new(buf);
+        ::tokio::io::AsyncRead::poll_read(::std::pin::Pin::new(&mut self.get_mut().0), cx, &mut read)
+            .map_ok(|()| read.filled().len())
+    }
+}
+
+#[cfg(all(feature = "tokio", feature = "futures-util"))]
+impl Asset<TokioFile> {
+    /// Asset read from the file at `path` by an asynchronous caller, with a media type
+    /// guessed from its extension.
+    ///
+    /// The file is read on Tokio's blocking threads, so this must be used within a Tokio
+    /// runtime. Enabled by the `tokio` feature.
+    pub async fn open_async(path: impl AsRef<Path>) -> ::std::io::Result<Self> {
+        let path = path.as_ref();
+        let file = ::tokio::fs::File::open(path).await?;
+        let len = file.metadata().await?.len();
+        Ok(Asset::new(TokioFile(file), len).with_content_type(guess_content_type(path)))
+    }
+}
+
+/// Media type for a file name, guessed from its extension.
+pub fn guess_content_type(name: impl AsRef<Path>) -> String {
+    ::mime_guess::from_path(name).first_or_octet_stream().to_string()
+}
+
+impl<T, Sleep, SleepFut, SleepOut> Caller<T, Sleep, SleepFut, SleepOut>
+where
+    T: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Upload a release asset, reading its contents while sending the request.
+    ///
+    /// Unlike [`Caller::repos_upload_release_asset`], the asset is not read into memory, so
+    /// assets of several gigabytes can be uploaded. Since the contents can only be read once,
+    /// the request is not retried. Instead, a rate limit policy set on the caller waits
+    /// before sending the request if the rate limit is exhausted.
+    ///
+    /// ```ignore
+    /// let asset = Asset::open_async("target/release/app.tar.gz").await?;
+    /// let response = github.upload_release_asset(owner, repo, release_id, "app.tar.gz", None, asset).await?;
+    /// ```
+    pub async fn upload_release_asset<Reader>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        name: &str,
+        label: Option<&str>,
+        asset: Asset<Reader>,
+    ) -> Result<T::Response, ApiError>
+    where
+        T: UploadTransport<Reader>,
+    {
+        let content_type = match asset.content_type {
+            Some(content_type) => content_type,
+            None => guess_content_type(name).into(),
+        };
+        let uploads_url = self.config.uploads_url();
+        let build = |theScheme: &AuthScheme| {
+            Ok(crate::v1_1_4::request::repos_upload_release_asset::http_builder(
+                uploads_url.as_ref(),
+                owner,
+                repo,
+                release_id,
+                name,
+                label,
+                self.config.user_agent.as_ref(),
+                self.config.accept.as_deref(),
+            )?
+            .with_authentication(theScheme)?
+            .header(CONTENT_TYPE, content_type.as_ref())
+            .header(CONTENT_LENGTH, asset.len)
+            .body(())?)
+        };
+        // The contents are read while sending, so the request can only be sent once.
+        let reader = Mutex::new(Some(asset.reader));
+        let send = |request| {
+            let reader = reader.lock().unwrap_or_else(|poison| poison.into_inner()).take();
+            let upload = reader.map(|reader| self.client.send_upload(request, reader, asset.len));
+            async move {
+                match upload {
+                    Some(upload) => upload.await,
+                    None => Err(ApiError::Other("Release asset upload cannot be sent again".to_owned())),
+                }
+            }
+        };
+        self.execute_sending(build, send, false, false).await
+    }
+}
@@ -0,0 +1,153 @@
+//! Check runs with any number of annotations.
+//!
+//! `checks_create` and `checks_update` accept at most 50 annotations per request.
+//! [`Caller::start_check_run`](crate::v1_1_4::caller::Caller::start_check_run) creates an
+//! `in_progress` check run and returns a
+//! [`CheckRunReporter`](crate::v1_1_4::caller::CheckRunReporter), which sends annotations in
+//! batches of 50, and completes the run with a conclusion derived from the annotation
+//! levels:
+//!
+//! ```ignore
+//! let mut run = github.start_check_run("octocat", "hello-world", "lint", head_sha).await?;
+//! for finding in findings {
+//!     run.annotate(annotation(finding)).await?;
+//! }
+//! run.set_summary(format!("{} findings", findings.len()));
+//! run.finish().await?;
+//! ```
+
+use ::std::borrow::Cow;
+
+use crate::v1_1_4::request::{checks_create, checks_update};
+
+/// An annotation of a check run.
+pub type Annotation<'a> = checks_create::body::json::output::Annotations<'a>;
+
+/// Maximum number of annotations in a `checks_create` or `checks_update` request.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+/// Maximum number of characters of the `summary` and `text` of a check run output.
+pub const MAX_OUTPUT_LENGTH: usize = 65535;
+
+const TRUNCATED: &str = "\n\n**Output truncated.**";
+
+/// Level of an annotation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+}
+
+impl AnnotationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationLevel::Notice => "notice",
+            AnnotationLevel::Warning => "warning",
+            AnnotationLevel::Failure => "failure",
+        }
+    }
+
+    /// The level of `annotation_level`, or `None` if it is not a known level.
+    pub fn parse(annotation_level: &str) -> Option<Self> {
+        match annotation_level {
+            "notice" => Some(AnnotationLevel::Notice),
+            "warning" => Some(AnnotationLevel::Warning),
+            "failure" => Some(AnnotationLevel::Failure),
+            _ => None,
+        }
+    }
+}
+
+/// Conclusion of a completed check run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Conclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    TimedOut,
+}
+
+impl Conclusion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Conclusion::ActionRequired => "action_required",
+            Conclusion::Cancelled => "cancelled",
+            Conclusion::Failure => "failure",
+            Conclusion::Neutral => "neutral",
+            Conclusion::Success => "success",
+            Conclusion::Skipped => "skipped",
+            Conclusion::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Number of annotations of each level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnnotationCounts {
+    pub notice: usize,
+    pub warning: usize,
+    pub failure: usize,
+}
+
+impl AnnotationCounts {
+    pub(crate) fn add(&mut self, annotation_level: &str) {
+        match AnnotationLevel::parse(annotation_level) {
+            Some(AnnotationLevel::Notice) => self.notice += 1,
+            Some(AnnotationLevel::Warning) => self.warning += 1,
+            Some(AnnotationLevel::Failure) => self.failure += 1,
+            None => {}
+        }
+    }
+
+    /// `failure` if any annotation is a failure, `neutral` if any is a warning, and
+    /// `success` otherwise.
+    pub fn conclusion(&self) -> Conclusion {
+        if self.failure > 0 {
+            Conclusion::Failure
+        } else if self.warning > 0 {
+            Conclusion::Neutral
+        } else {
+            Conclusion::Success
+        }
+    }
+
+    /// Summary of the counts, used when no summary is given.
+    pub(crate) fn summary(&self) -> String {
+        format!(
+            "{} failures, {} warnings, {} notices",
+            self.failure, self.warning, self.notice,
+        )
+    }
+}
+
+/// `output` truncated to [`MAX_OUTPUT_LENGTH`] characters, ending with a notice if truncated.
+pub fn truncate_output(output: &str) -> Cow<'_, str> {
+    match output.char_indices().nth(MAX_OUTPUT_LENGTH) {
+        None => Cow::Borrowed(output),
+        Some(_) => {
+            let keep = MAX_OUTPUT_LENGTH - TRUNCATED.chars().count();
+            let end = output.char_indices().nth(keep).map_or(output.len(), |(end, _)| end);
+            Cow::Owned(format!("{}{}", &output[..end], TRUNCATED))
+        }
+    }
+}
+
+/// `annotation`, as sent by `checks_update`.
+pub(crate) fn update_annotation<'a>(annotation: &'a Annotation<'_>) -> checks_update::body::json::output::Annotations<'a> {
+    checks_update::body::json::output::Annotations {
+        path: annotation.path.as_ref().into(),
+        start_line: annotation.start_line,
+        end_line: annotation.end_line,
+        start_column: annotation.start_column,
+        end_column: annotation.end_column,
+        annotation_level: annotation.annotation_level.as_ref().into(),
+        message: annotation.message.as_ref().into(),
+        title: annotation.title.as_deref().map(Into::into),
+        raw_details: annotation.raw_details.as_deref().map(Into::into),
+        additionalProperties: Default::default(),
+    }
+}
@@ -0,0 +1,186 @@
+//! Upload of code scanning analyses in SARIF format.
+//!
+//! `code_scanning_upload_sarif` expects the SARIF document compressed using gzip and base64
+//! encoded, and processes it asynchronously.
+//! [`Caller::upload_sarif`](crate::v1_1_4::caller::Caller::upload_sarif) checks and encodes
+//! the document, uploads it, and polls `code_scanning_get_sarif` until it is processed:
+//!
+//! ```ignore
+//! let sarif = File::open("results.sarif")?;
+//! let options = SarifOptions::new().with_tool_name("clippy");
+//! let status = github.upload_sarif("octocat", "hello-world", head_sha, "refs/heads/main", sarif, &options).await?;
+//! ```
+
+use ::std::io::{Read, Write};
+use ::std::time::Duration;
+
+use ::base64::engine::general_purpose::STANDARD;
+use ::base64::Engine;
+use ::flate2::write::GzEncoder;
+use ::flate2::Compression;
+
+use crate::v1_1_4::ApiError;
+
+/// Largest SARIF document accepted by GitHub, after compression using gzip.
+pub const MAX_SARIF_SIZE: usize = 10 * 1024 * 1024;
+
+/// Largest number of runs in a SARIF document.
+pub const MAX_RUNS: usize = 20;
+
+/// Largest number of results in a run of a SARIF document.
+pub const MAX_RESULTS_PER_RUN: usize = 25_000;
+
+/// Largest number of rules of the tool of a run.
+pub const MAX_RULES_PER_RUN: usize = 25_000;
+
+/// Largest number of extensions of the tool of a run.
+pub const MAX_EXTENSIONS_PER_RUN: usize = 100;
+
+/// Options of [`Caller::upload_sarif`](crate::v1_1_4::caller::Caller::upload_sarif).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SarifOptions<'a> {
+    /// Base directory of the analysis, as it appears in the SARIF document, e.g.
+    /// `file:///github/workspace/`.
+    pub checkout_uri: Option<&'a str>,
+    /// Time the analysis started, in ISO 8601 format.
+    pub started_at: Option<&'a str>,
+    /// Name of the tool, instead of the name in the SARIF document.
+    pub tool_name: Option<&'a str>,
+    /// Time between checks of the processing status. Defaults to 5 seconds, and must not be
+    /// zero.
+    pub poll_interval: Duration,
+    /// Longest time to wait for the upload to be processed. Defaults to 10 minutes.
+    pub timeout: Duration,
+}
+
+impl<'a> SarifOptions<'a> {
+    pub fn new() -> Self {
+        SarifOptions {
+            checkout_uri: None,
+            started_at: None,
+            tool_name: None,
+            poll_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    pub fn with_checkout_uri(self, checkout_uri: &'a str) -> Self {
+        SarifOptions { checkout_uri: Some(checkout_uri), ..self }
+    }
+
+    pub fn with_started_at(self, started_at: &'a str) -> Self {
+        SarifOptions { started_at: Some(started_at), ..self }
+    }
+
+    pub fn with_tool_name(self, tool_name: &'a str) -> Self {
+        SarifOptions { tool_name: Some(tool_name), ..self }
+    }
+
+    pub fn with_poll_interval(self, poll_interval: Duration) -> Self {
+        SarifOptions { poll_interval, ..self }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        SarifOptions { timeout, ..self }
+    }
+}
+
+impl<'a> Default for SarifOptions<'a> {
+    fn default() -> Self {
+        SarifOptions::new()
+    }
+}
+
+/// Processing status of an uploaded SARIF document.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct SarifStatus {
+    /// `pending`, `complete` or `failed`.
+    pub processing_status: String,
+    /// URL of the analyses of the upload, once complete.
+    #[serde(default)]
+    pub analyses_url: Option<String>,
+    #[serde(default)]
+    pub errors: Option<Vec<String>>,
+}
+
+#[derive(::serde::Deserialize)]
+struct Document {
+    version: String,
+    runs: Vec<Run>,
+}
+
+#[derive(::serde::Deserialize)]
+struct Run {
+    tool: Tool,
+    #[serde(default)]
+    results: Vec<::serde::de::IgnoredAny>,
+}
+
+#[derive(::serde::Deserialize)]
+struct Tool {
+    driver: Driver,
+    #[serde(default)]
+    extensions: Vec<::serde::de::IgnoredAny>,
+}
+
+#[derive(::serde::Deserialize)]
+struct Driver {
+    name: String,
+    #[serde(default)]
+    rules: Vec<::serde::de::IgnoredAny>,
+}
+
+/// Check a count of items in a SARIF document against the limit of GitHub.
+fn check_limit(count: usize, limit: usize, items: &str) -> Result<(), ApiError> {
+    if count > limit {
+        return Err(ApiError::Other(format!("SARIF document has {} {}, more than {}", count, items, limit)));
+    }
+    Ok(())
+}
+
+/// Check that `sarif` is a SARIF 2.1.0 document, with at least one run, each naming its tool.
+///
+/// The limits of GitHub that reject an upload are also checked: at most [`MAX_RUNS`] runs,
+/// each with at most [`MAX_RESULTS_PER_RUN`] results, [`MAX_RULES_PER_RUN`] rules and
+/// [`MAX_EXTENSIONS_PER_RUN`] tool extensions. GitHub truncates, rather than rejects, results
+/// with too many locations or thread flow locations and rules with too many tags, so these
+/// are not checked.
+pub fn validate_sarif(sarif: &[u8]) -> Result<(), ApiError> {
+    let document: Document = ::serde_json::from_slice(sarif)
+        .map_err(|err| ApiError::Other(format!("Invalid SARIF document: {}", err)))?;
+    if document.version != "2.1.0" {
+        return Err(ApiError::Other(format!("Unsupported SARIF version {:?}", document.version)));
+    }
+    if document.runs.is_empty() {
+        return Err(ApiError::Other("SARIF document has no runs".to_owned()));
+    }
+    check_limit(document.runs.len(), MAX_RUNS, "runs")?;
+    for run in &document.runs {
+        if run.tool.driver.name.is_empty() {
+            return Err(ApiError::Other("SARIF run has no tool name".to_owned()));
+        }
+        check_limit(run.results.len(), MAX_RESULTS_PER_RUN, "results in a run")?;
+        check_limit(run.tool.driver.rules.len(), MAX_RULES_PER_RUN, "rules in a run")?;
+        check_limit(run.tool.extensions.len(), MAX_EXTENSIONS_PER_RUN, "tool extensions in a run")?;
+    }
+    Ok(())
+}
+
+/// Read and validate the SARIF document `sarif`, returning it compressed using gzip and base64
+/// encoded, as expected by `code_scanning_upload_sarif`.
+pub fn encode_sarif<R: Read>(mut sarif: R) -> Result<String, ApiError> {
+    let mut document = Vec::new();
+    sarif.read_to_end(&mut document)?;
+    validate_sarif(&document)?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&document)?;
+    let compressed = encoder.finish()?;
+    if compressed.len() > MAX_SARIF_SIZE {
+        return Err(ApiError::Other(format!(
+            "Compressed SARIF document of {} bytes is larger than {} bytes",
+            compressed.len(),
+            MAX_SARIF_SIZE,
+        )));
+    }
+    Ok(STANDARD.encode(compressed))
+}
@@ -0,0 +1,189 @@
+//! Commits of several files, using the Git Data API.
+//!
+//! A [`CommitBuilder`] stages changes on top of a branch.
+//! [`Caller::commit_changes`](crate::v1_1_4::caller::Caller::commit_changes) uploads the
+//! contents of the files, creates a tree on top of the tree of the branch and a commit, and
+//! fast-forwards the branch, or creates it:
+//!
+//! ```ignore
+//! let changes = CommitBuilder::new("main", "Release 1.0")
+//!     .modify("Cargo.toml", manifest)
+//!     .add("CHANGELOG.md", changelog)
+//!     .rename("scripts/release", "bin/release")
+//!     .set_executable("bin/release", true);
+//! let commit = github.commit_changes("octocat", "hello-world", &changes).await?;
+//! ```
+
+use ::std::borrow::Cow;
+use ::std::collections::{BTreeMap, BTreeSet};
+
+use crate::v1_1_4::contents::Signature;
+
+/// Mode of a file in a Git tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileMode {
+    Regular,
+    Executable,
+}
+
+impl FileMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileMode::Regular => "100644",
+            FileMode::Executable => "100755",
+        }
+    }
+}
+
+/// A staged change of a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Staged<'a> {
+    Delete,
+    File {
+        source: Source<'a>,
+        /// Mode of the file, or `None` to keep the mode of the existing file.
+        mode: Option<FileMode>,
+    },
+}
+
+/// The contents of a staged file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Source<'a> {
+    /// New contents.
+    Content(Cow<'a, [u8]>),
+    /// The contents of an existing file, at the given path of the base tree.
+    Existing(String),
+}
+
+/// Changes to commit on top of a branch.
+#[derive(Clone, Debug)]
+pub struct CommitBuilder<'a> {
+    pub(crate) branch: &'a str,
+    pub(crate) base: Option<&'a str>,
+    pub(crate) message: &'a str,
+    pub(crate) author: Option<Signature<'a>>,
+    pub(crate) committer: Option<Signature<'a>>,
+    pub(crate) max_retries: usize,
+    pub(crate) changes: BTreeMap<String, Staged<'a>>,
+    /// Paths staged by `add` or `rename`, which are not taken from the base tree.
+    created: BTreeSet<String>,
+}
+
+impl<'a> CommitBuilder<'a> {
+    /// Changes to commit to `branch`, with the commit message `message`.
+    pub fn new(branch: &'a str, message: &'a str) -> Self {
+        CommitBuilder {
+            branch,
+            base: None,
+            message,
+            author: None,
+            committer: None,
+            max_retries: 3,
+            changes: BTreeMap::new(),
+            created: BTreeSet::new(),
+        }
+    }
+
+    /// Create `branch` from the branch `base` if it does not exist. Otherwise, committing
+    /// to a branch that does not exist fails.
+    #[must_use]
+    pub fn base(mut self, base: &'a str) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    #[must_use]
+    pub fn author(mut self, author: Signature<'a>) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    #[must_use]
+    pub fn committer(mut self, committer: Signature<'a>) -> Self {
+        self.committer = Some(committer);
+        self
+    }
+
+    /// Commit again on top of the new head of the branch, up to `max_retries` times, if the
+    /// branch moves before it is updated. Defaults to 3.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Add a regular file at `path`.
+    #[must_use]
+    pub fn add(mut self, path: &str, content: impl Into<Cow<'a, [u8]>>) -> Self {
+        let source = Source::Content(content.into());
+        self.changes.insert(path.to_owned(), Staged::File { source, mode: Some(FileMode::Regular) });
+        self.created.insert(path.to_owned());
+        self
+    }
+
+    /// Replace the contents of the file at `path`, keeping its mode.
+    #[must_use]
+    pub fn modify(mut self, path: &str, content: impl Into<Cow<'a, [u8]>>) -> Self {
+        let content = content.into();
+        match self.changes.get_mut(path) {
+            Some(Staged::File { source, .. }) => *source = Source::Content(content),
+            _ => {
+                let source = Source::Content(content);
+                self.changes.insert(path.to_owned(), Staged::File { source, mode: None });
+            }
+        }
+        self
+    }
+
+    /// Delete the file at `path`.
+    #[must_use]
+    pub fn delete(mut self, path: &str) -> Self {
+        self.unstage(path);
+        self
+    }
+
+    /// Move the file at `from` to `to`, with any change staged for `from`.
+    #[must_use]
+    pub fn rename(mut self, from: &str, to: &str) -> Self {
+        let staged = self.changes.get(from).cloned().unwrap_or_else(|| Staged::File {
+            source: Source::Existing(from.to_owned()),
+            mode: None,
+        });
+        self.unstage(from);
+        if staged != Staged::Delete {
+            self.changes.insert(to.to_owned(), staged);
+            self.created.insert(to.to_owned());
+        }
+        self
+    }
+
+    /// Remove `path` from the tree: drop the change of a path staged by `add` or `rename`,
+    /// or delete a path of the base tree.
+    fn unstage(&mut self, path: &str) {
+        if self.created.remove(path) {
+            self.changes.remove(path);
+        } else {
+            self.changes.insert(path.to_owned(), Staged::Delete);
+        }
+    }
+
+    /// Set or clear the executable bit of the file at `path`.
+    #[must_use]
+    pub fn set_executable(mut self, path: &str, executable: bool) -> Self {
+        let mode = if executable { FileMode::Executable } else { FileMode::Regular };
+        match self.changes.get_mut(path) {
+            Some(Staged::File { mode: staged, .. }) => *staged = Some(mode),
+            Some(Staged::Delete) => {}
+            None => {
+                let source = Source::Existing(path.to_owned());
+                self.changes.insert(path.to_owned(), Staged::File { source, mode: Some(mode) });
+            }
+        }
+        self
+    }
+
+    /// Whether no change is staged.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
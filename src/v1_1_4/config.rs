@@ -1,18 +1,40 @@
 use std::borrow::Cow;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::v1_1_4::cache::ResponseCache;
+
+#[derive(Clone)]
 pub enum Authentication {
     None,
     AccessToken(Arc<::authentic::credential::TokenCredential>),
     Basic(Arc<::authentic::credential::UsernamePasswordCredential>),
     JWT(Arc<::authentic::credential::JsonWebTokenCredential>),
+    /// Authenticate as a GitHub App installation, using installation access tokens that
+    /// are created and renewed as required.
+    Installation(Arc<crate::v1_1_4::installation::InstallationCredential>),
 }
 
+#[derive(Clone)]
 pub struct Configuration {
     pub authentication: Authentication,
     pub base_url: Cow<'static, str>,
+    /// Base URL of the host receiving release asset uploads. If empty, it is derived from
+    /// `base_url`, see [`Configuration::uploads_url`].
+    pub uploads_base_url: Cow<'static, str>,
+    /// URL of the GraphQL API.
+    pub graphql_url: Cow<'static, str>,
+    /// Connect to a GitHub Enterprise Server, checking that the server supports each
+    /// operation before sending it. See [`crate::v1_1_4::enterprise`].
+    pub enterprise_server: bool,
     pub user_agent: Cow<'static, str>,
     pub accept: Option<Cow<'static, str>>,
+    /// Retry requests that fail with a transient error. `None` returns all errors.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Cache responses to `GET` requests, and revalidate them using conditional requests.
+    pub cache: Option<Arc<dyn ResponseCache>>,
 }
 
 impl Default for Configuration {
@@ -20,8 +42,113 @@ impl Default for Configuration {
         Self {
             authentication: Authentication::None,
             base_url: Cow::default(),
+            uploads_base_url: Cow::default(),
+            graphql_url: "https://api.github.com/graphql".into(),
+            enterprise_server: false,
             user_agent: "jinxapi-github/0.1.0".into(),
             accept: Some("application/vnd.github.v3+json".into()),
+            retry_policy: None,
+            cache: None,
         }
     }
+}
+
+impl Configuration {
+    /// Configuration for the GitHub Enterprise Server at `host`, e.g. `github.example.com`,
+    /// using its API at `/api/v3`, uploads at `/api/uploads` and GraphQL API at `/api/graphql`.
+    ///
+    /// The host can include a scheme, e.g. `http://github.example.com`. The default scheme
+    /// is HTTPS.
+    pub fn enterprise(host: &str) -> Self {
+        let host = host.trim_end_matches('/');
+        let origin = if host.contains("://") {
+            host.to_owned()
+        } else {
+            format!("https://{}", host)
+        };
+        Self {
+            base_url: format!("{}/api/v3", origin).into(),
+            uploads_base_url: format!("{}/api/uploads", origin).into(),
+            graphql_url: format!("{}/api/graphql", origin).into(),
+            enterprise_server: true,
+            ..Default::default()
+        }
+    }
+
+    /// Base URL used for release asset uploads.
+    ///
+    /// If `uploads_base_url` is empty, uploads for GitHub.com use
+    /// `https://uploads.github.com`, and uploads for a `base_url` ending with `/api/v3` use
+    /// `/api/uploads` on the same host, as for [`Configuration::enterprise`]. Uploads for any
+    /// other `base_url`, such as a proxy or a test server, are sent to `base_url`.
+    pub fn uploads_url(&self) -> Cow<'_, str> {
+        if !self.uploads_base_url.is_empty() {
+            return Cow::Borrowed(self.uploads_base_url.as_ref());
+        }
+        let base_url = self.base_url.trim_end_matches('/');
+        if base_url.is_empty() || base_url == "https://api.github.com" {
+            return Cow::Borrowed("https://uploads.github.com");
+        }
+        match base_url.strip_suffix("/api/v3") {
+            Some(origin) => Cow::Owned(format!("{}/api/uploads", origin)),
+            None => Cow::Borrowed(self.base_url.as_ref()),
+        }
+    }
+}
+
+/// Retry requests that fail with a connection error or a `502 Bad Gateway`,
+/// `503 Service Unavailable` or `504 Gateway Timeout` response.
+///
+/// Retries wait for an exponentially increasing, jittered, backoff time. Only idempotent
+/// requests (`GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE`) are retried, unless
+/// `retry_non_idempotent` is set.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of times to retry a request.
+    pub max_retries: u32,
+    /// Backoff time before the first retry.
+    pub initial_backoff: Duration,
+    /// Maximum backoff time before a retry.
+    pub max_backoff: Duration,
+    /// Also retry non-idempotent requests (e.g. `POST`), which may have taken effect
+    /// despite the error.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the time to wait before retry number `attempt + 1` of a request using
+    /// `method`, or `None` if the request should not be retried.
+    pub fn backoff(&self, attempt: u32, method: &::http::Method) -> Option<Duration> {
+        if attempt >= self.max_retries || !(self.retry_non_idempotent || method.is_idempotent()) {
+            return None;
+        }
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        // Wait between half and all of the backoff time, so that clients failing together
+        // do not retry together.
+        let jitter = RandomState::new().build_hasher().finish() % 1024;
+        Some(backoff / 2 + backoff / 2 * jitter as u32 / 1023)
+    }
+
+    pub(crate) fn is_transient(status: ::http::StatusCode) -> bool {
+        matches!(
+            status,
+            ::http::StatusCode::BAD_GATEWAY
+                | ::http::StatusCode::SERVICE_UNAVAILABLE
+                | ::http::StatusCode::GATEWAY_TIMEOUT
+        )
+    }
 }
\ No newline at end of file
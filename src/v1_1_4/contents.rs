@@ -0,0 +1,148 @@
+//! Repository contents.
+//!
+//! [`Caller::read_file`](crate::v1_1_4::caller::Caller::read_file),
+//! [`Caller::write_file`](crate::v1_1_4::caller::Caller::write_file),
+//! [`Caller::delete_file`](crate::v1_1_4::caller::Caller::delete_file) and
+//! [`Caller::list_dir`](crate::v1_1_4::caller::Caller::list_dir) wrap the contents
+//! operations, encoding and decoding the base64 contents of files, and looking up the blob
+//! `sha` required to update or delete a file:
+//!
+//! ```ignore
+//! let file = github.read_file("octocat", "hello-world", "README.md", None).await?;
+//! let mut readme = file.content;
+//! readme.extend_from_slice(b"\nMore text.\n");
+//! github.write_file("octocat", "hello-world", "README.md", &readme, &CommitOptions::new("Update README")).await?;
+//! ```
+
+use ::base64::engine::general_purpose::STANDARD;
+use ::base64::Engine;
+
+use ::serde_json::Value;
+
+use crate::v1_1_4::schema::{ContentFile, ContentSubmodule, ContentSymlink};
+use crate::v1_1_4::ApiError;
+
+/// Largest file whose contents are returned by `repos_get_content`. Larger files are read
+/// using `git_get_blob`.
+pub const MAX_CONTENT_SIZE: i64 = 1024 * 1024;
+
+/// A file read by [`Caller::read_file`](crate::v1_1_4::caller::Caller::read_file).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    /// The file as returned by `repos_get_content`. Its `content` is left base64 encoded,
+    /// and is empty for files larger than 1 MB.
+    pub entry: ContentFile<'static>,
+    /// Decoded contents of the file.
+    pub content: Vec<u8>,
+}
+
+/// Name and email of the author or committer of a change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<'a> {
+    pub name: &'a str,
+    pub email: &'a str,
+}
+
+/// Options of the commit created by [`Caller::write_file`](crate::v1_1_4::caller::Caller::write_file) or
+/// [`Caller::delete_file`](crate::v1_1_4::caller::Caller::delete_file).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitOptions<'a> {
+    pub message: &'a str,
+    /// Branch to commit to, instead of the default branch of the repository.
+    pub branch: Option<&'a str>,
+    /// Expected blob `sha` of the file. If set, the change fails with a `409 Conflict` error
+    /// when the file has been changed. Otherwise, the current `sha` is looked up, and looked
+    /// up again if the file changes before the commit.
+    pub sha: Option<&'a str>,
+    pub committer: Option<Signature<'a>>,
+    pub author: Option<Signature<'a>>,
+    /// Number of times a conflicting change is retried. Defaults to 3.
+    pub max_retries: u32,
+}
+
+impl<'a> CommitOptions<'a> {
+    pub fn new(message: &'a str) -> Self {
+        CommitOptions {
+            message,
+            branch: None,
+            sha: None,
+            committer: None,
+            author: None,
+            max_retries: 3,
+        }
+    }
+
+    pub fn with_branch(self, branch: &'a str) -> Self {
+        CommitOptions { branch: Some(branch), ..self }
+    }
+
+    pub fn with_sha(self, sha: &'a str) -> Self {
+        CommitOptions { sha: Some(sha), ..self }
+    }
+
+    pub fn with_committer(self, committer: Signature<'a>) -> Self {
+        CommitOptions { committer: Some(committer), ..self }
+    }
+
+    pub fn with_author(self, author: Signature<'a>) -> Self {
+        CommitOptions { author: Some(author), ..self }
+    }
+
+    pub fn with_max_retries(self, max_retries: u32) -> Self {
+        CommitOptions { max_retries, ..self }
+    }
+}
+
+/// A `repos_get_content` response: the entries of a directory, or a single file, symbolic
+/// link or submodule.
+#[derive(Debug)]
+pub(crate) enum Contents {
+    Dir(Vec<ContentFile<'static>>),
+    File(Box<ContentFile<'static>>),
+    Symlink(Box<ContentSymlink<'static>>),
+    Submodule(Box<ContentSubmodule<'static>>),
+}
+
+impl Contents {
+    /// Decode a `repos_get_content` response, according to its `type`.
+    pub(crate) fn from_slice(body: &[u8]) -> Result<Self, ApiError> {
+        let contents = match ::serde_json::from_slice(body)? {
+            Value::Array(entries) => Contents::Dir(
+                entries
+                    .into_iter()
+                    .map(dir_entry)
+                    .collect::<Result<_, _>>()?,
+            ),
+            entry => match entry.get("type").and_then(Value::as_str) {
+                Some("symlink") => Contents::Symlink(::serde_json::from_value(entry)?),
+                Some("submodule") => Contents::Submodule(::serde_json::from_value(entry)?),
+                _ => Contents::File(::serde_json::from_value(entry)?),
+            },
+        };
+        Ok(contents)
+    }
+}
+
+/// Decode an entry of a directory. Entries are returned without their `content` and
+/// `encoding`, which are left empty.
+fn dir_entry(mut entry: Value) -> Result<ContentFile<'static>, ApiError> {
+    if let Value::Object(fields) = &mut entry {
+        for field in ["content", "encoding"] {
+            fields.entry(field).or_insert_with(|| Value::String(String::new()));
+        }
+    }
+    Ok(::serde_json::from_value(entry)?)
+}
+
+/// Decode base64 `content`, as returned by GitHub with line breaks.
+pub fn decode(content: &str) -> Result<Vec<u8>, ApiError> {
+    let content: String = content.split_ascii_whitespace().collect();
+    STANDARD
+        .decode(content)
+        .map_err(|err| ApiError::Other(format!("Invalid base64 content: {}", err)))
+}
+
+/// Encode `content` in base64, as expected by `repos_create_or_update_file_contents`.
+pub fn encode(content: &[u8]) -> String {
+    STANDARD.encode(content)
+}
@@ -0,0 +1,206 @@
+//! Check run annotations from the diagnostics of `cargo` and `rustc`.
+//!
+//! [`Annotator`] reads the `compiler-message` lines of `cargo build`, `cargo clippy` or
+//! `cargo test` run with `--message-format=json`, and returns an annotation for each
+//! diagnostic, with its path relative to the root of the repository:
+//!
+//! ```ignore
+//! let output = Command::new("cargo").args(["clippy", "--message-format=json"]).output()?;
+//! let annotations = Annotator::new(repository_root).annotations(&output.stdout[..])?;
+//! let mut run = github.start_check_run("octocat", "hello-world", "clippy", head_sha).await?;
+//! run.annotate_all(annotations).await?;
+//! run.finish().await?;
+//! ```
+
+use ::std::collections::HashSet;
+use ::std::io::BufRead;
+use ::std::path::{Component, Path, PathBuf};
+
+use crate::v1_1_4::checks::{Annotation, AnnotationLevel};
+use crate::v1_1_4::ApiError;
+
+/// A line of `cargo --message-format=json` output.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+#[serde(tag = "reason")]
+pub enum CargoMessage {
+    #[serde(rename = "compiler-message")]
+    CompilerMessage { message: Diagnostic },
+    #[serde(other)]
+    Other,
+}
+
+/// A diagnostic of `rustc`, as printed by `--error-format=json`.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+    /// `error`, `warning`, `note`, `help`, `failure-note` or
+    /// `error: internal compiler error`.
+    pub level: String,
+    pub spans: Vec<DiagnosticSpan>,
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+    #[serde(default)]
+    pub rendered: Option<String>,
+}
+
+/// The lint or error code of a diagnostic, e.g. `E0308` or `clippy::needless_return`.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+/// A source location of a diagnostic. Lines and columns start at 1, and `column_end` is
+/// exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub line_start: i64,
+    pub line_end: i64,
+    pub column_start: i64,
+    pub column_end: i64,
+    pub is_primary: bool,
+    #[serde(default)]
+    pub expansion: Option<Box<DiagnosticExpansion>>,
+}
+
+/// The macro expansion producing a span.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct DiagnosticExpansion {
+    /// The macro invocation.
+    pub span: DiagnosticSpan,
+}
+
+/// The annotation level of a `rustc` diagnostic level, or `None` for levels that are not
+/// reported.
+pub fn annotation_level(level: &str) -> Option<AnnotationLevel> {
+    match level {
+        "error" | "error: internal compiler error" => Some(AnnotationLevel::Failure),
+        "warning" => Some(AnnotationLevel::Warning),
+        "note" | "help" => Some(AnnotationLevel::Notice),
+        _ => None,
+    }
+}
+
+/// Converts diagnostics to annotations.
+#[derive(Clone, Debug)]
+pub struct Annotator {
+    repository_root: PathBuf,
+    workspace_root: PathBuf,
+}
+
+impl Annotator {
+    /// Annotations for the repository checked out at `repository_root`, which is also the
+    /// root of the cargo workspace.
+    pub fn new(repository_root: impl Into<PathBuf>) -> Self {
+        let repository_root = normalize(&repository_root.into());
+        Annotator {
+            workspace_root: repository_root.clone(),
+            repository_root,
+        }
+    }
+
+    /// Root of the cargo workspace, against which `rustc` reports relative paths. A relative
+    /// `workspace_root` is relative to the root of the repository.
+    #[must_use]
+    pub fn workspace_root(mut self, workspace_root: impl AsRef<Path>) -> Self {
+        self.workspace_root = normalize(&self.repository_root.join(workspace_root));
+        self
+    }
+
+    /// The annotations of the `compiler-message` lines of `reader`, skipping other lines,
+    /// diagnostics without a location in the repository, and duplicate diagnostics.
+    pub fn annotations<R: BufRead>(&self, reader: R) -> Result<Vec<Annotation<'static>>, ApiError> {
+        let mut seen = HashSet::new();
+        let mut annotations = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let diagnostic = match ::serde_json::from_str(&line) {
+                Ok(CargoMessage::CompilerMessage { message }) => message,
+                _ => continue,
+            };
+            if let Some(annotation) = self.annotation(&diagnostic) {
+                let key = (
+                    annotation.path.clone(),
+                    annotation.start_line,
+                    annotation.start_column,
+                    annotation.message.clone(),
+                );
+                if seen.insert(key) {
+                    annotations.push(annotation);
+                }
+            }
+        }
+        Ok(annotations)
+    }
+
+    /// The annotation of `diagnostic`, at its primary span, or at the invocation of the
+    /// macro producing the span if the macro is outside the repository.
+    pub fn annotation(&self, diagnostic: &Diagnostic) -> Option<Annotation<'static>> {
+        let level = annotation_level(&diagnostic.level)?;
+        let (span, path) = diagnostic
+            .spans
+            .iter()
+            .filter(|span| span.is_primary)
+            .find_map(|span| self.locate(span))?;
+        let single_line = span.line_start == span.line_end;
+        Some(Annotation {
+            path: path.into(),
+            start_line: span.line_start,
+            end_line: span.line_end,
+            // Columns are only accepted on a single line.
+            start_column: single_line.then_some(span.column_start),
+            end_column: single_line.then_some((span.column_end - 1).max(span.column_start)),
+            annotation_level: level.as_str().into(),
+            message: diagnostic.message.clone().into(),
+            title: diagnostic.code.as_ref().map(|code| code.code.clone().into()),
+            raw_details: diagnostic.rendered.clone().map(Into::into),
+            ..Default::default()
+        })
+    }
+
+    /// `span`, or the macro invocation producing it, in the repository, with its path
+    /// relative to the root of the repository.
+    fn locate<'s>(&self, mut span: &'s DiagnosticSpan) -> Option<(&'s DiagnosticSpan, String)> {
+        loop {
+            if let Some(path) = self.relative_path(&span.file_name) {
+                return Some((span, path));
+            }
+            span = &span.expansion.as_ref()?.span;
+        }
+    }
+
+    fn relative_path(&self, file_name: &str) -> Option<String> {
+        let path = normalize(&self.workspace_root.join(file_name));
+        let relative = path.strip_prefix(&self.repository_root).ok()?;
+        let components: Vec<_> = relative
+            .components()
+            .map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+        (!components.is_empty()).then(|| components.join("/"))
+    }
+}
+
+/// `path` without `.` components, and with `..` components applied to the preceding
+/// component.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => normalized.push(component),
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -0,0 +1,144 @@
+//! GitHub Enterprise Server.
+//!
+//! [`Configuration::enterprise`](crate::v1_1_4::config::Configuration::enterprise) configures
+//! a caller for a GitHub Enterprise Server. The first time an operation needs a feature that
+//! is not available on every server, the caller reads the version of the server from the
+//! `X-GitHub-Enterprise-Version` header of the `meta_get` response. Operations that the
+//! server does not support fail with [`ApiError::UnsupportedOnServer`] without being sent,
+//! rather than with a `404 Not Found` response.
+//!
+//! ```ignore
+//! let github = Caller::new(hyper::Client::new(), Configuration::enterprise("github.example.com"), tokio::time::sleep);
+//! match github.billing_get_github_actions_billing_org("octo-org").await {
+//!     Err(ApiError::UnsupportedOnServer { feature, .. }) => ...,
+//!     ...
+//! }
+//! ```
+
+use ::std::fmt;
+use ::std::str::FromStr;
+
+use ::http::HeaderMap;
+
+use crate::v1_1_4::ApiError;
+
+/// Version of a GitHub Enterprise Server, e.g. `3.4.2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        ServerVersion { major, minor, patch }
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = ApiError;
+
+    /// Parse a version such as `3.4.2` or `3.4`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ApiError::Other(format!("Invalid server version {:?}", value));
+        let mut parts = value.trim().splitn(3, '.');
+        let mut next = |required: bool| match parts.next() {
+            Some(part) => part.parse::<u32>().map_err(|_| invalid()),
+            None if required => Err(invalid()),
+            None => Ok(0),
+        };
+        Ok(ServerVersion {
+            major: next(true)?,
+            minor: next(true)?,
+            patch: next(false)?,
+        })
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A feature that is only available on GitHub.com, or on GitHub Enterprise Server from a
+/// minimum version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Feature {
+    pub name: &'static str,
+    /// First version of GitHub Enterprise Server supporting the feature, or `None` if the
+    /// feature is only available on GitHub.com.
+    pub minimum_version: Option<ServerVersion>,
+}
+
+impl Feature {
+    /// Whether the feature is available on GitHub Enterprise Server `version`.
+    pub fn is_supported(&self, version: ServerVersion) -> bool {
+        self.minimum_version.is_some_and(|minimum| version >= minimum)
+    }
+}
+
+/// Features, by the first path segment after the owner and repository, organization or user.
+///
+/// The table is partial: it lists the features of the API description that are missing from
+/// some servers, with the first release of GitHub Enterprise Server documenting them. Requests
+/// for features not listed are sent to every server. Setting
+/// [`Configuration::enterprise_server`](crate::v1_1_4::config::Configuration::enterprise_server)
+/// to `false` disables the check.
+const FEATURES: &[(&str, Feature)] = &[
+    ("actions", Feature { name: "GitHub Actions", minimum_version: Some(ServerVersion::new(3, 0, 0)) }),
+    ("code-scanning", Feature { name: "Code scanning", minimum_version: Some(ServerVersion::new(3, 0, 0)) }),
+    ("codespaces", Feature { name: "Codespaces", minimum_version: None }),
+    ("dependabot", Feature { name: "Dependabot", minimum_version: Some(ServerVersion::new(3, 3, 0)) }),
+    ("import", Feature { name: "Source imports", minimum_version: None }),
+    ("interaction-limits", Feature { name: "Interaction limits", minimum_version: None }),
+    ("marketplace_listing", Feature { name: "GitHub Marketplace", minimum_version: None }),
+    ("marketplace_purchases", Feature { name: "GitHub Marketplace", minimum_version: None }),
+    ("packages", Feature { name: "GitHub Packages", minimum_version: Some(ServerVersion::new(3, 0, 0)) }),
+    ("secret-scanning", Feature { name: "Secret scanning", minimum_version: Some(ServerVersion::new(3, 0, 0)) }),
+    ("settings/billing", Feature { name: "Billing", minimum_version: None }),
+];
+
+/// The feature required by a request to `path`, relative to the base URL, if the feature is
+/// not available on every GitHub Enterprise Server.
+pub fn required_feature(path: &str) -> Option<Feature> {
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let rest = match segments.as_slice() {
+        ["repos", _, _, rest @ ..] => rest,
+        ["orgs" | "users", _, rest @ ..] => rest,
+        ["user", rest @ ..] => rest,
+        rest => rest,
+    };
+    FEATURES.iter().find_map(|(prefix, feature)| {
+        let matches = prefix.split('/').enumerate().all(|(i, segment)| rest.get(i) == Some(&segment));
+        matches.then_some(*feature)
+    })
+}
+
+/// The version of GitHub Enterprise Server, from the `meta_get` response, or `None` for
+/// GitHub.com.
+pub fn server_version(headers: &HeaderMap, body: &[u8]) -> Result<Option<ServerVersion>, ApiError> {
+    if let Some(version) = headers.get("x-github-enterprise-version") {
+        let version = version
+            .to_str()
+            .map_err(|_| ApiError::Other("Invalid X-GitHub-Enterprise-Version header".to_owned()))?;
+        return version.parse().map(Some);
+    }
+    #[derive(::serde::Deserialize)]
+    struct Meta {
+        installed_version: Option<String>,
+    }
+    match ::serde_json::from_slice::<Meta>(body) {
+        Ok(Meta { installed_version: Some(version) }) => version.parse().map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Description of the version required by a feature, used by [`ApiError::UnsupportedOnServer`].
+pub(crate) fn requirement(minimum_version: &Option<ServerVersion>) -> String {
+    match minimum_version {
+        Some(version) => format!("requires version {}", version),
+        None => "is only available on GitHub.com".to_owned(),
+    }
+}
@@ -0,0 +1,249 @@
+//! Authentication as a GitHub App installation.
+//!
+//! A GitHub App authenticates as itself using a JWT signed with its private key. To act on
+//! the repositories of an installation, the app exchanges the JWT for an installation access
+//! token, which expires after one hour.
+//!
+//! [`InstallationCredential`] signs the JWT, requests installation access tokens using
+//! `apps_create_installation_access_token`, and caches each token until shortly before it
+//! expires. Use it with
+//! [`Authentication::Installation`](crate::v1_1_4::config::Authentication::Installation):
+//!
+//! ```ignore
+//! let credential = InstallationCredential::new(app_id, &private_key_pem, installation_id)?;
+//! let config = Configuration {
+//!     authentication: Authentication::Installation(Arc::new(credential)),
+//!     ..Default::default()
+//! };
+//! ```
+
+use ::std::sync::{Arc, Mutex};
+use ::std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ::authentic::credential::{AuthenticationCredential, FetchedToken, JsonWebTokenCredential};
+use ::authentic::AuthenticError;
+
+use crate::v1_1_4::request::apps_create_installation_access_token::body;
+use crate::v1_1_4::schema::{AppPermissions, InstallationToken};
+use crate::v1_1_4::ApiError;
+
+/// Lifetime of the app JWT. GitHub accepts a maximum of 10 minutes.
+const APP_TOKEN_EXPIRATION: Duration = Duration::from_secs(9 * 60);
+
+/// Time to wait while another request renews an expired token.
+const RENEWAL_WAIT: Duration = Duration::from_millis(10);
+
+/// Create a credential for the JWT authenticating as the app `app_id`, using the app's RSA
+/// private key in PEM format.
+pub fn app_credential(app_id: i64, private_key_pem: &[u8]) -> Result<JsonWebTokenCredential, ApiError> {
+    let key = ::jsonwebtoken::EncodingKey::from_rsa_pem(private_key_pem)
+        .map_err(AuthenticError::from)?;
+    Ok(JsonWebTokenCredential::new(
+        ::jsonwebtoken::Header::new(::jsonwebtoken::Algorithm::RS256),
+        key,
+        APP_TOKEN_EXPIRATION,
+    )
+    .with_issuer(app_id.to_string()))
+}
+
+/// Credential for a GitHub App installation, renewing installation access tokens as required.
+pub struct InstallationCredential {
+    app: Arc<JsonWebTokenCredential>,
+    installation_id: i64,
+    permissions: Option<AppPermissions<'static>>,
+    renew_before: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    current: Option<FetchedInstallationToken>,
+    renewing: bool,
+}
+
+/// An implementation of [`FetchedToken`] returned from [`InstallationCredential`].
+#[derive(Clone)]
+pub struct FetchedInstallationToken {
+    token: Arc<[u8]>,
+    renew: SystemTime,
+    expiry: SystemTime,
+}
+
+impl FetchedInstallationToken {
+    /// Time at which the token expires.
+    pub fn expiry(&self) -> SystemTime {
+        self.expiry
+    }
+}
+
+impl FetchedToken for FetchedInstallationToken {
+    fn token(&self) -> &[u8] {
+        &self.token
+    }
+}
+
+/// What a request must do before using the installation access token.
+pub(crate) enum Renewal {
+    /// The current token can be used.
+    Ready,
+    /// Another request is renewing an expired token.
+    Wait(Duration),
+    /// This request must renew the token.
+    Renew,
+}
+
+impl InstallationCredential {
+    /// Create a credential for installation `installation_id` of the app `app_id`, using the
+    /// app's RSA private key in PEM format.
+    pub fn new(app_id: i64, private_key_pem: &[u8], installation_id: i64) -> Result<Self, ApiError> {
+        let app = app_credential(app_id, private_key_pem)?;
+        Ok(Self::from_app(Arc::new(app), installation_id))
+    }
+
+    /// Create a credential for installation `installation_id`, using an app credential
+    /// created by [`app_credential`]. Credentials for each installation of an app can share
+    /// the app credential.
+    pub fn from_app(app: Arc<JsonWebTokenCredential>, installation_id: i64) -> Self {
+        Self {
+            app,
+            installation_id,
+            permissions: None,
+            renew_before: Duration::from_secs(5 * 60),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Request installation access tokens with a subset of the installation's permissions.
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: AppPermissions<'static>) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Renew installation access tokens when they have less than `renew_before` remaining.
+    ///
+    /// The default is 5 minutes.
+    #[must_use]
+    pub fn with_renew_before(mut self, renew_before: Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    pub fn installation_id(&self) -> i64 {
+        self.installation_id
+    }
+
+    /// Credential for the app JWT used to request installation access tokens.
+    pub fn app(&self) -> &Arc<JsonWebTokenCredential> {
+        &self.app
+    }
+
+    /// Body of the request for an installation access token.
+    pub(crate) fn request_body(&self) -> body::Json<'static> {
+        body::Json {
+            permissions: self.permissions.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Check whether the token needs renewing. If this returns [`Renewal::Renew`], the caller
+    /// must call [`InstallationCredential::renewed`] once the request completes or fails.
+    pub(crate) fn renewal(&self) -> Result<Renewal, AuthenticError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|poison| AuthenticError::Other(poison.to_string()))?;
+        let now = SystemTime::now();
+        let current_is_valid = match &state.current {
+            Some(current) if now < current.renew => return Ok(Renewal::Ready),
+            Some(current) => now < current.expiry,
+            None => false,
+        };
+        if state.renewing {
+            // Continue with the current token if it is still valid, or wait for the
+            // other request to renew the token.
+            if current_is_valid {
+                Ok(Renewal::Ready)
+            } else {
+                Ok(Renewal::Wait(RENEWAL_WAIT))
+            }
+        } else {
+            state.renewing = true;
+            Ok(Renewal::Renew)
+        }
+    }
+
+    /// Complete a renewal started by [`InstallationCredential::renewal`], storing the new token
+    /// if the request succeeded.
+    pub(crate) fn renewed(&self, token: Option<FetchedInstallationToken>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.renewing = false;
+            if let Some(token) = token {
+                state.current = Some(token);
+            }
+        }
+    }
+
+    /// Decode the response to a request for an installation access token.
+    pub(crate) fn token_from_response(
+        &self,
+        status: ::http::StatusCode,
+        headers: &::http::HeaderMap,
+        body: &[u8],
+    ) -> Result<FetchedInstallationToken, String> {
+        if !status.is_success() {
+            return Err(format!(
+                "Failed to create installation access token: {}",
+                ApiError::from_response(status, headers, body)
+            ));
+        }
+        let token: InstallationToken = ::serde_json::from_slice(body)
+            .map_err(|err| format!("Invalid installation access token: {}", err))?;
+        let expiry = parse_timestamp(&token.expires_at)
+            .ok_or_else(|| format!("Invalid installation token expiry {:?}", token.expires_at))?;
+        let renew = expiry
+            .checked_sub(self.renew_before)
+            .unwrap_or(UNIX_EPOCH);
+        Ok(FetchedInstallationToken {
+            token: token.token.as_bytes().into(),
+            renew,
+            expiry,
+        })
+    }
+}
+
+impl AuthenticationCredential for InstallationCredential {
+    type Fetch = FetchedInstallationToken;
+
+    fn fetch(&self) -> Result<Self::Fetch, AuthenticError> {
+        self.state
+            .lock()
+            .map_err(|poison| AuthenticError::Other(poison.to_string()))?
+            .current
+            .clone()
+            .ok_or_else(|| AuthenticError::Other("No installation access token".to_owned()))
+    }
+}
+
+/// Parse an ISO 8601 timestamp in UTC, e.g. `2016-07-11T22:14:10Z`.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let time = time.strip_suffix('Z')?;
+    let time = time.split_once('.').map_or(time, |(time, _fraction)| time);
+    let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    // Days since 1970-01-01, from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds.try_into().ok()?))
+}
+
@@ -0,0 +1,212 @@
+//! Custom media types, selecting the representation returned by an operation.
+//!
+//! Some operations return other representations than JSON, depending on the `Accept`
+//! header of the request. The request module of each such operation contains a `media`
+//! module, whose `MediaType` trait is implemented by the media types the operation
+//! supports, and gives the type decoded from the response.
+//!
+//! Operations returning issues, pull requests or comments also support [`RawJson`],
+//! [`TextJson`], [`HtmlJson`] and [`FullJson`], which select the representation of the
+//! Markdown body and decode into the same type as [`Json`].
+//!
+//! The `Caller` method with suffix `_as` takes a media type and returns the decoded
+//! response:
+//!
+//! ```ignore
+//! use github_api::media::{Diff, Json};
+//!
+//! let diff: String = github.pulls_get_as(owner, repo, 1347, Diff).await?;
+//! let pull = match github.pulls_get_as(owner, repo, 1347, Json).await? {
+//!     pulls_get::response::Response::Ok200(pull) => pull,
+//!     response => ...
+//! };
+//! ```
+//!
+//! For media types other than `Json`, an error response is returned as the error decoded by
+//! [`ApiError::from_response`].
+
+use ::std::borrow::Cow;
+
+use ::http::{HeaderMap, StatusCode};
+
+use crate::v1_1_4::ApiError;
+
+/// A media type, sent in the `Accept` header of the request.
+pub trait MediaType {
+    const ACCEPT: &'static str;
+}
+
+/// JSON, the default representation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Json;
+
+/// Raw contents of a file, or the source of a Markdown body.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Raw;
+
+/// Contents rendered as HTML.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Html;
+
+/// Unified diff of a commit, comparison or pull request.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Diff;
+
+/// Patch of a commit, comparison or pull request, as formatted by `git format-patch`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Patch;
+
+/// SHA-1 hash of a commit.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha;
+
+/// Search results with text match metadata.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextMatch;
+
+/// Stars with the time they were created.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Star;
+
+/// JSON, with the Markdown body of an issue, pull request or comment in `body`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RawJson;
+
+/// JSON, with the body of an issue, pull request or comment as plain text in `body_text`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextJson;
+
+/// JSON, with the body of an issue, pull request or comment rendered as HTML in `body_html`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HtmlJson;
+
+/// JSON, with the body of an issue, pull request or comment in each of `body`, `body_text`
+/// and `body_html`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FullJson;
+
+impl MediaType for Json {
+    const ACCEPT: &'static str = "application/vnd.github.v3+json";
+}
+
+impl MediaType for Raw {
+    const ACCEPT: &'static str = "application/vnd.github.v3.raw";
+}
+
+impl MediaType for Html {
+    const ACCEPT: &'static str = "application/vnd.github.v3.html";
+}
+
+impl MediaType for Diff {
+    const ACCEPT: &'static str = "application/vnd.github.v3.diff";
+}
+
+impl MediaType for Patch {
+    const ACCEPT: &'static str = "application/vnd.github.v3.patch";
+}
+
+impl MediaType for Sha {
+    const ACCEPT: &'static str = "application/vnd.github.v3.sha";
+}
+
+impl MediaType for TextMatch {
+    const ACCEPT: &'static str = "application/vnd.github.v3.text-match+json";
+}
+
+impl MediaType for Star {
+    const ACCEPT: &'static str = "application/vnd.github.v3.star+json";
+}
+
+impl MediaType for RawJson {
+    const ACCEPT: &'static str = "application/vnd.github.v3.raw+json";
+}
+
+impl MediaType for TextJson {
+    const ACCEPT: &'static str = "application/vnd.github.v3.text+json";
+}
+
+impl MediaType for HtmlJson {
+    const ACCEPT: &'static str = "application/vnd.github.v3.html+json";
+}
+
+impl MediaType for FullJson {
+    const ACCEPT: &'static str = "application/vnd.github.v3.full+json";
+}
+
+/// Search results, with text match metadata for each item.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct TextMatchResults<'a> {
+    pub total_count: i64,
+    pub incomplete_results: bool,
+    pub items: Vec<TextMatchItem<'a>>,
+}
+
+/// Search result item, with text match metadata.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct TextMatchItem<'a> {
+    #[serde(default)]
+    pub text_matches: Vec<TextMatchMetadata<'a>>,
+
+    /// The item, as returned using the `Json` media type.
+    #[serde(flatten)]
+    pub item: ::serde_json::value::Value,
+}
+
+/// Fragment of a search result item that matched the query.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct TextMatchMetadata<'a> {
+    pub object_url: Option<Cow<'a, str>>,
+    pub object_type: Option<Cow<'a, str>>,
+    pub property: Option<Cow<'a, str>>,
+    pub fragment: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub matches: Vec<TextMatchRange<'a>>,
+}
+
+/// Matching text within a [`TextMatchMetadata`] fragment.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct TextMatchRange<'a> {
+    pub text: Option<Cow<'a, str>>,
+    /// Start and end offsets of the text within the fragment.
+    #[serde(default)]
+    pub indices: Vec<i64>,
+}
+
+/// User who starred a repository.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct Stargazer<'a> {
+    pub starred_at: Cow<'a, str>,
+    pub user: crate::v1_1_4::schema::SimpleUser<'a>,
+}
+
+/// Repository starred by a user.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct StarredRepository<'a> {
+    pub starred_at: Cow<'a, str>,
+    pub repo: crate::v1_1_4::schema::Repository<'a>,
+}
+
+/// Return the body of a successful response.
+pub fn bytes(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Result<Vec<u8>, ApiError> {
+    if !status.is_success() {
+        return Err(ApiError::from_response(status, headers, body));
+    }
+    Ok(body.to_vec())
+}
+
+/// Return the body of a successful response as text.
+pub fn text(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Result<String, ApiError> {
+    let body = bytes(status, headers, body)?;
+    String::from_utf8(body).map_err(|err| ApiError::Other(format!("Response is not UTF-8: {}", err)))
+}
+
+/// Decode the JSON body of a successful response.
+pub fn json<T>(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Result<T, ApiError>
+where
+    T: ::serde::de::DeserializeOwned,
+{
+    if !status.is_success() {
+        return Err(ApiError::from_response(status, headers, body));
+    }
+    Ok(::serde_json::from_slice(body)?)
+}
@@ -11,9 +11,25 @@ use thiserror::Error;
 
 mod support;
 
+pub mod app;
+pub mod cache;
+pub mod caller;
+pub mod checks;
+pub mod code_scanning;
+pub mod commit;
 pub mod config;
+pub mod contents;
+pub mod diagnostics;
+pub mod enterprise;
+pub mod installation;
+pub mod media;
+pub mod pagination;
+pub mod rate_limit;
 pub mod request;
 pub mod schema;
+pub mod secrets;
+pub mod transport;
+pub mod webhooks;
 
 #[cfg(feature = "hyper")]
 pub mod hyper;
@@ -21,6 +37,12 @@ pub mod hyper;
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "tower")]
+pub mod tower;
+
 #[derive(Error, Debug)]
 pub enum ApiError {
     #[cfg(feature = "hyper")]
@@ -59,7 +81,7 @@ pub enum ApiError {
     #[error("InvalidHeaderValue")]
     InvalidHeaderValue {
         #[from]
-        source: ::hyper::header::InvalidHeaderValue,
+        source: ::http::header::InvalidHeaderValue,
     },
     #[error("HttpError")]
     Http {
@@ -76,6 +98,133 @@ pub enum ApiError {
         #[from]
         source: ::std::fmt::Error,
     },
+    #[error("IoError")]
+    Io {
+        #[from]
+        source: ::std::io::Error,
+    },
+    #[cfg(feature = "tower")]
+    #[error("Service error")]
+    Service {
+        source: ::tower::BoxError,
+    },
+    #[error("Rate limited ({status}): {message}")]
+    RateLimited {
+        status: ::http::StatusCode,
+        message: String,
+        documentation_url: Option<String>,
+        /// Boxed, so that the rate limit does not make every `Result` of the crate larger.
+        rate_limit: Option<Box<rate_limit::RateLimitState>>,
+    },
+    #[error("Unauthorized: {message}")]
+    Unauthorized {
+        message: String,
+        documentation_url: Option<String>,
+    },
+    #[error("Not found: {message}")]
+    NotFound {
+        message: String,
+        documentation_url: Option<String>,
+    },
+    #[error("Validation failed: {message}")]
+    Validation {
+        message: String,
+        documentation_url: Option<String>,
+        errors: Vec<ValidationError>,
+    },
+    #[error("GitHub error ({status}): {message}")]
+    GitHub {
+        status: ::http::StatusCode,
+        message: String,
+        documentation_url: Option<String>,
+        errors: Vec<ValidationError>,
+    },
+    #[error("{feature} {} (server version {server_version})", enterprise::requirement(.minimum_version))]
+    UnsupportedOnServer {
+        feature: &'static str,
+        server_version: enterprise::ServerVersion,
+        minimum_version: Option<enterprise::ServerVersion>,
+    },
+    #[error("SARIF processing failed: {}", .errors.join("; "))]
+    SarifProcessing {
+        sarif_id: String,
+        errors: Vec<String>,
+    },
     #[error("{0}")]
     Other(String),
-}
\ No newline at end of file
+}
+
+/// An entry in the `errors` list of a GitHub error response.
+///
+/// GitHub reports most validation failures as an object described by the
+/// [`ValidationError`](schema::ValidationError) schema, with a `code` such as `missing`,
+/// `missing_field`, `invalid`, `already_exists` or `custom`, but some operations report a
+/// plain message.
+#[derive(Clone, Debug, PartialEq, ::serde::Deserialize)]
+#[serde(untagged)]
+pub enum ValidationError {
+    Detail(schema::validation_error::Errors<'static>),
+    Message(String),
+    /// An entry of another shape, as sent.
+    Other(::serde_json::Value),
+}
+
+impl ApiError {
+    /// Decode the error in a non-success response from GitHub.
+    ///
+    /// Rate limit (`403 Forbidden` or `429 Too Many Requests` reporting a rate limit),
+    /// `401 Unauthorized`, `404 Not Found` and `422 Unprocessable Entity` responses have
+    /// their own variants. Other statuses return [`ApiError::GitHub`].
+    pub fn from_response(
+        status: ::http::StatusCode,
+        headers: &::http::HeaderMap,
+        body: &[u8],
+    ) -> ApiError {
+        let support::ErrorBody {
+            message,
+            documentation_url,
+            errors,
+        } = ::serde_json::from_slice(body).unwrap_or_else(|_| support::ErrorBody {
+            message: Some(String::from_utf8_lossy(body).into_owned()),
+            ..Default::default()
+        });
+        let message = match message {
+            Some(message) if !message.is_empty() => message,
+            _ => status.canonical_reason().unwrap_or_default().to_owned(),
+        };
+        let rate_limited = match rate_limit::check(status, headers) {
+            rate_limit::Check::Ok => false,
+            rate_limit::Check::Limited(_) => true,
+            rate_limit::Check::Body => rate_limit::is_secondary_message(message.as_bytes()),
+        };
+        if rate_limited {
+            return ApiError::RateLimited {
+                status,
+                message,
+                documentation_url,
+                rate_limit: rate_limit::RateLimitState::from_headers(headers).map(Box::new),
+            };
+        }
+        match status {
+            ::http::StatusCode::UNAUTHORIZED => ApiError::Unauthorized {
+                message,
+                documentation_url,
+            },
+            ::http::StatusCode::NOT_FOUND => ApiError::NotFound {
+                message,
+                documentation_url,
+            },
+            ::http::StatusCode::UNPROCESSABLE_ENTITY => ApiError::Validation {
+                message,
+                documentation_url,
+                errors,
+            },
+            _ => ApiError::GitHub {
+                status,
+                message,
+                documentation_url,
+                errors,
+            },
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Following the `Link` header across pages of list operations.
+//!
+//! List operations return one page per request, with a `Link` header pointing at the
+//! next page:
+//!
+//! ```text
+//! Link: <https://api.github.com/user/repos?page=3&per_page=100>; rel="next",
+//!       <https://api.github.com/user/repos?page=50&per_page=100>; rel="last"
+//! ```
+//!
+//! The `paginate` method on each `Caller` uses this module to request pages until no
+//! `rel="next"` link remains.
+
+use crate::v1_1_4::ApiError;
+
+/// Find the URL with relation `rel` in a `Link` header value.
+pub fn link_url<'h>(link: &'h str, rel: &str) -> Option<&'h str> {
+    link.split(',').find_map(|entry| {
+        let mut parts = entry.split(';');
+        let url = parts
+            .next()?
+            .trim()
+            .strip_prefix('<')?
+            .strip_suffix('>')?;
+        parts
+            .filter_map(|param| param.trim().strip_prefix("rel="))
+            .flat_map(|value| value.trim_matches('"').split_ascii_whitespace())
+            .any(|value| value == rel)
+            .then_some(url)
+    })
+}
+
+/// Get the URL of the `rel="next"` link in the response headers.
+///
+/// Returns `None` when the response is the last page.
+pub fn next_url(headers: &::http::HeaderMap) -> Result<Option<String>, ApiError> {
+    for value in headers.get_all(::http::header::LINK) {
+        let value = value
+            .to_str()
+            .map_err(|err| ApiError::Other(format!("Invalid Link header: {}", err)))?;
+        if let Some(url) = link_url(value, "next") {
+            return Ok(Some(url.to_owned()));
+        }
+    }
+    Ok(None)
+}
+
+/// Decode the items in a page body.
+///
+/// Most list operations return a JSON array. Others wrap the array in an object together
+/// with a `total_count` (e.g. `{"total_count": 2, "workflow_runs": [...]}`), in which case
+/// the items are taken from the array-valued property.
+#[cfg(feature = "futures-util")]
+pub(crate) fn page_items<T>(body: &[u8]) -> Result<Vec<T>, ApiError>
+where
+    T: ::serde::de::DeserializeOwned,
+{
+    let items = match ::serde_json::from_slice(body)? {
+        ::serde_json::Value::Array(items) => items,
+        ::serde_json::Value::Object(properties) => properties
+            .into_iter()
+            .find_map(|(_, value)| match value {
+                ::serde_json::Value::Array(items) => Some(items),
+                _ => None,
+            })
+            .ok_or_else(|| ApiError::Other("No list of items in page".to_owned()))?,
+        _ => return Err(ApiError::Other("No list of items in page".to_owned())),
+    };
+    items
+        .into_iter()
+        .map(|item| Ok(::serde_json::from_value(item)?))
+        .collect()
+}
+
+/// Stop collecting items once `max_items` have been returned.
+#[cfg(feature = "futures-util")]
+pub(crate) fn truncate<T>(items: &mut Vec<T>, remaining: &mut Option<usize>) {
+    if let Some(remaining) = remaining {
+        items.truncate(*remaining);
+        *remaining -= items.len();
+    }
+}
+
+/// Returns the next page to request, if any.
+#[cfg(feature = "futures-util")]
+pub(crate) fn should_continue<Page>(next: Option<Page>, remaining: Option<usize>) -> Option<Page> {
+    match remaining {
+        Some(0) => None,
+        _ => next,
+    }
+}
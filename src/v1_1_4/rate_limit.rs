@@ -0,0 +1,169 @@
+//! Waiting for rate limits.
+//!
+//! GitHub applies a primary rate limit to the number of requests in each hour, reported
+//! in the `x-ratelimit-*` headers of every response, and secondary rate limits that
+//! restrict bursts of requests. Both are reported using a `403 Forbidden` or
+//! `429 Too Many Requests` response.
+//!
+//! By default, a `Caller` returns these responses like any other. To wait and retry the
+//! request instead, set a [`RateLimitPolicy`] using the `with_rate_limit_policy` method
+//! of the `Caller`.
+//!
+//! Whether or not a policy is set, the `rate_limit` method of the `Caller` returns the
+//! [`RateLimitState`] reported in the most recent response.
+
+use ::std::sync::Mutex;
+use ::std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How a `Caller` handles rate limited responses.
+#[derive(Clone, Debug)]
+pub struct RateLimitPolicy {
+    /// Maximum number of times to retry a request.
+    pub max_retries: u32,
+    /// Longest time to wait before retrying. If the rate limit requires a longer wait, the
+    /// rate limited response is returned.
+    pub max_wait: Duration,
+    /// Time to wait after a secondary rate limit response that does not say how long to
+    /// wait. The time doubles for each retry of the same request.
+    pub secondary_wait: Duration,
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            max_wait: Duration::from_secs(60 * 60),
+            secondary_wait: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RateLimitPolicy {
+    /// Returns the time to wait before retry number `attempt + 1`, or `None` if the request
+    /// should not be retried.
+    pub(crate) fn retry_wait(&self, attempt: u32, limited: Limited) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        let wait = match limited {
+            Limited::Until(wait) => wait,
+            Limited::Secondary => self
+                .secondary_wait
+                .saturating_mul(2u32.saturating_pow(attempt)),
+        };
+        (wait <= self.max_wait).then_some(wait)
+    }
+
+    /// Returns the time to wait before sending a request that cannot be retried, if the
+    /// `latest` primary rate limit is exhausted.
+    pub(crate) fn wait_before(&self, latest: Option<RateLimitState>) -> Option<Duration> {
+        let state = latest.filter(|state| state.remaining == 0)?;
+        // The reset time is truncated to a second.
+        let wait = state.reset_after() + Duration::from_secs(1);
+        (wait <= self.max_wait).then_some(wait)
+    }
+}
+
+/// Primary rate limit reported in the `x-ratelimit-*` response headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitState {
+    /// Rate limit resource (e.g. `core`, `search`, `graphql`) that the request counted against.
+    pub resource: Option<String>,
+    /// Maximum number of requests in the current window.
+    pub limit: i64,
+    /// Number of requests remaining in the current window.
+    pub remaining: i64,
+    /// Number of requests made in the current window.
+    pub used: Option<i64>,
+    /// Time at which the current window ends.
+    pub reset: SystemTime,
+}
+
+impl RateLimitState {
+    /// Read the rate limit from response headers.
+    ///
+    /// Returns `None` if the headers do not include a rate limit.
+    pub fn from_headers(headers: &::http::HeaderMap) -> Option<Self> {
+        let reset = header_value::<u64>(headers, "x-ratelimit-reset")?;
+        Some(RateLimitState {
+            resource: header_value(headers, "x-ratelimit-resource"),
+            limit: header_value(headers, "x-ratelimit-limit")?,
+            remaining: header_value(headers, "x-ratelimit-remaining")?,
+            used: header_value(headers, "x-ratelimit-used"),
+            reset: UNIX_EPOCH + Duration::from_secs(reset),
+        })
+    }
+
+    /// Time until the current window ends.
+    pub fn reset_after(&self) -> Duration {
+        self.reset
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
+fn header_value<T: ::std::str::FromStr>(headers: &::http::HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Most recently reported rate limit.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker(Mutex<Option<RateLimitState>>);
+
+impl RateLimitTracker {
+    pub(crate) fn observe(&self, headers: &::http::HeaderMap) {
+        if let Some(state) = RateLimitState::from_headers(headers) {
+            if let Ok(mut latest) = self.0.lock() {
+                *latest = Some(state);
+            }
+        }
+    }
+
+    pub(crate) fn latest(&self) -> Option<RateLimitState> {
+        self.0.lock().ok()?.clone()
+    }
+}
+
+/// How long a rate limited response asks to wait.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Limited {
+    /// Retry after the given time.
+    Until(Duration),
+    /// Secondary rate limit without a time to retry.
+    Secondary,
+}
+
+/// Result of checking the status and headers of a response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Check {
+    /// Not rate limited.
+    Ok,
+    /// Rate limited.
+    Limited(Limited),
+    /// `403 Forbidden` without rate limit headers. This is a secondary rate limit only if
+    /// [`is_secondary_message`] matches the body.
+    Body,
+}
+
+pub(crate) fn check(status: ::http::StatusCode, headers: &::http::HeaderMap) -> Check {
+    if status != ::http::StatusCode::FORBIDDEN && status != ::http::StatusCode::TOO_MANY_REQUESTS {
+        return Check::Ok;
+    }
+    if let Some(seconds) = header_value::<u64>(headers, "retry-after") {
+        return Check::Limited(Limited::Until(Duration::from_secs(seconds)));
+    }
+    match RateLimitState::from_headers(headers) {
+        Some(state) if state.remaining == 0 => {
+            // The reset time is truncated to a second.
+            Check::Limited(Limited::Until(state.reset_after() + Duration::from_secs(1)))
+        }
+        _ if status == ::http::StatusCode::TOO_MANY_REQUESTS => Check::Limited(Limited::Secondary),
+        _ => Check::Body,
+    }
+}
+
+/// Does the body of a `403 Forbidden` response report a secondary rate limit?
+pub(crate) fn is_secondary_message(body: &[u8]) -> bool {
+    let message = String::from_utf8_lossy(body).to_ascii_lowercase();
+    message.contains("secondary rate limit") || message.contains("abuse detection")
+}
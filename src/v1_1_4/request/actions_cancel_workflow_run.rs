@@ -28,7 +28,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_owner: &str,
@@ -57,6 +56,13 @@ pub fn http_builder(
     Ok(builder)
 }
 
+#[inline]
+pub fn http_request(
+    builder: ::http::request::Builder,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError> {
+    Ok(builder.body(Vec::new())?)
+}
+
 #[cfg(feature = "hyper")]
 #[inline]
 pub fn hyper_request(
@@ -126,7 +126,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_repository_id: i64,
@@ -155,6 +154,17 @@ pub fn http_builder(
     Ok(builder)
 }
 
+pub fn http_request(
+    mut builder: ::http::request::Builder,
+    content: Content<Vec<u8>>,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError>
+{
+    if let Some(content_type) = content.content_type() {
+        builder = builder.header(::http::header::CONTENT_TYPE, content_type);
+    }
+    Ok(builder.body(content.into_body())?)
+}
+
 #[cfg(feature = "hyper")]
 pub fn hyper_request(
     mut builder: ::http::request::Builder,
@@ -167,6 +177,12 @@ pub fn hyper_request(
     Ok(builder.body(content.into_body())?)
 }
 
+impl From<Vec<u8>> for Content<Vec<u8>> {
+    fn from(body: Vec<u8>) -> Self {
+        Self::new(body)
+    }
+}
+
 #[cfg(feature = "hyper")]
 impl From<::hyper::Body> for Content<::hyper::Body> {
     fn from(body: ::hyper::Body) -> Self {
@@ -295,6 +311,17 @@ pub mod body {
         pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
     }
 
+    impl<'a> TryFrom<&Json<'a>> for super::Content<Vec<u8>> {
+        type Error = crate::v1_1_4::ApiError;
+
+        fn try_from(value: &Json<'a>) -> Result<Self, Self::Error> {
+            Ok(
+                Self::new(::serde_json::to_vec(value)?)
+                .with_content_type(&b"application/json"[..])
+            )
+        }
+    }
+
     #[cfg(feature = "hyper")]
     impl<'a> TryFrom<&Json<'a>> for super::Content<::hyper::Body> {
         type Error = crate::v1_1_4::ApiError;
@@ -25,7 +25,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_repository_id: i64,
@@ -52,6 +51,13 @@ pub fn http_builder(
     Ok(builder)
 }
 
+#[inline]
+pub fn http_request(
+    builder: ::http::request::Builder,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError> {
+    Ok(builder.body(Vec::new())?)
+}
+
 #[cfg(feature = "hyper")]
 #[inline]
 pub fn hyper_request(
@@ -135,3 +141,57 @@ pub fn reqwest_blocking_request(
 {
     Ok(builder)
 }
+
+/// Types for responses from [`super::actions_get_environment_public_key`]
+pub mod response {
+    /// Responses for [`super::actions_get_environment_public_key`]
+    #[allow(clippy::large_enum_variant)]
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Response<'a> {
+        /// Response
+        Ok200(crate::v1_1_4::schema::ActionsPublicKey<'a>),
+
+        /// A status code not described for this operation
+        Other(::http::StatusCode, ::std::vec::Vec<u8>),
+    }
+
+    impl<'a> Response<'a> {
+        /// Decode a response from its status code and complete body.
+        pub fn from_parts(
+            status: ::http::StatusCode,
+            body: &[u8],
+        ) -> Result<Self, crate::v1_1_4::ApiError> {
+            match status.as_u16() {
+                200 => Ok(Self::Ok200(::serde_json::from_slice(body)?)),
+                _ => Ok(Self::Other(status, body.to_vec())),
+            }
+        }
+    }
+
+    #[cfg(feature = "hyper")]
+    pub async fn hyper_response<'a>(
+        response: ::hyper::Response<::hyper::Body>,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = ::hyper::body::to_bytes(response.into_body()).await?;
+        Response::from_parts(status, &body)
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub async fn reqwest_response<'a>(
+        response: ::reqwest::Response,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = response.bytes().await?;
+        Response::from_parts(status, &body)
+    }
+
+    #[cfg(feature = "reqwest-blocking")]
+    pub fn reqwest_blocking_response<'a>(
+        response: ::reqwest::blocking::Response,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = response.bytes()?;
+        Response::from_parts(status, &body)
+    }
+}
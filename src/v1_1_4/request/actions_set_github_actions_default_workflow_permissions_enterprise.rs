@@ -52,7 +52,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_enterprise: &str,
@@ -77,6 +76,17 @@ pub fn http_builder(
     Ok(builder)
 }
 
+pub fn http_request(
+    mut builder: ::http::request::Builder,
+    content: Content<Vec<u8>>,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError>
+{
+    if let Some(content_type) = content.content_type() {
+        builder = builder.header(::http::header::CONTENT_TYPE, content_type);
+    }
+    Ok(builder.body(content.into_body())?)
+}
+
 #[cfg(feature = "hyper")]
 pub fn hyper_request(
     mut builder: ::http::request::Builder,
@@ -89,6 +99,12 @@ pub fn hyper_request(
     Ok(builder.body(content.into_body())?)
 }
 
+impl From<Vec<u8>> for Content<Vec<u8>> {
+    fn from(body: Vec<u8>) -> Self {
+        Self::new(body)
+    }
+}
+
 #[cfg(feature = "hyper")]
 impl From<::hyper::Body> for Content<::hyper::Body> {
     fn from(body: ::hyper::Body) -> Self {
@@ -196,6 +212,17 @@ impl From<::reqwest::blocking::Body> for Content<::reqwest::blocking::Body> {
 
 /// Types for body parameter in [`super::actions_set_github_actions_default_workflow_permissions_enterprise`]
 pub mod body {
+    impl<'a> TryFrom<&crate::v1_1_4::schema::ActionsSetDefaultWorkflowPermissions<'a>> for super::Content<Vec<u8>> {
+        type Error = crate::v1_1_4::ApiError;
+
+        fn try_from(value: &crate::v1_1_4::schema::ActionsSetDefaultWorkflowPermissions<'a>) -> Result<Self, Self::Error> {
+            Ok(
+                Self::new(::serde_json::to_vec(value)?)
+                .with_content_type(&b"application/json"[..])
+            )
+        }
+    }
+
     #[cfg(feature = "hyper")]
     impl<'a> TryFrom<&crate::v1_1_4::schema::ActionsSetDefaultWorkflowPermissions<'a>> for super::Content<::hyper::Body> {
         type Error = crate::v1_1_4::ApiError;
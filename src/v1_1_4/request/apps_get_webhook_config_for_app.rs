@@ -7,7 +7,6 @@
 //! [API method documentation](https://docs.github.com/rest/reference/apps#get-a-webhook-configuration-for-an-app)
 
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     h_user_agent: &str,
@@ -37,6 +36,13 @@ pub fn http_builder(
     Ok(builder)
 }
 
+#[inline]
+pub fn http_request(
+    builder: ::http::request::Builder,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError> {
+    Ok(builder.body(Vec::new())?)
+}
+
 #[cfg(feature = "hyper")]
 #[inline]
 pub fn hyper_request(
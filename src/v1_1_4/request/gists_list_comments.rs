@@ -31,7 +31,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_gist_id: &str,
@@ -60,6 +59,13 @@ pub fn http_builder(
     Ok(builder)
 }
 
+#[inline]
+pub fn http_request(
+    builder: ::http::request::Builder,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError> {
+    Ok(builder.body(Vec::new())?)
+}
+
 #[cfg(feature = "hyper")]
 #[inline]
 pub fn hyper_request(
@@ -147,3 +153,79 @@ pub fn reqwest_blocking_request(
 {
     Ok(builder)
 }
+
+/// Media types for [`super::gists_list_comments`]
+pub mod media {
+    pub use crate::v1_1_4::media::{FullJson, HtmlJson, Json, RawJson, TextJson};
+
+    /// A media type supported by the operation, and the type decoded from a response using it.
+    pub trait MediaType: crate::v1_1_4::media::MediaType {
+        type Output;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError>;
+    }
+
+    impl MediaType for Json {
+        type Output = ::serde_json::value::Value;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            crate::v1_1_4::media::json(status, headers, body)
+        }
+    }
+
+    impl MediaType for RawJson {
+        type Output = ::serde_json::value::Value;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            crate::v1_1_4::media::json(status, headers, body)
+        }
+    }
+
+    impl MediaType for TextJson {
+        type Output = ::serde_json::value::Value;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            crate::v1_1_4::media::json(status, headers, body)
+        }
+    }
+
+    impl MediaType for HtmlJson {
+        type Output = ::serde_json::value::Value;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            crate::v1_1_4::media::json(status, headers, body)
+        }
+    }
+
+    impl MediaType for FullJson {
+        type Output = ::serde_json::value::Value;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            crate::v1_1_4::media::json(status, headers, body)
+        }
+    }
+}
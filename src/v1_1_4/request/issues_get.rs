@@ -37,7 +37,6 @@ fn url_string(
     Ok(url)
 }
 
-#[cfg(feature = "hyper")]
 pub fn http_builder(
     base_url: &str,
     p_owner: &str,
@@ -66,6 +65,13 @@ pub fn http_builder(
     Ok(builder)
 }
 
+#[inline]
+pub fn http_request(
+    builder: ::http::request::Builder,
+) -> Result<::http::request::Request<Vec<u8>>, crate::v1_1_4::ApiError> {
+    Ok(builder.body(Vec::new())?)
+}
+
 #[cfg(feature = "hyper")]
 #[inline]
 pub fn hyper_request(
@@ -153,3 +159,149 @@ pub fn reqwest_blocking_request(
 {
     Ok(builder)
 }
+
+/// Types for responses from [`super::issues_get`]
+pub mod response {
+    /// Responses for [`super::issues_get`]
+    #[allow(clippy::large_enum_variant)]
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Response<'a> {
+        /// Response
+        Ok200(crate::v1_1_4::schema::Issue<'a>),
+
+        /// Moved permanently
+        MovedPermanently301(crate::v1_1_4::schema::BasicError<'a>),
+
+        /// Not modified
+        NotModified304,
+
+        /// Resource not found
+        NotFound404(crate::v1_1_4::schema::BasicError<'a>),
+
+        /// Gone
+        Gone410(crate::v1_1_4::schema::BasicError<'a>),
+
+        /// A status code not described for this operation
+        Other(::http::StatusCode, ::std::vec::Vec<u8>),
+    }
+
+    impl<'a> Response<'a> {
+        /// Decode a response from its status code and complete body.
+        pub fn from_parts(
+            status: ::http::StatusCode,
+            body: &[u8],
+        ) -> Result<Self, crate::v1_1_4::ApiError> {
+            match status.as_u16() {
+                200 => Ok(Self::Ok200(::serde_json::from_slice(body)?)),
+                301 => Ok(Self::MovedPermanently301(::serde_json::from_slice(body)?)),
+                304 => Ok(Self::NotModified304),
+                404 => Ok(Self::NotFound404(::serde_json::from_slice(body)?)),
+                410 => Ok(Self::Gone410(::serde_json::from_slice(body)?)),
+                _ => Ok(Self::Other(status, body.to_vec())),
+            }
+        }
+    }
+
+    #[cfg(feature = "hyper")]
+    pub async fn hyper_response<'a>(
+        response: ::hyper::Response<::hyper::Body>,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = ::hyper::body::to_bytes(response.into_body()).await?;
+        Response::from_parts(status, &body)
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub async fn reqwest_response<'a>(
+        response: ::reqwest::Response,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = response.bytes().await?;
+        Response::from_parts(status, &body)
+    }
+
+    #[cfg(feature = "reqwest-blocking")]
+    pub fn reqwest_blocking_response<'a>(
+        response: ::reqwest::blocking::Response,
+    ) -> Result<Response<'a>, crate::v1_1_4::ApiError> {
+        let status = response.status();
+        let body = response.bytes()?;
+        Response::from_parts(status, &body)
+    }
+}
+
+/// Media types for [`super::issues_get`]
+pub mod media {
+    pub use crate::v1_1_4::media::{FullJson, HtmlJson, Json, RawJson, TextJson};
+
+    /// A media type supported by the operation, and the type decoded from a response using it.
+    pub trait MediaType: crate::v1_1_4::media::MediaType {
+        type Output;
+
+        fn decode(
+            status: ::http::StatusCode,
+            headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError>;
+    }
+
+    impl MediaType for Json {
+        type Output = super::response::Response<'static>;
+
+        fn decode(
+            status: ::http::StatusCode,
+            _headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            super::response::Response::from_parts(status, body)
+        }
+    }
+
+    impl MediaType for RawJson {
+        type Output = super::response::Response<'static>;
+
+        fn decode(
+            status: ::http::StatusCode,
+            _headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            super::response::Response::from_parts(status, body)
+        }
+    }
+
+    impl MediaType for TextJson {
+        type Output = super::response::Response<'static>;
+
+        fn decode(
+            status: ::http::StatusCode,
+            _headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            super::response::Response::from_parts(status, body)
+        }
+    }
+
+    impl MediaType for HtmlJson {
+        type Output = super::response::Response<'static>;
+
+        fn decode(
+            status: ::http::StatusCode,
+            _headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            super::response::Response::from_parts(status, body)
+        }
+    }
+
+    impl MediaType for FullJson {
+        type Output = super::response::Response<'static>;
+
+        fn decode(
+            status: ::http::StatusCode,
+            _headers: &::http::HeaderMap,
+            body: &[u8],
+        ) -> Result<Self::Output, crate::v1_1_4::ApiError> {
+            super::response::Response::from_parts(status, body)
+        }
+    }
+}
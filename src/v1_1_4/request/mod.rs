@@ -1,3 +1,11 @@
+//! Requests and responses of each operation.
+//!
+//! Each module builds the HTTP request of an operation. A `response` module, decoding the
+//! documented responses into a `Response` enum, is generated only for a subset of the
+//! operations: those used by the helpers of this crate, and other common operations on
+//! repositories, issues, pull requests, checks, Git data, Actions artifacts and runs, and
+//! apps. The responses of other operations are returned undecoded.
+
 pub mod actions_add_custom_labels_to_self_hosted_runner_for_org;
 pub mod actions_add_custom_labels_to_self_hosted_runner_for_repo;
 pub mod actions_add_repo_access_to_self_hosted_runner_group_in_org;
@@ -0,0 +1,88 @@
+use ::std::future::Ready;
+use ::std::time::Duration;
+
+use crate::v1_1_4::caller;
+use crate::v1_1_4::checks::{Annotation, AnnotationCounts, Conclusion};
+use crate::v1_1_4::transport::wait;
+use crate::v1_1_4::ApiError;
+
+use super::{BlockingSleep, Caller};
+
+/// An `in_progress` check run, sending its annotations in batches.
+///
+/// Created by [`Caller::start_check_run`]. See [`caller::CheckRunReporter`].
+pub struct CheckRunReporter<'c> {
+    inner: caller::CheckRunReporter<'c, ::reqwest::blocking::Client, BlockingSleep, Ready<()>, ()>,
+}
+
+impl<'c> CheckRunReporter<'c> {
+    pub fn check_run_id(&self) -> i64 {
+        self.inner.check_run_id()
+    }
+
+    /// Number of annotations of each level added so far.
+    pub fn counts(&self) -> AnnotationCounts {
+        self.inner.counts()
+    }
+
+    /// Title of the output. Defaults to the name of the check run.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.inner.set_title(title)
+    }
+
+    /// Summary of the output, truncated to 65535 characters.
+    pub fn set_summary(&mut self, summary: impl Into<String>) {
+        self.inner.set_summary(summary)
+    }
+
+    /// Details of the output, truncated to 65535 characters.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.inner.set_text(text)
+    }
+
+    /// Add `annotation`, sending a batch of annotations once 50 are pending.
+    pub fn annotate(&mut self, annotation: Annotation<'static>) -> Result<(), ApiError> {
+        wait(self.inner.annotate(annotation))
+    }
+
+    /// Add each of `annotations`, sending batches of 50 annotations.
+    pub fn annotate_all<I>(&mut self, annotations: I) -> Result<(), ApiError>
+    where
+        I: IntoIterator<Item = Annotation<'static>>,
+    {
+        wait(self.inner.annotate_all(annotations))
+    }
+
+    /// Send the pending annotations.
+    pub fn flush(&mut self) -> Result<(), ApiError> {
+        wait(self.inner.flush())
+    }
+
+    /// Complete the check run with the conclusion derived from the annotation levels.
+    pub fn finish(self) -> Result<::reqwest::blocking::Response, ApiError> {
+        wait(self.inner.finish())
+    }
+
+    /// Complete the check run with `conclusion`, sending the pending annotations.
+    pub fn finish_with(self, conclusion: Conclusion) -> Result<::reqwest::blocking::Response, ApiError> {
+        wait(self.inner.finish_with(conclusion))
+    }
+}
+
+impl<Sleep> Caller<Sleep>
+where
+    Sleep: Fn(Duration) + Send + Sync + 'static,
+{
+    /// Create the `in_progress` check run `name` for the commit `head_sha`, returning a
+    /// reporter sending its annotations.
+    pub fn start_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        head_sha: &str,
+    ) -> Result<CheckRunReporter<'_>, ApiError> {
+        let inner = wait(self.inner.start_check_run(owner, repo, name, head_sha))?;
+        Ok(CheckRunReporter { inner })
+    }
+}
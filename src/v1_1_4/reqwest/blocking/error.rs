@@ -0,0 +1,14 @@
+use crate::v1_1_4::caller;
+use crate::v1_1_4::transport::wait;
+use crate::v1_1_4::ApiError;
+
+/// Return the response if its status is successful, or an error decoded from the response.
+///
+/// ```ignore
+/// let response = error_for_status(github.repos_get(owner, repo)?)?;
+/// ```
+pub fn error_for_status(
+    response: ::reqwest::blocking::Response,
+) -> Result<::reqwest::blocking::Response, ApiError> {
+    wait(caller::error_for_status(response))
+}
@@ -0,0 +1,63 @@
+/// ActionsPublicKey
+/// 
+/// The public key used for setting Actions Secrets.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ActionsPublicKey<'a> {
+    /// The identifier for the key.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "1234567"
+    /// ```
+    pub key_id: ::std::borrow::Cow<'a, str>,
+
+    /// The Base64 encoded public key.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs="
+    /// ```
+    pub key: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 2
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub id: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/user/keys/2"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "ssh-rsa AAAAB3NzaC1yc2EAAA"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub created_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,147 @@
+/// Api Overview
+/// 
+/// Api Overview
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ApiOverview<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub verifiable_password_authentication: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ssh_key_fingerprints: ::std::option::Option<crate::v1_1_4::schema::api_overview::SshKeyFingerprints<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "127.0.0.1/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub hooks: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "127.0.0.1/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub web: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "127.0.0.1/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub api: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "127.0.0.1/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub git: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "13.65.0.0/16",
+    ///   "157.55.204.33/32",
+    ///   "2a01:111:f403:f90c::/62"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub packages: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "192.30.252.153/32",
+    ///   "192.30.252.154/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pages: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "54.158.161.132",
+    ///   "54.226.70.38"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub importer: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "13.64.0.0/16",
+    ///   "13.65.0.0/16"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub actions: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "192.168.7.15/32",
+    ///   "192.168.7.16/32"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub dependabot: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`ApiOverview`]
+pub mod api_overview {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct SshKeyFingerprints<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub SHA256_RSA: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub SHA256_DSA: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub SHA256_ECDSA: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub SHA256_ED25519: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
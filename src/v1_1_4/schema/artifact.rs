@@ -0,0 +1,126 @@
+/// Artifact
+/// 
+/// An artifact
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Artifact<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDEwOkNoZWNrU3VpdGU1"
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The name of the artifact.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "AdventureWorks.Framework"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// The size in bytes of the artifact.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 12345
+    /// ```
+    pub size_in_bytes: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/artifacts/5"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/artifacts/5/zip"
+    /// ```
+    pub archive_download_url: ::std::borrow::Cow<'a, str>,
+
+    /// Whether or not the artifact has expired.
+    pub expired: bool,
+
+    pub created_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub expires_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub updated_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub workflow_run: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::artifact::WorkflowRun<'a>>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`Artifact`]
+pub mod artifact {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct WorkflowRun<'a> {
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// 10
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub id: ::std::option::Option<i64>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// 42
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub repository_id: ::std::option::Option<i64>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// 42
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub head_repository_id: ::std::option::Option<i64>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "main"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub head_branch: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub head_sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,21 @@
+/// Basic Error
+/// 
+/// Basic Error
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct BasicError<'a> {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub message: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub documentation_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub status: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
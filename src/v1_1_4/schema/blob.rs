@@ -0,0 +1,24 @@
+/// Blob
+/// 
+/// Blob
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Blob<'a> {
+    pub content: ::std::borrow::Cow<'a, str>,
+
+    pub encoding: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    pub size: ::std::option::Option<i64>,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub highlighted_content: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
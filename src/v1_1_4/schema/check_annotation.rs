@@ -0,0 +1,83 @@
+/// Check Annotation
+/// 
+/// Check Annotation
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CheckAnnotation<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "README.md"
+    /// ```
+    pub path: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 2
+    /// ```
+    pub start_line: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 2
+    /// ```
+    pub end_line: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    pub start_column: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 10
+    /// ```
+    pub end_column: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "warning"
+    /// ```
+    pub annotation_level: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Spell Checker"
+    /// ```
+    pub title: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Check your spelling for 'banaas'."
+    /// ```
+    pub message: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Do you mean 'bananas' or 'banana'?"
+    /// ```
+    pub raw_details: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub blob_href: ::std::borrow::Cow<'a, str>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
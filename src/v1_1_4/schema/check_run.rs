@@ -0,0 +1,149 @@
+/// CheckRun
+/// 
+/// A check performed on the code of a given code change
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CheckRun<'a> {
+    /// The id of the check.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 21
+    /// ```
+    pub id: i64,
+
+    /// The SHA of the commit that is being checked.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d"
+    /// ```
+    pub head_sha: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDg6Q2hlY2tSdW40"
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "42"
+    /// ```
+    pub external_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/check-runs/4"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/github/hello-world/runs/4"
+    /// ```
+    pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://example.com"
+    /// ```
+    pub details_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The phase of the lifecycle that the check is currently in.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "queued"
+    /// ```
+    pub status: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "neutral"
+    /// ```
+    pub conclusion: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2018-05-04T01:14:52Z"
+    /// ```
+    pub started_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2018-05-04T01:14:52Z"
+    /// ```
+    pub completed_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub output: crate::v1_1_4::schema::check_run::Output<'a>,
+
+    /// The name of the check.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "test-coverage"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    pub check_suite: ::std::option::Option<crate::v1_1_4::schema::check_run::CheckSuite<'a>>,
+
+    pub app: ::std::option::Option<crate::v1_1_4::schema::NullableIntegration<'a>>,
+
+    pub pull_requests: ::std::borrow::Cow<'a, [crate::v1_1_4::schema::PullRequestMinimal<'a>]>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deployment: ::std::option::Option<::serde_json::value::Value>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`CheckRun`]
+pub mod check_run {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Output<'a> {
+        pub title: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub summary: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub text: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub annotations_count: i64,
+
+        pub annotations_url: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct CheckSuite<'a> {
+        pub id: i64,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
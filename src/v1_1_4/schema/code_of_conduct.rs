@@ -0,0 +1,38 @@
+/// Code Of Conduct
+/// 
+/// Code Of Conduct
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CodeOfConduct<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "contributor_covenant"
+    /// ```
+    pub key: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Contributor Covenant"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/codes_of_conduct/contributor_covenant"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,20 @@
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CodeScanningSarifsReceipt<'a> {
+    /// An identifier for the upload.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "6c81cd8e-b078-4ac3-a3be-1dad7dbd0b53"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The REST API URL for checking the status of the upload.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
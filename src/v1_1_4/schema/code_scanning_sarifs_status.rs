@@ -0,0 +1,18 @@
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct CodeScanningSarifsStatus<'a> {
+    /// `pending` files have not yet been processed, while `complete` means results from the SARIF have been stored. `failed` files have either not been processed at all, or could only be partially processed.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub processing_status: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The REST API URL for getting the analyses associated with the upload.
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub analyses_url: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// Any errors that ocurred during processing of the delivery.
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub errors: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,68 @@
+/// Content File
+/// 
+/// Content File
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ContentFile<'a> {
+    pub r#type: ::std::borrow::Cow<'a, str>,
+
+    pub encoding: ::std::borrow::Cow<'a, str>,
+
+    pub size: i64,
+
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    pub path: ::std::borrow::Cow<'a, str>,
+
+    pub content: ::std::borrow::Cow<'a, str>,
+
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub git_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub download_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub _links: crate::v1_1_4::schema::content_file::Links<'a>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"actual/actual.md\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub target: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"git://example.com/defunkt/dotjs.git\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub submodule_git_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`ContentFile`]
+pub mod content_file {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Links<'a> {
+        pub git: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(rename = "self")]
+        pub self_: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
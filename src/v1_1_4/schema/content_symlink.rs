@@ -0,0 +1,48 @@
+/// Symlink Content
+/// 
+/// An object describing a symlink
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ContentSymlink<'a> {
+    pub r#type: ::std::borrow::Cow<'a, str>,
+
+    pub target: ::std::borrow::Cow<'a, str>,
+
+    pub size: i64,
+
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    pub path: ::std::borrow::Cow<'a, str>,
+
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub git_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub download_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub _links: crate::v1_1_4::schema::content_symlink::Links<'a>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`ContentSymlink`]
+pub mod content_symlink {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Links<'a> {
+        pub git: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(rename = "self")]
+        pub self_: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
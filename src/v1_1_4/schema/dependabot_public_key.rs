@@ -0,0 +1,27 @@
+/// DependabotPublicKey
+/// 
+/// The public key used for setting Dependabot Secrets.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct DependabotPublicKey<'a> {
+    /// The identifier for the key.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "1234567"
+    /// ```
+    pub key_id: ::std::borrow::Cow<'a, str>,
+
+    /// The Base64 encoded public key.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs="
+    /// ```
+    pub key: ::std::borrow::Cow<'a, str>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
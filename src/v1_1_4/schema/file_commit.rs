@@ -0,0 +1,192 @@
+/// File Commit
+/// 
+/// File Commit
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct FileCommit<'a> {
+    pub content: ::std::option::Option<crate::v1_1_4::schema::file_commit::Content<'a>>,
+
+    pub commit: crate::v1_1_4::schema::file_commit::Commit<'a>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`FileCommit`]
+pub mod file_commit {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Content<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub path: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub size: ::std::option::Option<i64>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub git_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub download_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub r#type: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub _links: ::std::option::Option<crate::v1_1_4::schema::file_commit::content::Links<'a>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    /// Types for fields in [`Content`]
+    pub mod content {
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Links<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default, rename = "self")]
+            pub self_: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub git: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Commit<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub node_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub author: ::std::option::Option<crate::v1_1_4::schema::file_commit::commit::Author<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub committer: ::std::option::Option<crate::v1_1_4::schema::file_commit::commit::Committer<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub message: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub tree: ::std::option::Option<crate::v1_1_4::schema::file_commit::commit::Tree<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub parents: ::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::file_commit::commit::Parents<'a>]>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub verification: ::std::option::Option<crate::v1_1_4::schema::file_commit::commit::Verification<'a>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    /// Types for fields in [`Commit`]
+    pub mod commit {
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Author<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub date: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub email: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Committer<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub date: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub email: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Tree<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Parents<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Verification<'a> {
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub verified: ::std::option::Option<bool>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default)]
+            pub reason: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+            pub signature: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+            #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+            pub payload: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+    }
+}
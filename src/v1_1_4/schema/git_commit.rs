@@ -0,0 +1,169 @@
+/// Git Commit
+/// 
+/// Low-level Git commit operations within a repository
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct GitCommit<'a> {
+    /// SHA for the commit
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "7638417db6d59f3c431d3e1f261cc637155684cd"
+    /// ```
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// Identifying information for the git-user
+    pub author: crate::v1_1_4::schema::git_commit::Author<'a>,
+
+    /// Identifying information for the git-user
+    pub committer: crate::v1_1_4::schema::git_commit::Committer<'a>,
+
+    /// Message describing the purpose of the commit
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Fix #42"
+    /// ```
+    pub message: ::std::borrow::Cow<'a, str>,
+
+    pub tree: crate::v1_1_4::schema::git_commit::Tree<'a>,
+
+    pub parents: ::std::borrow::Cow<'a, [crate::v1_1_4::schema::git_commit::Parents<'a>]>,
+
+    pub verification: crate::v1_1_4::schema::git_commit::Verification<'a>,
+
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`GitCommit`]
+pub mod git_commit {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Author<'a> {
+        /// Timestamp of the commit
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "2014-08-09T08:02:04+12:00"
+        /// ```
+        pub date: ::std::borrow::Cow<'a, str>,
+
+        /// Git email address of the user
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "monalisa.octocat@example.com"
+        /// ```
+        pub email: ::std::borrow::Cow<'a, str>,
+
+        /// Name of the git user
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "Monalisa Octocat"
+        /// ```
+        pub name: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Committer<'a> {
+        /// Timestamp of the commit
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "2014-08-09T08:02:04+12:00"
+        /// ```
+        pub date: ::std::borrow::Cow<'a, str>,
+
+        /// Git email address of the user
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "monalisa.octocat@example.com"
+        /// ```
+        pub email: ::std::borrow::Cow<'a, str>,
+
+        /// Name of the git user
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "Monalisa Octocat"
+        /// ```
+        pub name: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Tree<'a> {
+        /// SHA for the commit
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "7638417db6d59f3c431d3e1f261cc637155684cd"
+        /// ```
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub url: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Parents<'a> {
+        /// SHA for the commit
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "7638417db6d59f3c431d3e1f261cc637155684cd"
+        /// ```
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub url: ::std::borrow::Cow<'a, str>,
+
+        pub html_url: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Verification<'a> {
+        pub verified: bool,
+
+        pub reason: ::std::borrow::Cow<'a, str>,
+
+        pub signature: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub payload: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,40 @@
+/// Git Reference
+/// 
+/// Git references within a repository
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct GitRef<'a> {
+    pub r#ref: ::std::borrow::Cow<'a, str>,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub object: crate::v1_1_4::schema::git_ref::Object<'a>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`GitRef`]
+pub mod git_ref {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Object<'a> {
+        pub r#type: ::std::borrow::Cow<'a, str>,
+
+        /// SHA for the reference
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "7638417db6d59f3c431d3e1f261cc637155684cd"
+        /// ```
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub url: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,82 @@
+/// Git Tree
+/// 
+/// The hierarchy between files in a Git repository.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct GitTree<'a> {
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub truncated: bool,
+
+    /// Objects specifying a tree structure
+    pub tree: ::std::borrow::Cow<'a, [crate::v1_1_4::schema::git_tree::Tree<'a>]>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`GitTree`]
+pub mod git_tree {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Tree<'a> {
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "test/file.rb"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub path: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "040000"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub mode: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "tree"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub r#type: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "23f6827669e43831def8a7ad935069c8bd418261"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// 12
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub size: ::std::option::Option<i64>,
+
+        /// 
+        /// # Example
+        /// 
+        /// ```json
+        /// "https://api.github.com/repos/owner-482f3203ecf01f67e9deb18e/BBB_Private_Repo/git/blobs/23f6827669e43831def8a7ad935069c8bd418261"
+        /// ```
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
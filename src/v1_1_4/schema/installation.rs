@@ -0,0 +1,124 @@
+/// Installation
+/// 
+/// Installation
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Installation<'a> {
+    /// The ID of the installation.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    pub id: i64,
+
+    pub account: ::std::option::Option<::serde_json::value::Value>,
+
+    /// Describe whether all repositories have been selected or there's a selection involved
+    pub repository_selection: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/installations/1/access_tokens"
+    /// ```
+    pub access_tokens_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/installation/repositories"
+    /// ```
+    pub repositories_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/organizations/github/settings/installations/1"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    pub app_id: i64,
+
+    /// The ID of the user or organization this token is being scoped to.
+    pub target_id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Organization"
+    /// ```
+    pub target_type: ::std::borrow::Cow<'a, str>,
+
+    pub permissions: crate::v1_1_4::schema::AppPermissions<'a>,
+
+    pub events: ::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>,
+
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "config.yaml"
+    /// ```
+    pub single_file_name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_multiple_single_files: ::std::option::Option<bool>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "config.yml",
+    ///   ".github/issue_TEMPLATE.md"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub single_file_paths: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "github-actions"
+    /// ```
+    pub app_slug: ::std::borrow::Cow<'a, str>,
+
+    pub suspended_by: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    pub suspended_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"test_13f1e99741e3e004@d7e1eb0bc0a1ba12.com\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub contact_email: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,52 @@
+/// Installation Token
+/// 
+/// Authentication token for a GitHub App installed on a user or org.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct InstallationToken<'a> {
+    pub token: ::std::borrow::Cow<'a, str>,
+
+    pub expires_at: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub permissions: ::std::option::Option<crate::v1_1_4::schema::AppPermissions<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub repository_selection: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub repositories: ::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::Repository<'a>]>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "README.md"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub single_file: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_multiple_single_files: ::std::option::Option<bool>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "config.yml",
+    ///   ".github/issue_TEMPLATE.md"
+    /// ]
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub single_file_paths: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,160 @@
+/// Issue
+/// 
+/// Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Issue<'a> {
+    pub id: i64,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// URL for the issue
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repositories/42/issues/1"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub repository_url: ::std::borrow::Cow<'a, str>,
+
+    pub labels_url: ::std::borrow::Cow<'a, str>,
+
+    pub comments_url: ::std::borrow::Cow<'a, str>,
+
+    pub events_url: ::std::borrow::Cow<'a, str>,
+
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// Number uniquely identifying the issue within its repository
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 42
+    /// ```
+    pub number: i64,
+
+    /// State of the issue; either 'open' or 'closed'
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "open"
+    /// ```
+    pub state: ::std::borrow::Cow<'a, str>,
+
+    /// Title of the issue
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Widget creation fails in Safari on OS X 10.8"
+    /// ```
+    pub title: ::std::borrow::Cow<'a, str>,
+
+    /// Contents of the issue
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "It looks like the new widget form is broken on Safari. When I try and create the widget, Safari crashes. This is reproducible on 10.8, but not 10.9. Maybe a browser bug?"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub body: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    pub user: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// Labels to associate with this issue; pass one or more label names to replace the set of labels on this issue; send an empty array to clear all labels from the issue; note that the labels are silently dropped for users without push access to the repository
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "bug",
+    ///   "registration"
+    /// ]
+    /// ```
+    pub labels: ::std::borrow::Cow<'a, [::serde_json::value::Value]>,
+
+    pub assignee: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub assignees: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::SimpleUser<'a>]>>>,
+
+    pub milestone: ::std::option::Option<crate::v1_1_4::schema::NullableMilestone<'a>>,
+
+    pub locked: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub active_lock_reason: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    pub comments: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pull_request: ::std::option::Option<crate::v1_1_4::schema::issue::PullRequest<'a>>,
+
+    pub closed_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub draft: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub closed_by: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_text: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub timeline_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub repository: ::std::option::Option<crate::v1_1_4::schema::Repository<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub performed_via_github_app: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::NullableIntegration<'a>>>,
+
+    /// How the author is associated with the repository.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "OWNER"
+    /// ```
+    pub author_association: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reactions: ::std::option::Option<crate::v1_1_4::schema::ReactionRollup<'a>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`Issue`]
+pub mod issue {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct PullRequest<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+        pub merged_at: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+        pub diff_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub patch_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,82 @@
+/// Issue Comment
+/// 
+/// Comments provide a way for people to collaborate on an issue.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct IssueComment<'a> {
+    /// Unique identifier of the issue comment
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 42
+    /// ```
+    pub id: i64,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// URL for the issue comment
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repositories/42/issues/comments/1"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// Contents of the issue comment
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "What version of Safari were you using when you observed this bug?"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_text: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    pub user: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-04-14T16:00:49Z"
+    /// ```
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-04-14T16:00:49Z"
+    /// ```
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    pub issue_url: ::std::borrow::Cow<'a, str>,
+
+    /// How the author is associated with the repository.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "OWNER"
+    /// ```
+    pub author_association: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub performed_via_github_app: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::NullableIntegration<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reactions: ::std::option::Option<crate::v1_1_4::schema::ReactionRollup<'a>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,68 @@
+/// Label
+/// 
+/// Color-coded labels help you categorize and filter your issues (just like labels in Gmail).
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Label<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 208045946
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDU6TGFiZWwyMDgwNDU5NDY="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// URL for the label
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repositories/42/labels/bug"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// The name of the label.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "bug"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Something isn't working"
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 6-character hex code, without the leading #, identifying the color
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "FFFFFF"
+    /// ```
+    pub color: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub default: bool,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
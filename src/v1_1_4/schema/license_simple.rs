@@ -0,0 +1,52 @@
+/// License Simple
+/// 
+/// License Simple
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct LicenseSimple<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "mit"
+    /// ```
+    pub key: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MIT License"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/licenses/mit"
+    /// ```
+    pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MIT"
+    /// ```
+    pub spdx_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDc6TGljZW5zZW1pdA=="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub html_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
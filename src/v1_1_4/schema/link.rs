@@ -0,0 +1,11 @@
+/// Link
+/// 
+/// Hypermedia Link
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Link<'a> {
+    pub href: ::std::borrow::Cow<'a, str>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
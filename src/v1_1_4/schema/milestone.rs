@@ -0,0 +1,134 @@
+/// Milestone
+/// 
+/// A collection of related issues and pull requests.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Milestone<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/milestones/1"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World/milestones/v1.0"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/milestones/1/labels"
+    /// ```
+    pub labels_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1002604
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDk6TWlsZXN0b25lMTAwMjYwNA=="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The number of the milestone.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 42
+    /// ```
+    pub number: i64,
+
+    /// The state of the milestone.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "open"
+    /// ```
+    pub state: ::std::borrow::Cow<'a, str>,
+
+    /// The title of the milestone.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "v1.0"
+    /// ```
+    pub title: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Tracking milestone for version 1.0"
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub creator: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 4
+    /// ```
+    pub open_issues: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 8
+    /// ```
+    pub closed_issues: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-04-10T20:09:31Z"
+    /// ```
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2014-03-03T18:58:10Z"
+    /// ```
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2013-02-12T13:22:01Z"
+    /// ```
+    pub closed_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2012-10-09T23:39:01Z"
+    /// ```
+    pub due_on: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
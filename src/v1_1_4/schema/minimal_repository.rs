@@ -0,0 +1,346 @@
+/// Minimal Repository
+/// 
+/// Minimal Repository
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct MinimalRepository<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1296269
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDEwOlJlcG9zaXRvcnkxMjk2MjY5"
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Hello-World"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "octocat/Hello-World"
+    /// ```
+    pub full_name: ::std::borrow::Cow<'a, str>,
+
+    pub owner: crate::v1_1_4::schema::SimpleUser<'a>,
+
+    pub private: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "This your first repo!"
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub fork: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub archive_url: ::std::borrow::Cow<'a, str>,
+
+    pub assignees_url: ::std::borrow::Cow<'a, str>,
+
+    pub blobs_url: ::std::borrow::Cow<'a, str>,
+
+    pub branches_url: ::std::borrow::Cow<'a, str>,
+
+    pub collaborators_url: ::std::borrow::Cow<'a, str>,
+
+    pub comments_url: ::std::borrow::Cow<'a, str>,
+
+    pub commits_url: ::std::borrow::Cow<'a, str>,
+
+    pub compare_url: ::std::borrow::Cow<'a, str>,
+
+    pub contents_url: ::std::borrow::Cow<'a, str>,
+
+    pub contributors_url: ::std::borrow::Cow<'a, str>,
+
+    pub deployments_url: ::std::borrow::Cow<'a, str>,
+
+    pub downloads_url: ::std::borrow::Cow<'a, str>,
+
+    pub events_url: ::std::borrow::Cow<'a, str>,
+
+    pub forks_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_commits_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_refs_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_tags_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub git_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub issue_comment_url: ::std::borrow::Cow<'a, str>,
+
+    pub issue_events_url: ::std::borrow::Cow<'a, str>,
+
+    pub issues_url: ::std::borrow::Cow<'a, str>,
+
+    pub keys_url: ::std::borrow::Cow<'a, str>,
+
+    pub labels_url: ::std::borrow::Cow<'a, str>,
+
+    pub languages_url: ::std::borrow::Cow<'a, str>,
+
+    pub merges_url: ::std::borrow::Cow<'a, str>,
+
+    pub milestones_url: ::std::borrow::Cow<'a, str>,
+
+    pub notifications_url: ::std::borrow::Cow<'a, str>,
+
+    pub pulls_url: ::std::borrow::Cow<'a, str>,
+
+    pub releases_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ssh_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub stargazers_url: ::std::borrow::Cow<'a, str>,
+
+    pub statuses_url: ::std::borrow::Cow<'a, str>,
+
+    pub subscribers_url: ::std::borrow::Cow<'a, str>,
+
+    pub subscription_url: ::std::borrow::Cow<'a, str>,
+
+    pub tags_url: ::std::borrow::Cow<'a, str>,
+
+    pub teams_url: ::std::borrow::Cow<'a, str>,
+
+    pub trees_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub clone_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub mirror_url: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    pub hooks_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub svn_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub homepage: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub language: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub forks_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stargazers_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub watchers_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub size: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub default_branch: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub open_issues_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub is_template: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub topics: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_issues: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_projects: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_wiki: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_pages: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub has_downloads: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub archived: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub disabled: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub visibility: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:06:43Z"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub pushed_at: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub created_at: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:14:43Z"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub updated_at: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub permissions: ::std::option::Option<crate::v1_1_4::schema::minimal_repository::Permissions<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "admin"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub role_name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub temp_clone_token: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub delete_branch_on_merge: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub subscribers_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub network_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub code_of_conduct: ::std::option::Option<crate::v1_1_4::schema::CodeOfConduct<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub license: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::minimal_repository::License<'a>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 0
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub forks: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 0
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub open_issues: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 0
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub watchers: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_forking: ::std::option::Option<bool>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`MinimalRepository`]
+pub mod minimal_repository {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Permissions<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub admin: ::std::option::Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub maintain: ::std::option::Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub push: ::std::option::Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub triage: ::std::option::Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub pull: ::std::option::Option<bool>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct License<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub key: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub spdx_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub node_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
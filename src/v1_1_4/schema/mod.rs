@@ -1,5 +1,17 @@
 #![allow(non_snake_case)]
 
+//! Component schemas of the API description.
+//!
+//! Only the schemas referenced by the generated `response` modules and by the helpers of
+//! this crate are generated, e.g. [`Repository`], [`Issue`], [`PullRequest`],
+//! [`WorkflowRun`], [`CheckRun`], [`Installation`] and [`SimpleUser`], together with the
+//! schemas they refer to. Request bodies and responses using other schemas still decode
+//! them as `serde_json::Value`.
+
+#[path="actions_public_key.rs"]
+mod actions_public_key_Module;
+pub use actions_public_key_Module::*;
+
 #[path="actions_set_default_workflow_permissions.rs"]
 mod actions_set_default_workflow_permissions_Module;
 pub use actions_set_default_workflow_permissions_Module::*;
@@ -8,18 +20,226 @@ pub use actions_set_default_workflow_permissions_Module::*;
 mod actions_workflow_access_to_repository_Module;
 pub use actions_workflow_access_to_repository_Module::*;
 
+#[path="api_overview.rs"]
+mod api_overview_Module;
+pub use api_overview_Module::*;
+
 #[path="app_permissions.rs"]
 mod app_permissions_Module;
 pub use app_permissions_Module::*;
 
+#[path="artifact.rs"]
+mod artifact_Module;
+pub use artifact_Module::*;
+
+#[path="basic_error.rs"]
+mod basic_error_Module;
+pub use basic_error_Module::*;
+
+#[path="blob.rs"]
+mod blob_Module;
+pub use blob_Module::*;
+
+#[path="check_annotation.rs"]
+mod check_annotation_Module;
+pub use check_annotation_Module::*;
+
+#[path="check_run.rs"]
+mod check_run_Module;
+pub use check_run_Module::*;
+
+#[path="code_of_conduct.rs"]
+mod code_of_conduct_Module;
+pub use code_of_conduct_Module::*;
+
+#[path="code_scanning_sarifs_receipt.rs"]
+mod code_scanning_sarifs_receipt_Module;
+pub use code_scanning_sarifs_receipt_Module::*;
+
+#[path="code_scanning_sarifs_status.rs"]
+mod code_scanning_sarifs_status_Module;
+pub use code_scanning_sarifs_status_Module::*;
+
+#[path="codespaces_public_key.rs"]
+mod codespaces_public_key_Module;
+pub use codespaces_public_key_Module::*;
+
+#[path="content_file.rs"]
+mod content_file_Module;
+pub use content_file_Module::*;
+
+#[path="content_submodule.rs"]
+mod content_submodule_Module;
+pub use content_submodule_Module::*;
+
+#[path="content_symlink.rs"]
+mod content_symlink_Module;
+pub use content_symlink_Module::*;
+
+#[path="dependabot_public_key.rs"]
+mod dependabot_public_key_Module;
+pub use dependabot_public_key_Module::*;
+
 #[path="deployment_branch_policy.rs"]
 mod deployment_branch_policy_Module;
 pub use deployment_branch_policy_Module::*;
 
+#[path="file_commit.rs"]
+mod file_commit_Module;
+pub use file_commit_Module::*;
+
+#[path="git_commit.rs"]
+mod git_commit_Module;
+pub use git_commit_Module::*;
+
+#[path="git_ref.rs"]
+mod git_ref_Module;
+pub use git_ref_Module::*;
+
+#[path="git_tree.rs"]
+mod git_tree_Module;
+pub use git_tree_Module::*;
+
+#[path="installation.rs"]
+mod installation_Module;
+pub use installation_Module::*;
+
+#[path="installation_token.rs"]
+mod installation_token_Module;
+pub use installation_token_Module::*;
+
+#[path="integration.rs"]
+mod integration_Module;
+pub use integration_Module::*;
+
 #[path="interaction_limit.rs"]
 mod interaction_limit_Module;
 pub use interaction_limit_Module::*;
 
+#[path="issue.rs"]
+mod issue_Module;
+pub use issue_Module::*;
+
+#[path="issue_comment.rs"]
+mod issue_comment_Module;
+pub use issue_comment_Module::*;
+
+#[path="label.rs"]
+mod label_Module;
+pub use label_Module::*;
+
+#[path="license_simple.rs"]
+mod license_simple_Module;
+pub use license_simple_Module::*;
+
+#[path="link.rs"]
+mod link_Module;
+pub use link_Module::*;
+
+#[path="milestone.rs"]
+mod milestone_Module;
+pub use milestone_Module::*;
+
+#[path="minimal_repository.rs"]
+mod minimal_repository_Module;
+pub use minimal_repository_Module::*;
+
+#[path="nullable_integration.rs"]
+mod nullable_integration_Module;
+pub use nullable_integration_Module::*;
+
+#[path="nullable_license_simple.rs"]
+mod nullable_license_simple_Module;
+pub use nullable_license_simple_Module::*;
+
+#[path="nullable_milestone.rs"]
+mod nullable_milestone_Module;
+pub use nullable_milestone_Module::*;
+
+#[path="nullable_simple_commit.rs"]
+mod nullable_simple_commit_Module;
+pub use nullable_simple_commit_Module::*;
+
+#[path="nullable_simple_user.rs"]
+mod nullable_simple_user_Module;
+pub use nullable_simple_user_Module::*;
+
+#[path="nullable_team_simple.rs"]
+mod nullable_team_simple_Module;
+pub use nullable_team_simple_Module::*;
+
+#[path="organization_simple.rs"]
+mod organization_simple_Module;
+pub use organization_simple_Module::*;
+
+#[path="pull_request.rs"]
+mod pull_request_Module;
+pub use pull_request_Module::*;
+
+#[path="pull_request_minimal.rs"]
+mod pull_request_minimal_Module;
+pub use pull_request_minimal_Module::*;
+
+#[path="pull_request_simple.rs"]
+mod pull_request_simple_Module;
+pub use pull_request_simple_Module::*;
+
+#[path="rate_limit.rs"]
+mod rate_limit_Module;
+pub use rate_limit_Module::*;
+
+#[path="rate_limit_overview.rs"]
+mod rate_limit_overview_Module;
+pub use rate_limit_overview_Module::*;
+
+#[path="reaction_rollup.rs"]
+mod reaction_rollup_Module;
+pub use reaction_rollup_Module::*;
+
+#[path="release.rs"]
+mod release_Module;
+pub use release_Module::*;
+
+#[path="release_asset.rs"]
+mod release_asset_Module;
+pub use release_asset_Module::*;
+
+#[path="repository.rs"]
+mod repository_Module;
+pub use repository_Module::*;
+
 #[path="selected_actions.rs"]
 mod selected_actions_Module;
 pub use selected_actions_Module::*;
+
+#[path="short_blob.rs"]
+mod short_blob_Module;
+pub use short_blob_Module::*;
+
+#[path="simple_commit.rs"]
+mod simple_commit_Module;
+pub use simple_commit_Module::*;
+
+#[path="simple_user.rs"]
+mod simple_user_Module;
+pub use simple_user_Module::*;
+
+#[path="team_simple.rs"]
+mod team_simple_Module;
+pub use team_simple_Module::*;
+
+#[path="validation_error.rs"]
+mod validation_error_Module;
+pub use validation_error_Module::*;
+
+#[path="validation_error_simple.rs"]
+mod validation_error_simple_Module;
+pub use validation_error_simple_Module::*;
+
+#[path="workflow.rs"]
+mod workflow_Module;
+pub use workflow_Module::*;
+
+#[path="workflow_run.rs"]
+mod workflow_run_Module;
+pub use workflow_run_Module::*;
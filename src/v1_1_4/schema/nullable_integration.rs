@@ -0,0 +1,151 @@
+/// GitHub app
+/// 
+/// GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct NullableIntegration<'a> {
+    /// Unique identifier of the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 37
+    /// ```
+    pub id: i64,
+
+    /// The slug name of the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "probot-owners"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub slug: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDExOkludGVncmF0aW9uMQ=="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    pub owner: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// The name of the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Probot Owners"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "The description of the app."
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://example.com"
+    /// ```
+    pub external_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/apps/super-ci"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2017-07-08T16:18:44-04:00"
+    /// ```
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2017-07-08T16:18:44-04:00"
+    /// ```
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    /// The set of permissions for the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// {
+    ///   "issues": "read",
+    ///   "deployments": "write"
+    /// }
+    /// ```
+    pub permissions: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::std::borrow::Cow<'a, str>>,
+
+    /// The list of events for the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// [
+    ///   "label",
+    ///   "deployment"
+    /// ]
+    /// ```
+    pub events: ::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>,
+
+    /// The number of installations associated with the GitHub app
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub installations_count: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"Iv1.25b5d1e65ffc4022\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub client_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"1d4b2097ac622ba702d19de498f005747a8b21d3\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub client_secret: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"6fba8f2fc8a7e8f2cca5577eddd82ca7586b3b6b\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub webhook_secret: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pem: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
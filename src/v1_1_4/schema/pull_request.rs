@@ -0,0 +1,385 @@
+/// Pull Request
+/// 
+/// Pull requests let you tell others about changes you've pushed to a repository on GitHub. Once a pull request is sent, interested parties can review the set of changes, discuss potential modifications, and even push follow-up commits if necessary.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct PullRequest<'a> {
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/pulls/1347"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDExOlB1bGxSZXF1ZXN0MQ=="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World/pull/1347"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World/pull/1347.diff"
+    /// ```
+    pub diff_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World/pull/1347.patch"
+    /// ```
+    pub patch_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/issues/1347"
+    /// ```
+    pub issue_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/pulls/1347/commits"
+    /// ```
+    pub commits_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/pulls/1347/comments"
+    /// ```
+    pub review_comments_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/pulls/comments{/number}"
+    /// ```
+    pub review_comment_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/issues/1347/comments"
+    /// ```
+    pub comments_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World/statuses/6dcb09b5b57875f334f61aebed695e2e4193db5e"
+    /// ```
+    pub statuses_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1347
+    /// ```
+    pub number: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "open"
+    /// ```
+    pub state: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub locked: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Amazing new feature"
+    /// ```
+    pub title: ::std::borrow::Cow<'a, str>,
+
+    pub user: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Please pull these awesome changes"
+    /// ```
+    pub body: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub labels: ::std::borrow::Cow<'a, [crate::v1_1_4::schema::Label<'a>]>,
+
+    pub milestone: ::std::option::Option<crate::v1_1_4::schema::NullableMilestone<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "too heated"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub active_lock_reason: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    pub closed_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    pub merged_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "e5bd3914e2e596debea16f433f57875b5b90bcd6"
+    /// ```
+    pub merge_commit_sha: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub assignee: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub assignees: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::SimpleUser<'a>]>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub requested_reviewers: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::SimpleUser<'a>]>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub requested_teams: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::TeamSimple<'a>]>>>,
+
+    pub head: crate::v1_1_4::schema::pull_request::Head<'a>,
+
+    pub base: crate::v1_1_4::schema::pull_request::Base<'a>,
+
+    pub _links: crate::v1_1_4::schema::pull_request::Links<'a>,
+
+    /// How the author is associated with the repository.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "OWNER"
+    /// ```
+    pub author_association: ::std::borrow::Cow<'a, str>,
+
+    pub auto_merge: ::std::option::Option<::serde_json::value::Value>,
+
+    /// Indicates whether or not the pull request is a draft.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// false
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub draft: ::std::option::Option<bool>,
+
+    pub merged: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub mergeable: ::std::option::Option<bool>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub rebaseable: ::std::option::Option<::std::option::Option<bool>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "clean"
+    /// ```
+    pub mergeable_state: ::std::borrow::Cow<'a, str>,
+
+    pub merged_by: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 10
+    /// ```
+    pub comments: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 0
+    /// ```
+    pub review_comments: i64,
+
+    /// Indicates whether maintainers can modify the pull request.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub maintainer_can_modify: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 3
+    /// ```
+    pub commits: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 100
+    /// ```
+    pub additions: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 3
+    /// ```
+    pub deletions: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    pub changed_files: i64,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`PullRequest`]
+pub mod pull_request {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Head<'a> {
+        pub label: ::std::borrow::Cow<'a, str>,
+
+        pub r#ref: ::std::borrow::Cow<'a, str>,
+
+        pub repo: ::std::option::Option<crate::v1_1_4::schema::Repository<'a>>,
+
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub user: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Base<'a> {
+        pub label: ::std::borrow::Cow<'a, str>,
+
+        pub r#ref: ::std::borrow::Cow<'a, str>,
+
+        pub repo: ::std::option::Option<crate::v1_1_4::schema::Repository<'a>>,
+
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub user: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Links<'a> {
+        pub comments: crate::v1_1_4::schema::Link<'a>,
+
+        pub commits: crate::v1_1_4::schema::Link<'a>,
+
+        pub statuses: crate::v1_1_4::schema::Link<'a>,
+
+        pub html: crate::v1_1_4::schema::Link<'a>,
+
+        pub issue: crate::v1_1_4::schema::Link<'a>,
+
+        pub review_comments: crate::v1_1_4::schema::Link<'a>,
+
+        pub review_comment: crate::v1_1_4::schema::Link<'a>,
+
+        #[serde(rename = "self")]
+        pub self_: crate::v1_1_4::schema::Link<'a>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,77 @@
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct PullRequestMinimal<'a> {
+    pub id: i64,
+
+    pub number: i64,
+
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub head: crate::v1_1_4::schema::pull_request_minimal::Head<'a>,
+
+    pub base: crate::v1_1_4::schema::pull_request_minimal::Base<'a>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`PullRequestMinimal`]
+pub mod pull_request_minimal {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Head<'a> {
+        pub r#ref: ::std::borrow::Cow<'a, str>,
+
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub repo: crate::v1_1_4::schema::pull_request_minimal::head::Repo<'a>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    /// Types for fields in [`Head`]
+    pub mod head {
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Repo<'a> {
+            pub id: i64,
+
+            pub url: ::std::borrow::Cow<'a, str>,
+
+            pub name: ::std::borrow::Cow<'a, str>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Base<'a> {
+        pub r#ref: ::std::borrow::Cow<'a, str>,
+
+        pub sha: ::std::borrow::Cow<'a, str>,
+
+        pub repo: crate::v1_1_4::schema::pull_request_minimal::base::Repo<'a>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    /// Types for fields in [`Base`]
+    pub mod base {
+        #[allow(non_snake_case)]
+        #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Repo<'a> {
+            pub id: i64,
+
+            pub url: ::std::borrow::Cow<'a, str>,
+
+            pub name: ::std::borrow::Cow<'a, str>,
+
+            #[serde(flatten)]
+            pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/// Rate Limit
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct RateLimit<'a> {
+    pub limit: i64,
+
+    pub remaining: i64,
+
+    pub reset: i64,
+
+    pub used: i64,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,45 @@
+/// Rate Limit Overview
+/// 
+/// Rate Limit Overview
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct RateLimitOverview<'a> {
+    pub resources: crate::v1_1_4::schema::rate_limit_overview::Resources<'a>,
+
+    pub rate: crate::v1_1_4::schema::RateLimit<'a>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`RateLimitOverview`]
+pub mod rate_limit_overview {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Resources<'a> {
+        pub core: crate::v1_1_4::schema::RateLimit<'a>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub graphql: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        pub search: crate::v1_1_4::schema::RateLimit<'a>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub source_import: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub integration_manifest: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub code_scanning_upload: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub actions_runner_registration: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub scim: ::std::option::Option<crate::v1_1_4::schema::RateLimit<'a>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
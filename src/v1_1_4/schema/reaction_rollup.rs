@@ -0,0 +1,29 @@
+/// Reaction Rollup
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ReactionRollup<'a> {
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub total_count: i64,
+
+    #[serde(rename = "+1")]
+    pub plus_1: i64,
+
+    #[serde(rename = "-1")]
+    pub minus_1: i64,
+
+    pub laugh: i64,
+
+    pub confused: i64,
+
+    pub heart: i64,
+
+    pub hooray: i64,
+
+    pub eyes: i64,
+
+    pub rocket: i64,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
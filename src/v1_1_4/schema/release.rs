@@ -0,0 +1,90 @@
+/// Release
+/// 
+/// A release.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Release<'a> {
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    pub assets_url: ::std::borrow::Cow<'a, str>,
+
+    pub upload_url: ::std::borrow::Cow<'a, str>,
+
+    pub tarball_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub zipball_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub id: i64,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The name of the tag.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "v1.0.0"
+    /// ```
+    pub tag_name: ::std::borrow::Cow<'a, str>,
+
+    /// Specifies the commitish value that determines where the Git tag is created from.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "master"
+    /// ```
+    pub target_commitish: ::std::borrow::Cow<'a, str>,
+
+    pub name: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub body: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// true to create a draft (unpublished) release, false to create a published one.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// false
+    /// ```
+    pub draft: bool,
+
+    /// Whether to identify the release as a prerelease or a full release.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// false
+    /// ```
+    pub prerelease: bool,
+
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    pub published_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub author: crate::v1_1_4::schema::SimpleUser<'a>,
+
+    pub assets: ::std::borrow::Cow<'a, [crate::v1_1_4::schema::ReleaseAsset<'a>]>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_html: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub body_text: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub mentions_count: ::std::option::Option<i64>,
+
+    /// The URL of the release discussion.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub discussion_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reactions: ::std::option::Option<crate::v1_1_4::schema::ReactionRollup<'a>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,43 @@
+/// Release Asset
+/// 
+/// Data related to a release.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ReleaseAsset<'a> {
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub browser_download_url: ::std::borrow::Cow<'a, str>,
+
+    pub id: i64,
+
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The file name of the asset.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Team Environment"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    pub label: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// State of the release asset.
+    pub state: ::std::borrow::Cow<'a, str>,
+
+    pub content_type: ::std::borrow::Cow<'a, str>,
+
+    pub size: i64,
+
+    pub download_count: i64,
+
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    pub uploader: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,432 @@
+/// Repository
+/// 
+/// A git repository
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct Repository<'a> {
+    /// Unique identifier of the repository
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 42
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDEwOlJlcG9zaXRvcnkxMjk2MjY5"
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The name of the repository.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Team Environment"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "octocat/Hello-World"
+    /// ```
+    pub full_name: ::std::borrow::Cow<'a, str>,
+
+    pub license: ::std::option::Option<crate::v1_1_4::schema::NullableLicenseSimple<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub organization: ::std::option::Option<::std::option::Option<crate::v1_1_4::schema::NullableSimpleUser<'a>>>,
+
+    pub forks: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub permissions: ::std::option::Option<crate::v1_1_4::schema::repository::Permissions<'a>>,
+
+    pub owner: crate::v1_1_4::schema::SimpleUser<'a>,
+
+    /// Whether the repository is private or public.
+    pub private: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat/Hello-World"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "This your first repo!"
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub fork: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/octocat/Hello-World"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub archive_url: ::std::borrow::Cow<'a, str>,
+
+    pub assignees_url: ::std::borrow::Cow<'a, str>,
+
+    pub blobs_url: ::std::borrow::Cow<'a, str>,
+
+    pub branches_url: ::std::borrow::Cow<'a, str>,
+
+    pub collaborators_url: ::std::borrow::Cow<'a, str>,
+
+    pub comments_url: ::std::borrow::Cow<'a, str>,
+
+    pub commits_url: ::std::borrow::Cow<'a, str>,
+
+    pub compare_url: ::std::borrow::Cow<'a, str>,
+
+    pub contents_url: ::std::borrow::Cow<'a, str>,
+
+    pub contributors_url: ::std::borrow::Cow<'a, str>,
+
+    pub deployments_url: ::std::borrow::Cow<'a, str>,
+
+    pub downloads_url: ::std::borrow::Cow<'a, str>,
+
+    pub events_url: ::std::borrow::Cow<'a, str>,
+
+    pub forks_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_commits_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_refs_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_tags_url: ::std::borrow::Cow<'a, str>,
+
+    pub git_url: ::std::borrow::Cow<'a, str>,
+
+    pub issue_comment_url: ::std::borrow::Cow<'a, str>,
+
+    pub issue_events_url: ::std::borrow::Cow<'a, str>,
+
+    pub issues_url: ::std::borrow::Cow<'a, str>,
+
+    pub keys_url: ::std::borrow::Cow<'a, str>,
+
+    pub labels_url: ::std::borrow::Cow<'a, str>,
+
+    pub languages_url: ::std::borrow::Cow<'a, str>,
+
+    pub merges_url: ::std::borrow::Cow<'a, str>,
+
+    pub milestones_url: ::std::borrow::Cow<'a, str>,
+
+    pub notifications_url: ::std::borrow::Cow<'a, str>,
+
+    pub pulls_url: ::std::borrow::Cow<'a, str>,
+
+    pub releases_url: ::std::borrow::Cow<'a, str>,
+
+    pub ssh_url: ::std::borrow::Cow<'a, str>,
+
+    pub stargazers_url: ::std::borrow::Cow<'a, str>,
+
+    pub statuses_url: ::std::borrow::Cow<'a, str>,
+
+    pub subscribers_url: ::std::borrow::Cow<'a, str>,
+
+    pub subscription_url: ::std::borrow::Cow<'a, str>,
+
+    pub tags_url: ::std::borrow::Cow<'a, str>,
+
+    pub teams_url: ::std::borrow::Cow<'a, str>,
+
+    pub trees_url: ::std::borrow::Cow<'a, str>,
+
+    pub clone_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "git:git.example.com/octocat/Hello-World"
+    /// ```
+    pub mirror_url: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "http://api.github.com/repos/octocat/Hello-World/hooks"
+    /// ```
+    pub hooks_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://svn.github.com/octocat/Hello-World"
+    /// ```
+    pub svn_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com"
+    /// ```
+    pub homepage: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    pub language: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 9
+    /// ```
+    pub forks_count: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 80
+    /// ```
+    pub stargazers_count: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 80
+    /// ```
+    pub watchers_count: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 108
+    /// ```
+    pub size: i64,
+
+    /// The default branch of the repository.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "master"
+    /// ```
+    pub default_branch: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 0
+    /// ```
+    pub open_issues_count: i64,
+
+    /// Whether this repository acts as a template that can be used to generate new repositories.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub is_template: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub topics: ::std::option::Option<::std::borrow::Cow<'a, [::std::borrow::Cow<'a, str>]>>,
+
+    /// Whether issues are enabled.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub has_issues: bool,
+
+    /// Whether projects are enabled.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub has_projects: bool,
+
+    /// Whether the wiki is enabled.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub has_wiki: bool,
+
+    pub has_pages: bool,
+
+    /// Whether downloads are enabled.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    pub has_downloads: bool,
+
+    /// Whether the repository is archived.
+    pub archived: bool,
+
+    /// Returns whether or not this repository disabled.
+    pub disabled: bool,
+
+    /// The repository visibility: public, private, or internal.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub visibility: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:06:43Z"
+    /// ```
+    pub pushed_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:01:12Z"
+    /// ```
+    pub created_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2011-01-26T19:14:43Z"
+    /// ```
+    pub updated_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// Whether to allow rebase merges for pull requests.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_rebase_merge: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub template_repository: ::std::option::Option<::std::option::Option<::serde_json::value::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub temp_clone_token: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// Whether to allow squash merges for pull requests.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_squash_merge: ::std::option::Option<bool>,
+
+    /// Whether to allow Auto-merge to be used on pull requests.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// false
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_auto_merge: ::std::option::Option<bool>,
+
+    /// Whether to delete head branches when pull requests are merged
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// false
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub delete_branch_on_merge: ::std::option::Option<bool>,
+
+    /// Whether to allow merge commits for pull requests.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// true
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_merge_commit: ::std::option::Option<bool>,
+
+    /// Whether to allow forking this repo
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allow_forking: ::std::option::Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub subscribers_count: ::std::option::Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub network_count: ::std::option::Option<i64>,
+
+    pub open_issues: i64,
+
+    pub watchers: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub master_branch: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "\"2020-07-09T00:17:42Z\""
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub starred_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`Repository`]
+pub mod repository {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Permissions<'a> {
+        pub admin: bool,
+
+        pub pull: bool,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub triage: ::std::option::Option<bool>,
+
+        pub push: bool,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub maintain: ::std::option::Option<bool>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
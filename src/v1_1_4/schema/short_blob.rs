@@ -0,0 +1,13 @@
+/// Short Blob
+/// 
+/// Short Blob
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ShortBlob<'a> {
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    pub sha: ::std::borrow::Cow<'a, str>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,46 @@
+/// Simple Commit
+/// 
+/// Simple Commit
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct SimpleCommit<'a> {
+    pub id: ::std::borrow::Cow<'a, str>,
+
+    pub tree_id: ::std::borrow::Cow<'a, str>,
+
+    pub message: ::std::borrow::Cow<'a, str>,
+
+    pub timestamp: ::std::borrow::Cow<'a, str>,
+
+    pub author: ::std::option::Option<crate::v1_1_4::schema::simple_commit::Author<'a>>,
+
+    pub committer: ::std::option::Option<crate::v1_1_4::schema::simple_commit::Committer<'a>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`SimpleCommit`]
+pub mod simple_commit {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Author<'a> {
+        pub name: ::std::borrow::Cow<'a, str>,
+
+        pub email: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Committer<'a> {
+        pub name: ::std::borrow::Cow<'a, str>,
+
+        pub email: ::std::borrow::Cow<'a, str>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
@@ -0,0 +1,162 @@
+/// Simple User
+/// 
+/// Simple User
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct SimpleUser<'a> {
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub name: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub email: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "octocat"
+    /// ```
+    pub login: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDQ6VXNlcjE="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/images/error/octocat_happy.gif"
+    /// ```
+    pub avatar_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "41d064eb2195891e12d0413f63227ea7"
+    /// ```
+    pub gravatar_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/octocat"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/followers"
+    /// ```
+    pub followers_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/following{/other_user}"
+    /// ```
+    pub following_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/gists{/gist_id}"
+    /// ```
+    pub gists_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/starred{/owner}{/repo}"
+    /// ```
+    pub starred_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/subscriptions"
+    /// ```
+    pub subscriptions_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/orgs"
+    /// ```
+    pub organizations_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/repos"
+    /// ```
+    pub repos_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/events{/privacy}"
+    /// ```
+    pub events_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/users/octocat/received_events"
+    /// ```
+    pub received_events_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "User"
+    /// ```
+    pub r#type: ::std::borrow::Cow<'a, str>,
+
+    pub site_admin: bool,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "2020-07-09T00:17:55Z"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub starred_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
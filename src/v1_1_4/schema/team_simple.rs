@@ -0,0 +1,114 @@
+/// Team Simple
+/// 
+/// Groups of organization members that gives permissions on specified repositories.
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct TeamSimple<'a> {
+    /// Unique identifier of the team
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    pub id: i64,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDQ6VGVhbTE="
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// URL for the team
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/organizations/1/team/1"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/organizations/1/team/1/members{/member}"
+    /// ```
+    pub members_url: ::std::borrow::Cow<'a, str>,
+
+    /// Name of the team
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Justice League"
+    /// ```
+    pub name: ::std::borrow::Cow<'a, str>,
+
+    /// Description of the team
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "A great team."
+    /// ```
+    pub description: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// Permission that the team will have for its repositories
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "admin"
+    /// ```
+    pub permission: ::std::borrow::Cow<'a, str>,
+
+    /// The level of privacy this team should have
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "closed"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub privacy: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/orgs/rails/teams/core"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/organizations/1/team/1/repos"
+    /// ```
+    pub repositories_url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "justice-league"
+    /// ```
+    pub slug: ::std::borrow::Cow<'a, str>,
+
+    /// Distinguished Name (DN) that team maps to within LDAP environment
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "uid=example,ou=users,dc=github,dc=com"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ldap_dn: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
@@ -0,0 +1,43 @@
+/// Validation Error
+/// 
+/// Validation Error
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct ValidationError<'a> {
+    pub message: ::std::borrow::Cow<'a, str>,
+
+    pub documentation_url: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub errors: ::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::validation_error::Errors<'a>]>>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
+
+/// Types for fields in [`ValidationError`]
+pub mod validation_error {
+    #[allow(non_snake_case)]
+    #[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Errors<'a> {
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub resource: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub field: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub message: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+        pub code: ::std::borrow::Cow<'a, str>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub index: ::std::option::Option<i64>,
+
+        #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+        pub value: ::std::option::Option<::std::option::Option<::serde_json::value::Value>>,
+
+        #[serde(flatten)]
+        pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+    }
+}
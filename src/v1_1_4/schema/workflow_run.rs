@@ -0,0 +1,246 @@
+/// Workflow Run
+/// 
+/// An invocation of a workflow
+#[allow(non_snake_case)]
+#[derive(Clone, Eq, PartialEq, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+pub struct WorkflowRun<'a> {
+    /// The ID of the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    pub id: i64,
+
+    /// The name of the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "Build"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub name: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDEwOkNoZWNrU3VpdGU1"
+    /// ```
+    pub node_id: ::std::borrow::Cow<'a, str>,
+
+    /// The ID of the associated check suite.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 42
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub check_suite_id: ::std::option::Option<i64>,
+
+    /// The node ID of the associated check suite.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "MDEwOkNoZWNrU3VpdGU0Mg=="
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub check_suite_node_id: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "master"
+    /// ```
+    pub head_branch: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The SHA of the head commit that points to the version of the workflow being run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d"
+    /// ```
+    pub head_sha: ::std::borrow::Cow<'a, str>,
+
+    /// The auto incrementing run number for the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 106
+    /// ```
+    pub run_number: i64,
+
+    /// Attempt number of the run, 1 for first attempt and higher if the workflow was re-run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 1
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub run_attempt: ::std::option::Option<i64>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "push"
+    /// ```
+    pub event: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "completed"
+    /// ```
+    pub status: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "neutral"
+    /// ```
+    pub conclusion: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The ID of the parent workflow.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    pub workflow_id: i64,
+
+    /// The URL to the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5"
+    /// ```
+    pub url: ::std::borrow::Cow<'a, str>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://github.com/github/hello-world/suites/4"
+    /// ```
+    pub html_url: ::std::borrow::Cow<'a, str>,
+
+    pub pull_requests: ::std::option::Option<::std::borrow::Cow<'a, [crate::v1_1_4::schema::PullRequestMinimal<'a>]>>,
+
+    pub created_at: ::std::borrow::Cow<'a, str>,
+
+    pub updated_at: ::std::borrow::Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub actor: ::std::option::Option<crate::v1_1_4::schema::SimpleUser<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub triggering_actor: ::std::option::Option<crate::v1_1_4::schema::SimpleUser<'a>>,
+
+    /// The start time of the latest run. Resets on re-run.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub run_started_at: ::std::option::Option<::std::borrow::Cow<'a, str>>,
+
+    /// The URL to the jobs for the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/jobs"
+    /// ```
+    pub jobs_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to download the logs for the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/logs"
+    /// ```
+    pub logs_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to the associated check suite.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/check-suites/12"
+    /// ```
+    pub check_suite_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to the artifacts for the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun/artifacts"
+    /// ```
+    pub artifacts_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to cancel the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/cancel"
+    /// ```
+    pub cancel_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to rerun the workflow run.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun"
+    /// ```
+    pub rerun_url: ::std::borrow::Cow<'a, str>,
+
+    /// The URL to the previous attempted run of this workflow, if one exists.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/runs/5/attempts/3"
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default, deserialize_with = "crate::v1_1_4::support::deserialize_some")]
+    pub previous_attempt_url: ::std::option::Option<::std::option::Option<::std::borrow::Cow<'a, str>>>,
+
+    /// The URL to the workflow.
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// "https://api.github.com/repos/github/hello-world/actions/workflows/main.yaml"
+    /// ```
+    pub workflow_url: ::std::borrow::Cow<'a, str>,
+
+    pub head_commit: ::std::option::Option<crate::v1_1_4::schema::NullableSimpleCommit<'a>>,
+
+    pub repository: crate::v1_1_4::schema::MinimalRepository<'a>,
+
+    pub head_repository: crate::v1_1_4::schema::MinimalRepository<'a>,
+
+    /// 
+    /// # Example
+    /// 
+    /// ```json
+    /// 5
+    /// ```
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub head_repository_id: ::std::option::Option<i64>,
+
+    #[serde(flatten)]
+    pub additionalProperties: ::std::collections::HashMap<::std::borrow::Cow<'a, str>, ::serde_json::value::Value>
+}
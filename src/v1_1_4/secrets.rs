@@ -0,0 +1,95 @@
+//! Encryption of Actions, Dependabot and Codespaces secrets.
+//!
+//! GitHub expects the value of a secret to be sealed, as by libsodium's `crypto_box_seal`,
+//! using the public key of the repository, organization or environment receiving the
+//! secret. [`Caller::set_secret`](crate::v1_1_4::caller::Caller::set_secret) fetches the
+//! public key, seals the value and creates or updates the secret:
+//!
+//! ```ignore
+//! let scope = SecretScope::Organization { org: "octo-org", visibility: Visibility::Selected(&[1296269]) };
+//! github.set_secret(&scope, "DEPLOY_TOKEN", token.as_bytes()).await?;
+//! ```
+
+use ::base64::engine::general_purpose::STANDARD;
+use ::base64::Engine;
+
+use crate::v1_1_4::ApiError;
+
+/// The owner of a secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecretScope<'a> {
+    /// Actions secret of a repository.
+    Repository { owner: &'a str, repo: &'a str },
+    /// Actions secret of an organization, available to the repositories given by
+    /// `visibility`.
+    Organization { org: &'a str, visibility: Visibility<'a> },
+    /// Actions secret of a deployment environment.
+    Environment { repository_id: i64, environment_name: &'a str },
+    /// Dependabot secret of a repository.
+    DependabotRepository { owner: &'a str, repo: &'a str },
+    /// Codespaces secret of a repository.
+    CodespacesRepository { owner: &'a str, repo: &'a str },
+}
+
+impl<'a> SecretScope<'a> {
+    /// Key identifying the public key of the scope.
+    pub(crate) fn cache_key(&self) -> String {
+        match self {
+            SecretScope::Repository { owner, repo } => format!("actions/repos/{}/{}", owner, repo),
+            SecretScope::Organization { org, .. } => format!("actions/orgs/{}", org),
+            SecretScope::Environment { repository_id, environment_name } => {
+                format!("actions/repositories/{}/environments/{}", repository_id, environment_name)
+            }
+            SecretScope::DependabotRepository { owner, repo } => format!("dependabot/repos/{}/{}", owner, repo),
+            SecretScope::CodespacesRepository { owner, repo } => format!("codespaces/repos/{}/{}", owner, repo),
+        }
+    }
+}
+
+/// Repositories that can access an organization secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Visibility<'a> {
+    /// All repositories of the organization.
+    All,
+    /// Private repositories of the organization.
+    Private,
+    /// The repositories with the given ids.
+    Selected(&'a [i64]),
+}
+
+impl<'a> Visibility<'a> {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::All => "all",
+            Visibility::Private => "private",
+            Visibility::Selected(_) => "selected",
+        }
+    }
+}
+
+/// Public key used to encrypt the secrets of a scope.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize)]
+pub struct PublicKey {
+    pub key_id: String,
+    /// Base64 encoded Curve25519 public key.
+    pub key: String,
+}
+
+impl PublicKey {
+    /// Seal `plaintext` using the key, returning the base64 encoded value of a secret.
+    pub fn seal(&self, plaintext: &[u8]) -> Result<String, ApiError> {
+        seal(&self.key, plaintext)
+    }
+}
+
+/// Seal `plaintext` for the owner of the base64 encoded Curve25519 `public_key`, as by
+/// libsodium's `crypto_box_seal`, returning the base64 encoded ciphertext.
+pub fn seal(public_key: &str, plaintext: &[u8]) -> Result<String, ApiError> {
+    let invalid = || ApiError::Other("Invalid secret public key".to_owned());
+    let public_key = STANDARD.decode(public_key).map_err(|_| invalid())?;
+    let public_key = ::crypto_box::PublicKey::from_slice(&public_key).map_err(|_| invalid())?;
+    let sealed = public_key
+        .seal(&mut ::crypto_box::aead::OsRng, plaintext)
+        .map_err(|_| ApiError::Other("Failed to encrypt secret".to_owned()))?;
+    Ok(STANDARD.encode(sealed))
+}
@@ -9,4 +9,34 @@ where
     D: Deserializer<'de>,
 {
     Deserialize::deserialize(deserializer).map(Some)
-}
\ No newline at end of file
+}
+
+/// Standard body of a GitHub error response.
+#[derive(Default, Deserialize)]
+pub(crate) struct ErrorBody {
+    pub message: Option<String>,
+    pub documentation_url: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<super::ValidationError>,
+}
+
+/// Rebuild a response whose body has been read.
+#[cfg(feature = "reqwest")]
+pub(crate) fn reqwest_response<Body>(
+    status: ::http::StatusCode,
+    version: ::http::Version,
+    headers: ::http::HeaderMap,
+    url: ::url::Url,
+    body: Body,
+) -> Result<::http::Response<Body>, crate::v1_1_4::ApiError> {
+    use ::reqwest::ResponseBuilderExt;
+
+    let mut builder = ::http::Response::builder()
+        .status(status)
+        .version(version)
+        .url(url);
+    if let Some(builder_headers) = builder.headers_mut() {
+        *builder_headers = headers;
+    }
+    Ok(builder.body(body)?)
+}
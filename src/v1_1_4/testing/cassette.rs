@@ -0,0 +1,432 @@
+//! Recording and replaying HTTP interactions.
+//!
+//! A [`Cassette`] is a [`Transport`] wrapping the client of a `Caller`, e.g. a `hyper` or
+//! `reqwest` client. In record mode, it forwards each request to the client, and saves the
+//! request and response to a JSON file when the last clone of the cassette is dropped. In
+//! replay mode, the same requests are answered from the file, without using the client.
+//!
+//! ```ignore
+//! let cassette = if record {
+//!     Cassette::record("tests/cassettes/issues.json", hyper::Client::new())
+//! } else {
+//!     Cassette::replay("tests/cassettes/issues.json", hyper::Client::new())?
+//! };
+//! let github = Caller::new(cassette.clone(), config, tokio::time::sleep);
+//! ```
+//!
+//! Requests are matched on method, path, query and body, and each recorded interaction is
+//! replayed once, in the order recorded. An unmatched request in replay mode returns
+//! [`ApiError::Other`].
+//!
+//! The `Authorization`, `Cookie` and `Set-Cookie` headers are not recorded. In other headers,
+//! paths, queries and bodies, the following are replaced with `REDACTED`:
+//!
+//! - credentials sent in the `Authorization` header of a recorded request,
+//! - GitHub tokens, such as `ghp_…` or `ghs_…`, and JSON web tokens (`eyJ…`),
+//! - 40-digit hexadecimal tokens following `token` or `Bearer`,
+//! - the values of JSON fields and query parameters named in [`SECRET_FIELDS`], such as
+//!   the `token` of a new installation access token.
+//!
+//! Other 40-digit hexadecimal values are kept, since they cannot be told apart from commit
+//! SHAs, which requests must match on.
+
+use ::std::collections::BTreeSet;
+use ::std::future::ready;
+use ::std::path::{Path, PathBuf};
+use ::std::sync::{Arc, Mutex};
+
+use ::futures_util::future::BoxFuture;
+use ::http::header::{HeaderMap, HeaderName};
+
+use crate::v1_1_4::transport::{RequestUri, Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+#[derive(Clone, Debug, Default, ::serde::Serialize, ::serde::Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Clone, Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Clone, Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+    body: RecordedBody,
+}
+
+#[derive(Clone, Debug, ::serde::Serialize, ::serde::Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: RecordedBody,
+}
+
+/// A body, as text if possible.
+#[derive(Clone, Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+    Text(String),
+    Hex(String),
+}
+
+impl RecordedBody {
+    fn bytes(&self) -> Vec<u8> {
+        match self {
+            RecordedBody::Text(text) => text.clone().into_bytes(),
+            RecordedBody::Hex(hex) => ::hex::decode(hex).unwrap_or_default(),
+        }
+    }
+
+    /// Compare bodies, ignoring the formatting of JSON.
+    fn matches(&self, other: &RecordedBody) -> bool {
+        match (self, other) {
+            (RecordedBody::Text(this), RecordedBody::Text(other)) => {
+                match (
+                    ::serde_json::from_str::<::serde_json::Value>(this),
+                    ::serde_json::from_str::<::serde_json::Value>(other),
+                ) {
+                    (Ok(this), Ok(other)) => this == other,
+                    _ => this == other,
+                }
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// Headers that are never recorded. The length of a body changes when it is scrubbed, so
+/// the headers describing it are not recorded either.
+const UNRECORDED_HEADERS: [HeaderName; 7] = [
+    ::http::header::AUTHORIZATION,
+    ::http::header::PROXY_AUTHORIZATION,
+    ::http::header::COOKIE,
+    ::http::header::SET_COOKIE,
+    ::http::header::CONTENT_LENGTH,
+    ::http::header::TRANSFER_ENCODING,
+    ::http::header::CONNECTION,
+];
+
+/// Names of JSON fields and query parameters whose values are never recorded.
+pub const SECRET_FIELDS: [&str; 4] = ["token", "access_token", "refresh_token", "client_secret"];
+
+/// Prefixes of GitHub tokens, e.g. `ghs_` for an installation access token.
+const TOKEN_PREFIXES: [&str; 6] = ["ghp_", "gho_", "ghu_", "ghs_", "ghr_", "github_pat_"];
+
+/// Credentials shorter than this are not searched for, e.g. an empty `Bearer`.
+const MIN_SECRET_LEN: usize = 8;
+
+/// Replaces secrets with `REDACTED`.
+#[derive(Clone, Debug, Default)]
+struct Scrubber {
+    /// Credentials sent in `Authorization` headers, which may be echoed elsewhere.
+    secrets: Vec<String>,
+}
+
+impl Scrubber {
+    fn text(&self, text: &str) -> String {
+        let text = self
+            .secrets
+            .iter()
+            .fold(text.to_owned(), |text, secret| text.replace(secret.as_str(), "REDACTED"));
+        scrub(&text)
+    }
+
+    fn query(&self, query: &str) -> String {
+        query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if SECRET_FIELDS.contains(&name) => format!("{}=REDACTED", name),
+                _ => self.text(pair),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn json(&self, value: &mut ::serde_json::Value) {
+        match value {
+            ::serde_json::Value::String(text) => *text = self.text(text),
+            ::serde_json::Value::Array(items) => items.iter_mut().for_each(|item| self.json(item)),
+            ::serde_json::Value::Object(fields) => {
+                for (name, value) in fields.iter_mut() {
+                    if SECRET_FIELDS.contains(&name.as_str()) && value.is_string() {
+                        *value = ::serde_json::Value::String("REDACTED".to_owned());
+                    } else {
+                        self.json(value);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn body(&self, body: &[u8]) -> RecordedBody {
+        if let Ok(mut value) = ::serde_json::from_slice::<::serde_json::Value>(body) {
+            self.json(&mut value);
+            return RecordedBody::Text(value.to_string());
+        }
+        match ::std::str::from_utf8(body) {
+            Ok(text) => RecordedBody::Text(self.text(text)),
+            Err(_) => RecordedBody::Hex(::hex::encode(body)),
+        }
+    }
+
+    fn headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .filter(|(name, _)| !UNRECORDED_HEADERS.contains(name))
+            .map(|(name, value)| (name.as_str().to_owned(), self.text(&String::from_utf8_lossy(value.as_bytes()))))
+            .collect()
+    }
+
+    fn request(&self, request: &::http::Request<Vec<u8>>) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method().to_string(),
+            path: self.text(request.uri().path()),
+            query: request.uri().query().map(|query| self.query(query)),
+            headers: self.headers(request.headers()),
+            body: self.body(request.body()),
+        }
+    }
+
+    fn response(&self, response: &::http::Response<Vec<u8>>) -> RecordedResponse {
+        RecordedResponse {
+            status: response.status().as_u16(),
+            headers: self.headers(response.headers()),
+            body: self.body(response.body()),
+        }
+    }
+}
+
+/// Replace GitHub tokens, JSON web tokens, and hexadecimal tokens following `token` or
+/// `Bearer`, in `text` with `REDACTED`.
+fn scrub(text: &str) -> String {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.');
+    let mut scrubbed = String::with_capacity(text.len());
+    let mut previous = "";
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest.find(is_word).unwrap_or(rest.len());
+        scrubbed.push_str(&rest[..start]);
+        let end = rest[start..].find(|c: char| !is_word(c)).map_or(rest.len(), |end| start + end);
+        let word = &rest[start..end];
+        scrub_word(&mut scrubbed, word, previous);
+        previous = word;
+        rest = &rest[end..];
+    }
+    scrubbed
+}
+
+fn scrub_word(scrubbed: &mut String, word: &str, previous: &str) {
+    // A trailing full stop ends a sentence rather than a token.
+    let token = word.trim_end_matches('.');
+    let is_base64url = |part: &str| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    };
+    let parts = token.split('.').collect::<Vec<_>>();
+    if token.starts_with("eyJ") && parts.len() == 3 && is_base64url(parts[0]) && is_base64url(parts[1]) {
+        scrubbed.push_str("REDACTED");
+        scrubbed.push_str(&word[token.len()..]);
+    } else if let Some(prefix) = TOKEN_PREFIXES.iter().find(|prefix| word.starts_with(*prefix)) {
+        let end = word[prefix.len()..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(word.len(), |end| prefix.len() + end);
+        scrubbed.push_str("REDACTED");
+        scrubbed.push_str(&word[end..]);
+    } else if (previous.eq_ignore_ascii_case("token") || previous.eq_ignore_ascii_case("bearer"))
+        && token.len() == 40
+        && token.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        scrubbed.push_str("REDACTED");
+        scrubbed.push_str(&word[token.len()..]);
+    } else {
+        scrubbed.push_str(word);
+    }
+}
+
+struct Tape {
+    file: CassetteFile,
+    /// Has each interaction been replayed?
+    played: Vec<bool>,
+}
+
+struct CassetteState {
+    path: PathBuf,
+    recording: bool,
+    tape: Mutex<Tape>,
+    /// Credentials sent in `Authorization` headers so far.
+    secrets: Mutex<BTreeSet<String>>,
+}
+
+impl CassetteState {
+    /// Scrubber for a request, learning the credentials in its `Authorization` header.
+    fn scrubber(&self, request: &::http::Request<Vec<u8>>) -> Scrubber {
+        let mut secrets = match self.secrets.lock() {
+            Ok(secrets) => secrets,
+            Err(_) => return Scrubber::default(),
+        };
+        let credentials = request
+            .headers()
+            .get_all(::http::header::AUTHORIZATION)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .map(|value| value.split_once(' ').map_or(value, |(_, credentials)| credentials).trim())
+            .filter(|credentials| credentials.len() >= MIN_SECRET_LEN);
+        secrets.extend(credentials.map(str::to_owned));
+        Scrubber {
+            secrets: secrets.iter().cloned().collect(),
+        }
+    }
+
+    fn record(&self, request: RecordedRequest, response: RecordedResponse) {
+        if let Ok(mut tape) = self.tape.lock() {
+            tape.file.interactions.push(Interaction { request, response });
+            tape.played.push(true);
+        }
+    }
+
+    fn replay(&self, request: &RecordedRequest) -> Result<::http::Response<Vec<u8>>, ApiError> {
+        let mut tape = self
+            .tape
+            .lock()
+            .map_err(|_| ApiError::Other("Cassette lock poisoned".to_owned()))?;
+        let Tape { file, played } = &mut *tape;
+        let index = file
+            .interactions
+            .iter()
+            .zip(played.iter())
+            .position(|(interaction, played)| {
+                !played
+                    && interaction.request.method == request.method
+                    && interaction.request.path == request.path
+                    && interaction.request.query == request.query
+                    && interaction.request.body.matches(&request.body)
+            })
+            .ok_or_else(|| {
+                ApiError::Other(format!(
+                    "No recorded interaction for {} {}{}",
+                    request.method,
+                    request.path,
+                    request.query.as_ref().map(|query| format!("?{}", query)).unwrap_or_default()
+                ))
+            })?;
+        played[index] = true;
+        let recorded = &file.interactions[index].response;
+        let mut builder = ::http::Response::builder().status(recorded.status);
+        for (name, value) in &recorded.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        Ok(builder.body(recorded.body.bytes())?)
+    }
+
+    fn save(&self) -> Result<(), ApiError> {
+        if !self.recording {
+            return Ok(());
+        }
+        let json = match self.tape.lock() {
+            Ok(tape) => ::serde_json::to_vec_pretty(&tape.file)?,
+            Err(_) => return Err(ApiError::Other("Cassette lock poisoned".to_owned())),
+        };
+        if let Some(parent) = self.path.parent() {
+            ::std::fs::create_dir_all(parent)?;
+        }
+        ::std::fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+impl Drop for CassetteState {
+    fn drop(&mut self) {
+        if let Err(err) = self.save() {
+            ::log::warn!("Failed to save cassette {}: {}", self.path.display(), err);
+        }
+    }
+}
+
+/// A [`Transport`] recording the interactions of the client `T` to a JSON file, or
+/// replaying them from the file.
+///
+/// Clones share the recorded interactions, which are saved when the last clone is dropped.
+#[derive(Clone)]
+pub struct Cassette<T> {
+    inner: T,
+    state: Arc<CassetteState>,
+}
+
+impl<T> Cassette<T> {
+    /// Record the interactions of `client`, replacing any existing file at `path` when the
+    /// cassette is saved or dropped.
+    pub fn record(path: impl AsRef<Path>, client: T) -> Self {
+        Self::new(path.as_ref(), client, true, CassetteFile::default())
+    }
+
+    /// Replay interactions recorded in the file at `path`. `client` is not used to send
+    /// requests, but determines the type of the responses.
+    pub fn replay(path: impl AsRef<Path>, client: T) -> Result<Self, ApiError> {
+        let file = ::serde_json::from_slice(&::std::fs::read(path.as_ref())?)?;
+        Ok(Self::new(path.as_ref(), client, false, file))
+    }
+
+    fn new(path: &Path, inner: T, recording: bool, file: CassetteFile) -> Self {
+        let played = vec![false; file.interactions.len()];
+        Cassette {
+            inner,
+            state: Arc::new(CassetteState {
+                path: path.to_owned(),
+                recording,
+                tape: Mutex::new(Tape { file, played }),
+                secrets: Mutex::new(BTreeSet::new()),
+            }),
+        }
+    }
+
+    /// Number of recorded interactions that have not been replayed.
+    pub fn remaining(&self) -> usize {
+        self.state
+            .tape
+            .lock()
+            .map(|tape| tape.played.iter().filter(|played| !**played).count())
+            .unwrap_or_default()
+    }
+
+    /// Write recorded interactions to the file. This does nothing in replay mode.
+    pub fn save(&self) -> Result<(), ApiError> {
+        self.state.save()
+    }
+}
+
+impl<T> Transport for Cassette<T>
+where
+    T: Transport,
+    T::Future: Send + 'static,
+    T::Response: Send + 'static,
+    <T::Response as TransportResponse>::Bytes: Send + 'static,
+{
+    type Response = T::Response;
+    type Future = BoxFuture<'static, Result<T::Response, ApiError>>;
+
+    fn send(&self, request: ::http::Request<Vec<u8>>) -> Self::Future {
+        let scrubber = self.state.scrubber(&request);
+        let recorded = scrubber.request(&request);
+        if !self.state.recording {
+            let uri = request.uri().clone();
+            return Box::pin(ready(self.state.replay(&recorded).and_then(|mut response| {
+                response.extensions_mut().insert(RequestUri(uri));
+                T::Response::from_bytes(response)
+            })));
+        }
+        let state = self.state.clone();
+        let sent = self.inner.send(request);
+        Box::pin(async move {
+            let response = sent.await?.into_bytes().await?;
+            state.record(recorded, scrubber.response(&response));
+            T::Response::from_bytes(response)
+        })
+    }
+}
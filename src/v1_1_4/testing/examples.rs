@@ -0,0 +1,1571 @@
+//! Example bodies of the response types used by the routes.
+//!
+//! Generated from the examples of the schemas in the same description as the
+//! [`request`](crate::v1_1_4::request) modules. Fields without an example hold a placeholder
+//! of the right type, and optional fields without an example are omitted.
+
+/// A type with an example value, as JSON.
+pub(super) trait Example {
+    const JSON: &'static str;
+}
+
+impl Example for ::std::string::String {
+    const JSON: &'static str = r##""Design for failure.""##;
+}
+
+impl Example for ::std::borrow::Cow<'_, str> {
+    const JSON: &'static str = r##""Actionscript""##;
+}
+
+impl Example for ::std::collections::HashMap<::std::borrow::Cow<'_, str>, ::std::borrow::Cow<'_, str>> {
+    const JSON: &'static str = r##"{
+    "+1": "https://github.githubassets.com/images/icons/emoji/unicode/1f44d.png?v8",
+    "-1": "https://github.githubassets.com/images/icons/emoji/unicode/1f44e.png?v8"
+}"##;
+}
+
+impl Example for ::serde_json::value::Value {
+    const JSON: &'static str = r##"{
+    "type": "file",
+    "encoding": "base64",
+    "size": 5362,
+    "name": "README.md",
+    "path": "README.md",
+    "content": "IyBZb2dhIEJvmsgaW4gcHJvZ3Jlc3MhIEZlZWwgZnJdHRvIGxvb2sgYXJvdW5kLg==",
+    "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+    "url": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
+    "git_url": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+    "html_url": "https://github.com/octokit/octokit.rb/blob/master/README.md",
+    "download_url": "https://raw.githubusercontent.com/octokit/octokit.rb/master/README.md",
+    "_links": {
+        "git": "https://api.github.com/repos/octokit/octokit.rb/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+        "self": "https://api.github.com/repos/octokit/octokit.rb/contents/README.md",
+        "html": "https://github.com/octokit/octokit.rb/blob/master/README.md"
+    }
+}"##;
+}
+
+impl Example for crate::v1_1_4::request::actions_list_artifacts_for_repo::response::Ok200<'_> {
+    const JSON: &'static str = r##"{
+    "total_count": 1,
+    "artifacts": [
+        {
+            "id": 5,
+            "node_id": "MDEwOkNoZWNrU3VpdGU1",
+            "name": "AdventureWorks.Framework",
+            "size_in_bytes": 12345,
+            "url": "https://api.github.com/repos/github/hello-world/actions/artifacts/5",
+            "archive_download_url": "https://api.github.com/repos/github/hello-world/actions/artifacts/5/zip",
+            "expired": false,
+            "created_at": null,
+            "expires_at": null,
+            "updated_at": null
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::request::actions_list_repo_workflows::response::Ok200<'_> {
+    const JSON: &'static str = r##"{
+    "total_count": 1,
+    "workflows": [
+        {
+            "id": 5,
+            "node_id": "MDg6V29ya2Zsb3cxMg==",
+            "name": "CI",
+            "path": "ruby.yaml",
+            "state": "active",
+            "created_at": "2019-12-06T14:20:20.000Z",
+            "updated_at": "2019-12-06T14:20:20.000Z",
+            "url": "https://api.github.com/repos/actions/setup-ruby/workflows/5",
+            "html_url": "https://github.com/actions/setup-ruby/blob/master/.github/workflows/ruby.yaml",
+            "badge_url": "https://github.com/actions/setup-ruby/workflows/CI/badge.svg",
+            "deleted_at": "2019-12-06T14:20:20.000Z"
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::request::actions_list_workflow_runs::response::Ok200<'_> {
+    const JSON: &'static str = r##"{
+    "total_count": 1,
+    "workflow_runs": [
+        {
+            "id": 5,
+            "name": "Build",
+            "node_id": "MDEwOkNoZWNrU3VpdGU1",
+            "check_suite_id": 42,
+            "check_suite_node_id": "MDEwOkNoZWNrU3VpdGU0Mg==",
+            "head_branch": "master",
+            "head_sha": "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d",
+            "run_number": 106,
+            "run_attempt": 1,
+            "event": "push",
+            "status": "completed",
+            "conclusion": "neutral",
+            "workflow_id": 5,
+            "url": "https://api.github.com/repos/github/hello-world/actions/runs/5",
+            "html_url": "https://github.com/github/hello-world/suites/4",
+            "pull_requests": null,
+            "created_at": "2011-01-26T19:01:12Z",
+            "updated_at": "2011-01-26T19:01:12Z",
+            "jobs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/jobs",
+            "logs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/logs",
+            "check_suite_url": "https://api.github.com/repos/github/hello-world/check-suites/12",
+            "artifacts_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun/artifacts",
+            "cancel_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/cancel",
+            "rerun_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun",
+            "previous_attempt_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/attempts/3",
+            "workflow_url": "https://api.github.com/repos/github/hello-world/actions/workflows/main.yaml",
+            "head_commit": null,
+            "repository": {
+                "id": 1296269,
+                "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+                "name": "Hello-World",
+                "full_name": "octocat/Hello-World",
+                "owner": {
+                    "login": "octocat",
+                    "id": 1,
+                    "node_id": "MDQ6VXNlcjE=",
+                    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                    "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+                    "url": "https://api.github.com/users/octocat",
+                    "html_url": "https://github.com/octocat",
+                    "followers_url": "https://api.github.com/users/octocat/followers",
+                    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                    "organizations_url": "https://api.github.com/users/octocat/orgs",
+                    "repos_url": "https://api.github.com/users/octocat/repos",
+                    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                    "received_events_url": "https://api.github.com/users/octocat/received_events",
+                    "type": "User",
+                    "site_admin": false,
+                    "starred_at": "2020-07-09T00:17:55Z"
+                },
+                "private": false,
+                "html_url": "https://github.com/octocat/Hello-World",
+                "description": "This your first repo!",
+                "fork": false,
+                "url": "https://api.github.com/repos/octocat/Hello-World",
+                "archive_url": "https://api.github.com/archive_url",
+                "assignees_url": "https://api.github.com/assignees_url",
+                "blobs_url": "https://api.github.com/blobs_url",
+                "branches_url": "https://api.github.com/branches_url",
+                "collaborators_url": "https://api.github.com/collaborators_url",
+                "comments_url": "https://api.github.com/comments_url",
+                "commits_url": "https://api.github.com/commits_url",
+                "compare_url": "https://api.github.com/compare_url",
+                "contents_url": "https://api.github.com/contents_url",
+                "contributors_url": "https://api.github.com/contributors_url",
+                "deployments_url": "https://api.github.com/deployments_url",
+                "downloads_url": "https://api.github.com/downloads_url",
+                "events_url": "https://api.github.com/events_url",
+                "forks_url": "https://api.github.com/forks_url",
+                "git_commits_url": "https://api.github.com/git_commits_url",
+                "git_refs_url": "https://api.github.com/git_refs_url",
+                "git_tags_url": "https://api.github.com/git_tags_url",
+                "issue_comment_url": "https://api.github.com/issue_comment_url",
+                "issue_events_url": "https://api.github.com/issue_events_url",
+                "issues_url": "https://api.github.com/issues_url",
+                "keys_url": "https://api.github.com/keys_url",
+                "labels_url": "https://api.github.com/labels_url",
+                "languages_url": "https://api.github.com/languages_url",
+                "merges_url": "https://api.github.com/merges_url",
+                "milestones_url": "https://api.github.com/milestones_url",
+                "notifications_url": "https://api.github.com/notifications_url",
+                "pulls_url": "https://api.github.com/pulls_url",
+                "releases_url": "https://api.github.com/releases_url",
+                "stargazers_url": "https://api.github.com/stargazers_url",
+                "statuses_url": "https://api.github.com/statuses_url",
+                "subscribers_url": "https://api.github.com/subscribers_url",
+                "subscription_url": "https://api.github.com/subscription_url",
+                "tags_url": "https://api.github.com/tags_url",
+                "teams_url": "https://api.github.com/teams_url",
+                "trees_url": "https://api.github.com/trees_url",
+                "hooks_url": "https://api.github.com/hooks_url",
+                "pushed_at": "2011-01-26T19:06:43Z",
+                "created_at": "2011-01-26T19:01:12Z",
+                "updated_at": "2011-01-26T19:14:43Z",
+                "role_name": "admin",
+                "forks": 0,
+                "open_issues": 0,
+                "watchers": 0
+            },
+            "head_repository": {
+                "id": 1296269,
+                "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+                "name": "Hello-World",
+                "full_name": "octocat/Hello-World",
+                "owner": {
+                    "login": "octocat",
+                    "id": 1,
+                    "node_id": "MDQ6VXNlcjE=",
+                    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                    "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+                    "url": "https://api.github.com/users/octocat",
+                    "html_url": "https://github.com/octocat",
+                    "followers_url": "https://api.github.com/users/octocat/followers",
+                    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                    "organizations_url": "https://api.github.com/users/octocat/orgs",
+                    "repos_url": "https://api.github.com/users/octocat/repos",
+                    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                    "received_events_url": "https://api.github.com/users/octocat/received_events",
+                    "type": "User",
+                    "site_admin": false,
+                    "starred_at": "2020-07-09T00:17:55Z"
+                },
+                "private": false,
+                "html_url": "https://github.com/octocat/Hello-World",
+                "description": "This your first repo!",
+                "fork": false,
+                "url": "https://api.github.com/repos/octocat/Hello-World",
+                "archive_url": "https://api.github.com/archive_url",
+                "assignees_url": "https://api.github.com/assignees_url",
+                "blobs_url": "https://api.github.com/blobs_url",
+                "branches_url": "https://api.github.com/branches_url",
+                "collaborators_url": "https://api.github.com/collaborators_url",
+                "comments_url": "https://api.github.com/comments_url",
+                "commits_url": "https://api.github.com/commits_url",
+                "compare_url": "https://api.github.com/compare_url",
+                "contents_url": "https://api.github.com/contents_url",
+                "contributors_url": "https://api.github.com/contributors_url",
+                "deployments_url": "https://api.github.com/deployments_url",
+                "downloads_url": "https://api.github.com/downloads_url",
+                "events_url": "https://api.github.com/events_url",
+                "forks_url": "https://api.github.com/forks_url",
+                "git_commits_url": "https://api.github.com/git_commits_url",
+                "git_refs_url": "https://api.github.com/git_refs_url",
+                "git_tags_url": "https://api.github.com/git_tags_url",
+                "issue_comment_url": "https://api.github.com/issue_comment_url",
+                "issue_events_url": "https://api.github.com/issue_events_url",
+                "issues_url": "https://api.github.com/issues_url",
+                "keys_url": "https://api.github.com/keys_url",
+                "labels_url": "https://api.github.com/labels_url",
+                "languages_url": "https://api.github.com/languages_url",
+                "merges_url": "https://api.github.com/merges_url",
+                "milestones_url": "https://api.github.com/milestones_url",
+                "notifications_url": "https://api.github.com/notifications_url",
+                "pulls_url": "https://api.github.com/pulls_url",
+                "releases_url": "https://api.github.com/releases_url",
+                "stargazers_url": "https://api.github.com/stargazers_url",
+                "statuses_url": "https://api.github.com/statuses_url",
+                "subscribers_url": "https://api.github.com/subscribers_url",
+                "subscription_url": "https://api.github.com/subscription_url",
+                "tags_url": "https://api.github.com/tags_url",
+                "teams_url": "https://api.github.com/teams_url",
+                "trees_url": "https://api.github.com/trees_url",
+                "hooks_url": "https://api.github.com/hooks_url",
+                "pushed_at": "2011-01-26T19:06:43Z",
+                "created_at": "2011-01-26T19:01:12Z",
+                "updated_at": "2011-01-26T19:14:43Z",
+                "role_name": "admin",
+                "forks": 0,
+                "open_issues": 0,
+                "watchers": 0
+            },
+            "head_repository_id": 5
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::request::actions_list_workflow_runs_for_repo::response::Ok200<'_> {
+    const JSON: &'static str = r##"{
+    "total_count": 1,
+    "workflow_runs": [
+        {
+            "id": 5,
+            "name": "Build",
+            "node_id": "MDEwOkNoZWNrU3VpdGU1",
+            "check_suite_id": 42,
+            "check_suite_node_id": "MDEwOkNoZWNrU3VpdGU0Mg==",
+            "head_branch": "master",
+            "head_sha": "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d",
+            "run_number": 106,
+            "run_attempt": 1,
+            "event": "push",
+            "status": "completed",
+            "conclusion": "neutral",
+            "workflow_id": 5,
+            "url": "https://api.github.com/repos/github/hello-world/actions/runs/5",
+            "html_url": "https://github.com/github/hello-world/suites/4",
+            "pull_requests": null,
+            "created_at": "2011-01-26T19:01:12Z",
+            "updated_at": "2011-01-26T19:01:12Z",
+            "jobs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/jobs",
+            "logs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/logs",
+            "check_suite_url": "https://api.github.com/repos/github/hello-world/check-suites/12",
+            "artifacts_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun/artifacts",
+            "cancel_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/cancel",
+            "rerun_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun",
+            "previous_attempt_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/attempts/3",
+            "workflow_url": "https://api.github.com/repos/github/hello-world/actions/workflows/main.yaml",
+            "head_commit": null,
+            "repository": {
+                "id": 1296269,
+                "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+                "name": "Hello-World",
+                "full_name": "octocat/Hello-World",
+                "owner": {
+                    "login": "octocat",
+                    "id": 1,
+                    "node_id": "MDQ6VXNlcjE=",
+                    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                    "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+                    "url": "https://api.github.com/users/octocat",
+                    "html_url": "https://github.com/octocat",
+                    "followers_url": "https://api.github.com/users/octocat/followers",
+                    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                    "organizations_url": "https://api.github.com/users/octocat/orgs",
+                    "repos_url": "https://api.github.com/users/octocat/repos",
+                    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                    "received_events_url": "https://api.github.com/users/octocat/received_events",
+                    "type": "User",
+                    "site_admin": false,
+                    "starred_at": "2020-07-09T00:17:55Z"
+                },
+                "private": false,
+                "html_url": "https://github.com/octocat/Hello-World",
+                "description": "This your first repo!",
+                "fork": false,
+                "url": "https://api.github.com/repos/octocat/Hello-World",
+                "archive_url": "https://api.github.com/archive_url",
+                "assignees_url": "https://api.github.com/assignees_url",
+                "blobs_url": "https://api.github.com/blobs_url",
+                "branches_url": "https://api.github.com/branches_url",
+                "collaborators_url": "https://api.github.com/collaborators_url",
+                "comments_url": "https://api.github.com/comments_url",
+                "commits_url": "https://api.github.com/commits_url",
+                "compare_url": "https://api.github.com/compare_url",
+                "contents_url": "https://api.github.com/contents_url",
+                "contributors_url": "https://api.github.com/contributors_url",
+                "deployments_url": "https://api.github.com/deployments_url",
+                "downloads_url": "https://api.github.com/downloads_url",
+                "events_url": "https://api.github.com/events_url",
+                "forks_url": "https://api.github.com/forks_url",
+                "git_commits_url": "https://api.github.com/git_commits_url",
+                "git_refs_url": "https://api.github.com/git_refs_url",
+                "git_tags_url": "https://api.github.com/git_tags_url",
+                "issue_comment_url": "https://api.github.com/issue_comment_url",
+                "issue_events_url": "https://api.github.com/issue_events_url",
+                "issues_url": "https://api.github.com/issues_url",
+                "keys_url": "https://api.github.com/keys_url",
+                "labels_url": "https://api.github.com/labels_url",
+                "languages_url": "https://api.github.com/languages_url",
+                "merges_url": "https://api.github.com/merges_url",
+                "milestones_url": "https://api.github.com/milestones_url",
+                "notifications_url": "https://api.github.com/notifications_url",
+                "pulls_url": "https://api.github.com/pulls_url",
+                "releases_url": "https://api.github.com/releases_url",
+                "stargazers_url": "https://api.github.com/stargazers_url",
+                "statuses_url": "https://api.github.com/statuses_url",
+                "subscribers_url": "https://api.github.com/subscribers_url",
+                "subscription_url": "https://api.github.com/subscription_url",
+                "tags_url": "https://api.github.com/tags_url",
+                "teams_url": "https://api.github.com/teams_url",
+                "trees_url": "https://api.github.com/trees_url",
+                "hooks_url": "https://api.github.com/hooks_url",
+                "pushed_at": "2011-01-26T19:06:43Z",
+                "created_at": "2011-01-26T19:01:12Z",
+                "updated_at": "2011-01-26T19:14:43Z",
+                "role_name": "admin",
+                "forks": 0,
+                "open_issues": 0,
+                "watchers": 0
+            },
+            "head_repository": {
+                "id": 1296269,
+                "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+                "name": "Hello-World",
+                "full_name": "octocat/Hello-World",
+                "owner": {
+                    "login": "octocat",
+                    "id": 1,
+                    "node_id": "MDQ6VXNlcjE=",
+                    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                    "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+                    "url": "https://api.github.com/users/octocat",
+                    "html_url": "https://github.com/octocat",
+                    "followers_url": "https://api.github.com/users/octocat/followers",
+                    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                    "organizations_url": "https://api.github.com/users/octocat/orgs",
+                    "repos_url": "https://api.github.com/users/octocat/repos",
+                    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                    "received_events_url": "https://api.github.com/users/octocat/received_events",
+                    "type": "User",
+                    "site_admin": false,
+                    "starred_at": "2020-07-09T00:17:55Z"
+                },
+                "private": false,
+                "html_url": "https://github.com/octocat/Hello-World",
+                "description": "This your first repo!",
+                "fork": false,
+                "url": "https://api.github.com/repos/octocat/Hello-World",
+                "archive_url": "https://api.github.com/archive_url",
+                "assignees_url": "https://api.github.com/assignees_url",
+                "blobs_url": "https://api.github.com/blobs_url",
+                "branches_url": "https://api.github.com/branches_url",
+                "collaborators_url": "https://api.github.com/collaborators_url",
+                "comments_url": "https://api.github.com/comments_url",
+                "commits_url": "https://api.github.com/commits_url",
+                "compare_url": "https://api.github.com/compare_url",
+                "contents_url": "https://api.github.com/contents_url",
+                "contributors_url": "https://api.github.com/contributors_url",
+                "deployments_url": "https://api.github.com/deployments_url",
+                "downloads_url": "https://api.github.com/downloads_url",
+                "events_url": "https://api.github.com/events_url",
+                "forks_url": "https://api.github.com/forks_url",
+                "git_commits_url": "https://api.github.com/git_commits_url",
+                "git_refs_url": "https://api.github.com/git_refs_url",
+                "git_tags_url": "https://api.github.com/git_tags_url",
+                "issue_comment_url": "https://api.github.com/issue_comment_url",
+                "issue_events_url": "https://api.github.com/issue_events_url",
+                "issues_url": "https://api.github.com/issues_url",
+                "keys_url": "https://api.github.com/keys_url",
+                "labels_url": "https://api.github.com/labels_url",
+                "languages_url": "https://api.github.com/languages_url",
+                "merges_url": "https://api.github.com/merges_url",
+                "milestones_url": "https://api.github.com/milestones_url",
+                "notifications_url": "https://api.github.com/notifications_url",
+                "pulls_url": "https://api.github.com/pulls_url",
+                "releases_url": "https://api.github.com/releases_url",
+                "stargazers_url": "https://api.github.com/stargazers_url",
+                "statuses_url": "https://api.github.com/statuses_url",
+                "subscribers_url": "https://api.github.com/subscribers_url",
+                "subscription_url": "https://api.github.com/subscription_url",
+                "tags_url": "https://api.github.com/tags_url",
+                "teams_url": "https://api.github.com/teams_url",
+                "trees_url": "https://api.github.com/trees_url",
+                "hooks_url": "https://api.github.com/hooks_url",
+                "pushed_at": "2011-01-26T19:06:43Z",
+                "created_at": "2011-01-26T19:01:12Z",
+                "updated_at": "2011-01-26T19:14:43Z",
+                "role_name": "admin",
+                "forks": 0,
+                "open_issues": 0,
+                "watchers": 0
+            },
+            "head_repository_id": 5
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::request::checks_list_for_ref::response::Ok200<'_> {
+    const JSON: &'static str = r##"{
+    "total_count": 1,
+    "check_runs": [
+        {
+            "id": 21,
+            "head_sha": "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d",
+            "node_id": "MDg6Q2hlY2tSdW40",
+            "external_id": "42",
+            "url": "https://api.github.com/repos/github/hello-world/check-runs/4",
+            "html_url": "https://github.com/github/hello-world/runs/4",
+            "details_url": "https://example.com",
+            "status": "queued",
+            "conclusion": "neutral",
+            "started_at": "2018-05-04T01:14:52Z",
+            "completed_at": "2018-05-04T01:14:52Z",
+            "output": {
+                "title": null,
+                "summary": null,
+                "text": null,
+                "annotations_count": 1,
+                "annotations_url": "https://api.github.com/annotations_url"
+            },
+            "name": "test-coverage",
+            "check_suite": null,
+            "app": null,
+            "pull_requests": [
+                {
+                    "id": 1,
+                    "number": 1,
+                    "url": "https://api.github.com/url",
+                    "head": {
+                        "ref": "ref",
+                        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                        "repo": {
+                            "id": 1,
+                            "url": "https://api.github.com/url",
+                            "name": "name"
+                        }
+                    },
+                    "base": {
+                        "ref": "ref",
+                        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                        "repo": {
+                            "id": 1,
+                            "url": "https://api.github.com/url",
+                            "name": "name"
+                        }
+                    }
+                }
+            ]
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::ActionsPublicKey<'_> {
+    const JSON: &'static str = r##"{
+    "key_id": "1234567",
+    "key": "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs=",
+    "id": 2,
+    "url": "https://api.github.com/user/keys/2",
+    "title": "ssh-rsa AAAAB3NzaC1yc2EAAA",
+    "created_at": "2011-01-26T19:01:12Z"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::ApiOverview<'_> {
+    const JSON: &'static str = r##"{
+    "verifiable_password_authentication": true,
+    "hooks": [
+        "127.0.0.1/32"
+    ],
+    "web": [
+        "127.0.0.1/32"
+    ],
+    "api": [
+        "127.0.0.1/32"
+    ],
+    "git": [
+        "127.0.0.1/32"
+    ],
+    "packages": [
+        "13.65.0.0/16",
+        "157.55.204.33/32",
+        "2a01:111:f403:f90c::/62"
+    ],
+    "pages": [
+        "192.30.252.153/32",
+        "192.30.252.154/32"
+    ],
+    "importer": [
+        "54.158.161.132",
+        "54.226.70.38"
+    ],
+    "actions": [
+        "13.64.0.0/16",
+        "13.65.0.0/16"
+    ],
+    "dependabot": [
+        "192.168.7.15/32",
+        "192.168.7.16/32"
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Artifact<'_> {
+    const JSON: &'static str = r##"{
+    "id": 5,
+    "node_id": "MDEwOkNoZWNrU3VpdGU1",
+    "name": "AdventureWorks.Framework",
+    "size_in_bytes": 12345,
+    "url": "https://api.github.com/repos/github/hello-world/actions/artifacts/5",
+    "archive_download_url": "https://api.github.com/repos/github/hello-world/actions/artifacts/5/zip",
+    "expired": false,
+    "created_at": null,
+    "expires_at": null,
+    "updated_at": null
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Blob<'_> {
+    const JSON: &'static str = r##"{
+    "content": "content",
+    "encoding": "encoding",
+    "url": "https://api.github.com/url",
+    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+    "size": null,
+    "node_id": "node_id"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::CheckAnnotation<'_> {
+    const JSON: &'static str = r##"{
+    "path": "README.md",
+    "start_line": 2,
+    "end_line": 2,
+    "start_column": 5,
+    "end_column": 10,
+    "annotation_level": "warning",
+    "title": "Spell Checker",
+    "message": "Check your spelling for 'banaas'.",
+    "raw_details": "Do you mean 'bananas' or 'banana'?",
+    "blob_href": "blob_href"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::CheckRun<'_> {
+    const JSON: &'static str = r##"{
+    "id": 21,
+    "head_sha": "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d",
+    "node_id": "MDg6Q2hlY2tSdW40",
+    "external_id": "42",
+    "url": "https://api.github.com/repos/github/hello-world/check-runs/4",
+    "html_url": "https://github.com/github/hello-world/runs/4",
+    "details_url": "https://example.com",
+    "status": "queued",
+    "conclusion": "neutral",
+    "started_at": "2018-05-04T01:14:52Z",
+    "completed_at": "2018-05-04T01:14:52Z",
+    "output": {
+        "title": null,
+        "summary": null,
+        "text": null,
+        "annotations_count": 1,
+        "annotations_url": "https://api.github.com/annotations_url"
+    },
+    "name": "test-coverage",
+    "check_suite": null,
+    "app": null,
+    "pull_requests": [
+        {
+            "id": 1,
+            "number": 1,
+            "url": "https://api.github.com/url",
+            "head": {
+                "ref": "ref",
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "repo": {
+                    "id": 1,
+                    "url": "https://api.github.com/url",
+                    "name": "name"
+                }
+            },
+            "base": {
+                "ref": "ref",
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "repo": {
+                    "id": 1,
+                    "url": "https://api.github.com/url",
+                    "name": "name"
+                }
+            }
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::CodeScanningSarifsReceipt<'_> {
+    const JSON: &'static str = r##"{
+    "id": "6c81cd8e-b078-4ac3-a3be-1dad7dbd0b53"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::CodeScanningSarifsStatus<'_> {
+    const JSON: &'static str = r##"{}"##;
+}
+
+impl Example for crate::v1_1_4::schema::CodespacesPublicKey<'_> {
+    const JSON: &'static str = r##"{
+    "key_id": "1234567",
+    "key": "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs=",
+    "id": 2,
+    "url": "https://api.github.com/user/keys/2",
+    "title": "ssh-rsa AAAAB3NzaC1yc2EAAA",
+    "created_at": "2011-01-26T19:01:12Z"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::DependabotPublicKey<'_> {
+    const JSON: &'static str = r##"{
+    "key_id": "1234567",
+    "key": "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs="
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::FileCommit<'_> {
+    const JSON: &'static str = r##"{
+    "content": null,
+    "commit": {}
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::GitCommit<'_> {
+    const JSON: &'static str = r##"{
+    "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+    "node_id": "node_id",
+    "url": "https://api.github.com/url",
+    "author": {
+        "date": "2014-08-09T08:02:04+12:00",
+        "email": "monalisa.octocat@example.com",
+        "name": "Monalisa Octocat"
+    },
+    "committer": {
+        "date": "2014-08-09T08:02:04+12:00",
+        "email": "monalisa.octocat@example.com",
+        "name": "Monalisa Octocat"
+    },
+    "message": "Fix #42",
+    "tree": {
+        "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+        "url": "https://api.github.com/url"
+    },
+    "parents": [
+        {
+            "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+            "url": "https://api.github.com/url",
+            "html_url": "https://api.github.com/html_url"
+        }
+    ],
+    "verification": {
+        "verified": false,
+        "reason": "reason",
+        "signature": null,
+        "payload": null
+    },
+    "html_url": "https://api.github.com/html_url"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::GitRef<'_> {
+    const JSON: &'static str = r##"{
+    "ref": "ref",
+    "node_id": "node_id",
+    "url": "https://api.github.com/url",
+    "object": {
+        "type": "type",
+        "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+        "url": "https://api.github.com/url"
+    }
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::GitTree<'_> {
+    const JSON: &'static str = r##"{
+    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+    "url": "https://api.github.com/url",
+    "truncated": false,
+    "tree": [
+        {
+            "path": "test/file.rb",
+            "mode": "040000",
+            "type": "tree",
+            "sha": "23f6827669e43831def8a7ad935069c8bd418261",
+            "size": 12,
+            "url": "https://api.github.com/repos/owner-482f3203ecf01f67e9deb18e/BBB_Private_Repo/git/blobs/23f6827669e43831def8a7ad935069c8bd418261"
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Installation<'_> {
+    const JSON: &'static str = r##"{
+    "id": 1,
+    "account": null,
+    "repository_selection": "repository_selection",
+    "access_tokens_url": "https://api.github.com/installations/1/access_tokens",
+    "repositories_url": "https://api.github.com/installation/repositories",
+    "html_url": "https://github.com/organizations/github/settings/installations/1",
+    "app_id": 1,
+    "target_id": 1,
+    "target_type": "Organization",
+    "permissions": {},
+    "events": [
+        "events"
+    ],
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "single_file_name": "config.yaml",
+    "has_multiple_single_files": true,
+    "single_file_paths": [
+        "config.yml",
+        ".github/issue_TEMPLATE.md"
+    ],
+    "app_slug": "github-actions",
+    "suspended_by": null,
+    "suspended_at": null,
+    "contact_email": "\"test_13f1e99741e3e004@d7e1eb0bc0a1ba12.com\""
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::InstallationToken<'_> {
+    const JSON: &'static str = r##"{
+    "token": "token",
+    "expires_at": "2011-01-26T19:01:12Z",
+    "single_file": "README.md",
+    "has_multiple_single_files": true,
+    "single_file_paths": [
+        "config.yml",
+        ".github/issue_TEMPLATE.md"
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Integration<'_> {
+    const JSON: &'static str = r##"{
+    "id": 37,
+    "slug": "probot-owners",
+    "node_id": "MDExOkludGVncmF0aW9uMQ==",
+    "owner": null,
+    "name": "Probot Owners",
+    "description": "The description of the app.",
+    "external_url": "https://example.com",
+    "html_url": "https://github.com/apps/super-ci",
+    "created_at": "2017-07-08T16:18:44-04:00",
+    "updated_at": "2017-07-08T16:18:44-04:00",
+    "permissions": {
+        "issues": "read",
+        "deployments": "write"
+    },
+    "events": [
+        "label",
+        "deployment"
+    ],
+    "installations_count": 5,
+    "client_id": "\"Iv1.25b5d1e65ffc4022\"",
+    "client_secret": "\"1d4b2097ac622ba702d19de498f005747a8b21d3\"",
+    "webhook_secret": "\"6fba8f2fc8a7e8f2cca5577eddd82ca7586b3b6b\""
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Issue<'_> {
+    const JSON: &'static str = r##"{
+    "id": 1,
+    "node_id": "node_id",
+    "url": "https://api.github.com/repositories/42/issues/1",
+    "repository_url": "https://api.github.com/repository_url",
+    "labels_url": "https://api.github.com/labels_url",
+    "comments_url": "https://api.github.com/comments_url",
+    "events_url": "https://api.github.com/events_url",
+    "html_url": "https://api.github.com/html_url",
+    "number": 42,
+    "state": "open",
+    "title": "Widget creation fails in Safari on OS X 10.8",
+    "body": "It looks like the new widget form is broken on Safari. When I try and create the widget, Safari crashes. This is reproducible on 10.8, but not 10.9. Maybe a browser bug?",
+    "user": null,
+    "labels": [
+        "bug",
+        "registration"
+    ],
+    "assignee": null,
+    "milestone": null,
+    "locked": false,
+    "comments": 1,
+    "closed_at": null,
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "author_association": "OWNER"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::IssueComment<'_> {
+    const JSON: &'static str = r##"{
+    "id": 42,
+    "node_id": "node_id",
+    "url": "https://api.github.com/repositories/42/issues/comments/1",
+    "body": "What version of Safari were you using when you observed this bug?",
+    "html_url": "https://api.github.com/html_url",
+    "user": null,
+    "created_at": "2011-04-14T16:00:49Z",
+    "updated_at": "2011-04-14T16:00:49Z",
+    "issue_url": "https://api.github.com/issue_url",
+    "author_association": "OWNER"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Label<'_> {
+    const JSON: &'static str = r##"{
+    "id": 208045946,
+    "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+    "url": "https://api.github.com/repositories/42/labels/bug",
+    "name": "bug",
+    "description": "Something isn't working",
+    "color": "FFFFFF",
+    "default": true
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::MinimalRepository<'_> {
+    const JSON: &'static str = r##"{
+    "id": 1296269,
+    "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+    "name": "Hello-World",
+    "full_name": "octocat/Hello-World",
+    "owner": {
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+        "url": "https://api.github.com/users/octocat",
+        "html_url": "https://github.com/octocat",
+        "followers_url": "https://api.github.com/users/octocat/followers",
+        "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+        "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+        "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+        "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+        "organizations_url": "https://api.github.com/users/octocat/orgs",
+        "repos_url": "https://api.github.com/users/octocat/repos",
+        "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+        "received_events_url": "https://api.github.com/users/octocat/received_events",
+        "type": "User",
+        "site_admin": false,
+        "starred_at": "2020-07-09T00:17:55Z"
+    },
+    "private": false,
+    "html_url": "https://github.com/octocat/Hello-World",
+    "description": "This your first repo!",
+    "fork": false,
+    "url": "https://api.github.com/repos/octocat/Hello-World",
+    "archive_url": "https://api.github.com/archive_url",
+    "assignees_url": "https://api.github.com/assignees_url",
+    "blobs_url": "https://api.github.com/blobs_url",
+    "branches_url": "https://api.github.com/branches_url",
+    "collaborators_url": "https://api.github.com/collaborators_url",
+    "comments_url": "https://api.github.com/comments_url",
+    "commits_url": "https://api.github.com/commits_url",
+    "compare_url": "https://api.github.com/compare_url",
+    "contents_url": "https://api.github.com/contents_url",
+    "contributors_url": "https://api.github.com/contributors_url",
+    "deployments_url": "https://api.github.com/deployments_url",
+    "downloads_url": "https://api.github.com/downloads_url",
+    "events_url": "https://api.github.com/events_url",
+    "forks_url": "https://api.github.com/forks_url",
+    "git_commits_url": "https://api.github.com/git_commits_url",
+    "git_refs_url": "https://api.github.com/git_refs_url",
+    "git_tags_url": "https://api.github.com/git_tags_url",
+    "issue_comment_url": "https://api.github.com/issue_comment_url",
+    "issue_events_url": "https://api.github.com/issue_events_url",
+    "issues_url": "https://api.github.com/issues_url",
+    "keys_url": "https://api.github.com/keys_url",
+    "labels_url": "https://api.github.com/labels_url",
+    "languages_url": "https://api.github.com/languages_url",
+    "merges_url": "https://api.github.com/merges_url",
+    "milestones_url": "https://api.github.com/milestones_url",
+    "notifications_url": "https://api.github.com/notifications_url",
+    "pulls_url": "https://api.github.com/pulls_url",
+    "releases_url": "https://api.github.com/releases_url",
+    "stargazers_url": "https://api.github.com/stargazers_url",
+    "statuses_url": "https://api.github.com/statuses_url",
+    "subscribers_url": "https://api.github.com/subscribers_url",
+    "subscription_url": "https://api.github.com/subscription_url",
+    "tags_url": "https://api.github.com/tags_url",
+    "teams_url": "https://api.github.com/teams_url",
+    "trees_url": "https://api.github.com/trees_url",
+    "hooks_url": "https://api.github.com/hooks_url",
+    "pushed_at": "2011-01-26T19:06:43Z",
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:14:43Z",
+    "role_name": "admin",
+    "forks": 0,
+    "open_issues": 0,
+    "watchers": 0
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::PullRequest<'_> {
+    const JSON: &'static str = r##"{
+    "url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347",
+    "id": 1,
+    "node_id": "MDExOlB1bGxSZXF1ZXN0MQ==",
+    "html_url": "https://github.com/octocat/Hello-World/pull/1347",
+    "diff_url": "https://github.com/octocat/Hello-World/pull/1347.diff",
+    "patch_url": "https://github.com/octocat/Hello-World/pull/1347.patch",
+    "issue_url": "https://api.github.com/repos/octocat/Hello-World/issues/1347",
+    "commits_url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347/commits",
+    "review_comments_url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347/comments",
+    "review_comment_url": "https://api.github.com/repos/octocat/Hello-World/pulls/comments{/number}",
+    "comments_url": "https://api.github.com/repos/octocat/Hello-World/issues/1347/comments",
+    "statuses_url": "https://api.github.com/repos/octocat/Hello-World/statuses/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+    "number": 1347,
+    "state": "open",
+    "locked": true,
+    "title": "Amazing new feature",
+    "user": null,
+    "body": "Please pull these awesome changes",
+    "labels": [
+        {
+            "id": 208045946,
+            "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+            "url": "https://api.github.com/repositories/42/labels/bug",
+            "name": "bug",
+            "description": "Something isn't working",
+            "color": "FFFFFF",
+            "default": true
+        }
+    ],
+    "milestone": null,
+    "active_lock_reason": "too heated",
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "closed_at": "2011-01-26T19:01:12Z",
+    "merged_at": "2011-01-26T19:01:12Z",
+    "merge_commit_sha": "e5bd3914e2e596debea16f433f57875b5b90bcd6",
+    "assignee": null,
+    "head": {
+        "label": "label",
+        "ref": "ref",
+        "repo": null,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "user": null
+    },
+    "base": {
+        "label": "label",
+        "ref": "ref",
+        "repo": null,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "user": null
+    },
+    "_links": {
+        "comments": {
+            "href": "href"
+        },
+        "commits": {
+            "href": "href"
+        },
+        "statuses": {
+            "href": "href"
+        },
+        "html": {
+            "href": "href"
+        },
+        "issue": {
+            "href": "href"
+        },
+        "review_comments": {
+            "href": "href"
+        },
+        "review_comment": {
+            "href": "href"
+        },
+        "self": {
+            "href": "href"
+        }
+    },
+    "author_association": "OWNER",
+    "auto_merge": null,
+    "draft": false,
+    "merged": false,
+    "mergeable": true,
+    "rebaseable": true,
+    "mergeable_state": "clean",
+    "merged_by": null,
+    "comments": 10,
+    "review_comments": 0,
+    "maintainer_can_modify": true,
+    "commits": 3,
+    "additions": 100,
+    "deletions": 3,
+    "changed_files": 5
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::PullRequestSimple<'_> {
+    const JSON: &'static str = r##"{
+    "url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347",
+    "id": 1,
+    "node_id": "MDExOlB1bGxSZXF1ZXN0MQ==",
+    "html_url": "https://github.com/octocat/Hello-World/pull/1347",
+    "diff_url": "https://github.com/octocat/Hello-World/pull/1347.diff",
+    "patch_url": "https://github.com/octocat/Hello-World/pull/1347.patch",
+    "issue_url": "https://api.github.com/repos/octocat/Hello-World/issues/1347",
+    "commits_url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347/commits",
+    "review_comments_url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347/comments",
+    "review_comment_url": "https://api.github.com/repos/octocat/Hello-World/pulls/comments{/number}",
+    "comments_url": "https://api.github.com/repos/octocat/Hello-World/issues/1347/comments",
+    "statuses_url": "https://api.github.com/repos/octocat/Hello-World/statuses/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+    "number": 1347,
+    "state": "open",
+    "locked": true,
+    "title": "Amazing new feature",
+    "user": null,
+    "body": "Please pull these awesome changes",
+    "labels": [
+        {
+            "id": 208045946,
+            "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+            "url": "https://api.github.com/repositories/42/labels/bug",
+            "name": "bug",
+            "description": "Something isn't working",
+            "color": "FFFFFF",
+            "default": true
+        }
+    ],
+    "milestone": null,
+    "active_lock_reason": "too heated",
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "closed_at": "2011-01-26T19:01:12Z",
+    "merged_at": "2011-01-26T19:01:12Z",
+    "merge_commit_sha": "e5bd3914e2e596debea16f433f57875b5b90bcd6",
+    "assignee": null,
+    "head": {
+        "label": "label",
+        "ref": "ref",
+        "repo": null,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "user": null
+    },
+    "base": {
+        "label": "label",
+        "ref": "ref",
+        "repo": null,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "user": null
+    },
+    "_links": {
+        "comments": {
+            "href": "href"
+        },
+        "commits": {
+            "href": "href"
+        },
+        "statuses": {
+            "href": "href"
+        },
+        "html": {
+            "href": "href"
+        },
+        "issue": {
+            "href": "href"
+        },
+        "review_comments": {
+            "href": "href"
+        },
+        "review_comment": {
+            "href": "href"
+        },
+        "self": {
+            "href": "href"
+        }
+    },
+    "author_association": "OWNER",
+    "auto_merge": null,
+    "draft": false
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::RateLimitOverview<'_> {
+    const JSON: &'static str = r##"{
+    "resources": {
+        "core": {
+            "limit": 1,
+            "remaining": 1,
+            "reset": 1,
+            "used": 1
+        },
+        "search": {
+            "limit": 1,
+            "remaining": 1,
+            "reset": 1,
+            "used": 1
+        }
+    },
+    "rate": {
+        "limit": 1,
+        "remaining": 1,
+        "reset": 1,
+        "used": 1
+    }
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Release<'_> {
+    const JSON: &'static str = r##"{
+    "url": "https://api.github.com/url",
+    "html_url": "https://api.github.com/html_url",
+    "assets_url": "https://api.github.com/assets_url",
+    "upload_url": "https://api.github.com/upload_url",
+    "tarball_url": null,
+    "zipball_url": null,
+    "id": 1,
+    "node_id": "node_id",
+    "tag_name": "v1.0.0",
+    "target_commitish": "master",
+    "name": null,
+    "draft": false,
+    "prerelease": false,
+    "created_at": "2011-01-26T19:01:12Z",
+    "published_at": null,
+    "author": {
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+        "url": "https://api.github.com/users/octocat",
+        "html_url": "https://github.com/octocat",
+        "followers_url": "https://api.github.com/users/octocat/followers",
+        "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+        "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+        "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+        "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+        "organizations_url": "https://api.github.com/users/octocat/orgs",
+        "repos_url": "https://api.github.com/users/octocat/repos",
+        "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+        "received_events_url": "https://api.github.com/users/octocat/received_events",
+        "type": "User",
+        "site_admin": false,
+        "starred_at": "2020-07-09T00:17:55Z"
+    },
+    "assets": [
+        {
+            "url": "https://api.github.com/url",
+            "browser_download_url": "https://api.github.com/browser_download_url",
+            "id": 1,
+            "node_id": "node_id",
+            "name": "Team Environment",
+            "label": null,
+            "state": "state",
+            "content_type": "content_type",
+            "size": 1,
+            "download_count": 1,
+            "created_at": "2011-01-26T19:01:12Z",
+            "updated_at": "2011-01-26T19:01:12Z",
+            "uploader": null
+        }
+    ]
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::ReleaseAsset<'_> {
+    const JSON: &'static str = r##"{
+    "url": "https://api.github.com/url",
+    "browser_download_url": "https://api.github.com/browser_download_url",
+    "id": 1,
+    "node_id": "node_id",
+    "name": "Team Environment",
+    "label": null,
+    "state": "state",
+    "content_type": "content_type",
+    "size": 1,
+    "download_count": 1,
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "uploader": null
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::Repository<'_> {
+    const JSON: &'static str = r##"{
+    "id": 42,
+    "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+    "name": "Team Environment",
+    "full_name": "octocat/Hello-World",
+    "license": null,
+    "forks": 1,
+    "owner": {
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+        "url": "https://api.github.com/users/octocat",
+        "html_url": "https://github.com/octocat",
+        "followers_url": "https://api.github.com/users/octocat/followers",
+        "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+        "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+        "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+        "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+        "organizations_url": "https://api.github.com/users/octocat/orgs",
+        "repos_url": "https://api.github.com/users/octocat/repos",
+        "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+        "received_events_url": "https://api.github.com/users/octocat/received_events",
+        "type": "User",
+        "site_admin": false,
+        "starred_at": "2020-07-09T00:17:55Z"
+    },
+    "private": false,
+    "html_url": "https://github.com/octocat/Hello-World",
+    "description": "This your first repo!",
+    "fork": false,
+    "url": "https://api.github.com/repos/octocat/Hello-World",
+    "archive_url": "https://api.github.com/archive_url",
+    "assignees_url": "https://api.github.com/assignees_url",
+    "blobs_url": "https://api.github.com/blobs_url",
+    "branches_url": "https://api.github.com/branches_url",
+    "collaborators_url": "https://api.github.com/collaborators_url",
+    "comments_url": "https://api.github.com/comments_url",
+    "commits_url": "https://api.github.com/commits_url",
+    "compare_url": "https://api.github.com/compare_url",
+    "contents_url": "https://api.github.com/contents_url",
+    "contributors_url": "https://api.github.com/contributors_url",
+    "deployments_url": "https://api.github.com/deployments_url",
+    "downloads_url": "https://api.github.com/downloads_url",
+    "events_url": "https://api.github.com/events_url",
+    "forks_url": "https://api.github.com/forks_url",
+    "git_commits_url": "https://api.github.com/git_commits_url",
+    "git_refs_url": "https://api.github.com/git_refs_url",
+    "git_tags_url": "https://api.github.com/git_tags_url",
+    "git_url": "https://api.github.com/git_url",
+    "issue_comment_url": "https://api.github.com/issue_comment_url",
+    "issue_events_url": "https://api.github.com/issue_events_url",
+    "issues_url": "https://api.github.com/issues_url",
+    "keys_url": "https://api.github.com/keys_url",
+    "labels_url": "https://api.github.com/labels_url",
+    "languages_url": "https://api.github.com/languages_url",
+    "merges_url": "https://api.github.com/merges_url",
+    "milestones_url": "https://api.github.com/milestones_url",
+    "notifications_url": "https://api.github.com/notifications_url",
+    "pulls_url": "https://api.github.com/pulls_url",
+    "releases_url": "https://api.github.com/releases_url",
+    "ssh_url": "https://api.github.com/ssh_url",
+    "stargazers_url": "https://api.github.com/stargazers_url",
+    "statuses_url": "https://api.github.com/statuses_url",
+    "subscribers_url": "https://api.github.com/subscribers_url",
+    "subscription_url": "https://api.github.com/subscription_url",
+    "tags_url": "https://api.github.com/tags_url",
+    "teams_url": "https://api.github.com/teams_url",
+    "trees_url": "https://api.github.com/trees_url",
+    "clone_url": "https://api.github.com/clone_url",
+    "mirror_url": "git:git.example.com/octocat/Hello-World",
+    "hooks_url": "http://api.github.com/repos/octocat/Hello-World/hooks",
+    "svn_url": "https://svn.github.com/octocat/Hello-World",
+    "homepage": "https://github.com",
+    "language": null,
+    "forks_count": 9,
+    "stargazers_count": 80,
+    "watchers_count": 80,
+    "size": 108,
+    "default_branch": "master",
+    "open_issues_count": 0,
+    "is_template": true,
+    "has_issues": true,
+    "has_projects": true,
+    "has_wiki": true,
+    "has_pages": false,
+    "has_downloads": true,
+    "archived": false,
+    "disabled": false,
+    "pushed_at": "2011-01-26T19:06:43Z",
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:14:43Z",
+    "allow_rebase_merge": true,
+    "allow_squash_merge": true,
+    "allow_auto_merge": false,
+    "delete_branch_on_merge": false,
+    "allow_merge_commit": true,
+    "open_issues": 1,
+    "watchers": 1,
+    "starred_at": "\"2020-07-09T00:17:42Z\""
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::ShortBlob<'_> {
+    const JSON: &'static str = r##"{
+    "url": "https://api.github.com/url",
+    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::SimpleUser<'_> {
+    const JSON: &'static str = r##"{
+    "login": "octocat",
+    "id": 1,
+    "node_id": "MDQ6VXNlcjE=",
+    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+    "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+    "url": "https://api.github.com/users/octocat",
+    "html_url": "https://github.com/octocat",
+    "followers_url": "https://api.github.com/users/octocat/followers",
+    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+    "organizations_url": "https://api.github.com/users/octocat/orgs",
+    "repos_url": "https://api.github.com/users/octocat/repos",
+    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+    "received_events_url": "https://api.github.com/users/octocat/received_events",
+    "type": "User",
+    "site_admin": false,
+    "starred_at": "2020-07-09T00:17:55Z"
+}"##;
+}
+
+impl Example for crate::v1_1_4::schema::WorkflowRun<'_> {
+    const JSON: &'static str = r##"{
+    "id": 5,
+    "name": "Build",
+    "node_id": "MDEwOkNoZWNrU3VpdGU1",
+    "check_suite_id": 42,
+    "check_suite_node_id": "MDEwOkNoZWNrU3VpdGU0Mg==",
+    "head_branch": "master",
+    "head_sha": "009b8a3a9ccbb128af87f9b1c0f4c62e8a304f6d",
+    "run_number": 106,
+    "run_attempt": 1,
+    "event": "push",
+    "status": "completed",
+    "conclusion": "neutral",
+    "workflow_id": 5,
+    "url": "https://api.github.com/repos/github/hello-world/actions/runs/5",
+    "html_url": "https://github.com/github/hello-world/suites/4",
+    "pull_requests": null,
+    "created_at": "2011-01-26T19:01:12Z",
+    "updated_at": "2011-01-26T19:01:12Z",
+    "jobs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/jobs",
+    "logs_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/logs",
+    "check_suite_url": "https://api.github.com/repos/github/hello-world/check-suites/12",
+    "artifacts_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun/artifacts",
+    "cancel_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/cancel",
+    "rerun_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/rerun",
+    "previous_attempt_url": "https://api.github.com/repos/github/hello-world/actions/runs/5/attempts/3",
+    "workflow_url": "https://api.github.com/repos/github/hello-world/actions/workflows/main.yaml",
+    "head_commit": null,
+    "repository": {
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "Hello-World",
+        "full_name": "octocat/Hello-World",
+        "owner": {
+            "login": "octocat",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+            "url": "https://api.github.com/users/octocat",
+            "html_url": "https://github.com/octocat",
+            "followers_url": "https://api.github.com/users/octocat/followers",
+            "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+            "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+            "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+            "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+            "organizations_url": "https://api.github.com/users/octocat/orgs",
+            "repos_url": "https://api.github.com/users/octocat/repos",
+            "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+            "received_events_url": "https://api.github.com/users/octocat/received_events",
+            "type": "User",
+            "site_admin": false,
+            "starred_at": "2020-07-09T00:17:55Z"
+        },
+        "private": false,
+        "html_url": "https://github.com/octocat/Hello-World",
+        "description": "This your first repo!",
+        "fork": false,
+        "url": "https://api.github.com/repos/octocat/Hello-World",
+        "archive_url": "https://api.github.com/archive_url",
+        "assignees_url": "https://api.github.com/assignees_url",
+        "blobs_url": "https://api.github.com/blobs_url",
+        "branches_url": "https://api.github.com/branches_url",
+        "collaborators_url": "https://api.github.com/collaborators_url",
+        "comments_url": "https://api.github.com/comments_url",
+        "commits_url": "https://api.github.com/commits_url",
+        "compare_url": "https://api.github.com/compare_url",
+        "contents_url": "https://api.github.com/contents_url",
+        "contributors_url": "https://api.github.com/contributors_url",
+        "deployments_url": "https://api.github.com/deployments_url",
+        "downloads_url": "https://api.github.com/downloads_url",
+        "events_url": "https://api.github.com/events_url",
+        "forks_url": "https://api.github.com/forks_url",
+        "git_commits_url": "https://api.github.com/git_commits_url",
+        "git_refs_url": "https://api.github.com/git_refs_url",
+        "git_tags_url": "https://api.github.com/git_tags_url",
+        "issue_comment_url": "https://api.github.com/issue_comment_url",
+        "issue_events_url": "https://api.github.com/issue_events_url",
+        "issues_url": "https://api.github.com/issues_url",
+        "keys_url": "https://api.github.com/keys_url",
+        "labels_url": "https://api.github.com/labels_url",
+        "languages_url": "https://api.github.com/languages_url",
+        "merges_url": "https://api.github.com/merges_url",
+        "milestones_url": "https://api.github.com/milestones_url",
+        "notifications_url": "https://api.github.com/notifications_url",
+        "pulls_url": "https://api.github.com/pulls_url",
+        "releases_url": "https://api.github.com/releases_url",
+        "stargazers_url": "https://api.github.com/stargazers_url",
+        "statuses_url": "https://api.github.com/statuses_url",
+        "subscribers_url": "https://api.github.com/subscribers_url",
+        "subscription_url": "https://api.github.com/subscription_url",
+        "tags_url": "https://api.github.com/tags_url",
+        "teams_url": "https://api.github.com/teams_url",
+        "trees_url": "https://api.github.com/trees_url",
+        "hooks_url": "https://api.github.com/hooks_url",
+        "pushed_at": "2011-01-26T19:06:43Z",
+        "created_at": "2011-01-26T19:01:12Z",
+        "updated_at": "2011-01-26T19:14:43Z",
+        "role_name": "admin",
+        "forks": 0,
+        "open_issues": 0,
+        "watchers": 0
+    },
+    "head_repository": {
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "Hello-World",
+        "full_name": "octocat/Hello-World",
+        "owner": {
+            "login": "octocat",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "gravatar_id": "41d064eb2195891e12d0413f63227ea7",
+            "url": "https://api.github.com/users/octocat",
+            "html_url": "https://github.com/octocat",
+            "followers_url": "https://api.github.com/users/octocat/followers",
+            "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+            "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+            "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+            "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+            "organizations_url": "https://api.github.com/users/octocat/orgs",
+            "repos_url": "https://api.github.com/users/octocat/repos",
+            "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+            "received_events_url": "https://api.github.com/users/octocat/received_events",
+            "type": "User",
+            "site_admin": false,
+            "starred_at": "2020-07-09T00:17:55Z"
+        },
+        "private": false,
+        "html_url": "https://github.com/octocat/Hello-World",
+        "description": "This your first repo!",
+        "fork": false,
+        "url": "https://api.github.com/repos/octocat/Hello-World",
+        "archive_url": "https://api.github.com/archive_url",
+        "assignees_url": "https://api.github.com/assignees_url",
+        "blobs_url": "https://api.github.com/blobs_url",
+        "branches_url": "https://api.github.com/branches_url",
+        "collaborators_url": "https://api.github.com/collaborators_url",
+        "comments_url": "https://api.github.com/comments_url",
+        "commits_url": "https://api.github.com/commits_url",
+        "compare_url": "https://api.github.com/compare_url",
+        "contents_url": "https://api.github.com/contents_url",
+        "contributors_url": "https://api.github.com/contributors_url",
+        "deployments_url": "https://api.github.com/deployments_url",
+        "downloads_url": "https://api.github.com/downloads_url",
+        "events_url": "https://api.github.com/events_url",
+        "forks_url": "https://api.github.com/forks_url",
+        "git_commits_url": "https://api.github.com/git_commits_url",
+        "git_refs_url": "https://api.github.com/git_refs_url",
+        "git_tags_url": "https://api.github.com/git_tags_url",
+        "issue_comment_url": "https://api.github.com/issue_comment_url",
+        "issue_events_url": "https://api.github.com/issue_events_url",
+        "issues_url": "https://api.github.com/issues_url",
+        "keys_url": "https://api.github.com/keys_url",
+        "labels_url": "https://api.github.com/labels_url",
+        "languages_url": "https://api.github.com/languages_url",
+        "merges_url": "https://api.github.com/merges_url",
+        "milestones_url": "https://api.github.com/milestones_url",
+        "notifications_url": "https://api.github.com/notifications_url",
+        "pulls_url": "https://api.github.com/pulls_url",
+        "releases_url": "https://api.github.com/releases_url",
+        "stargazers_url": "https://api.github.com/stargazers_url",
+        "statuses_url": "https://api.github.com/statuses_url",
+        "subscribers_url": "https://api.github.com/subscribers_url",
+        "subscription_url": "https://api.github.com/subscription_url",
+        "tags_url": "https://api.github.com/tags_url",
+        "teams_url": "https://api.github.com/teams_url",
+        "trees_url": "https://api.github.com/trees_url",
+        "hooks_url": "https://api.github.com/hooks_url",
+        "pushed_at": "2011-01-26T19:06:43Z",
+        "created_at": "2011-01-26T19:01:12Z",
+        "updated_at": "2011-01-26T19:14:43Z",
+        "role_name": "admin",
+        "forks": 0,
+        "open_issues": 0,
+        "watchers": 0
+    },
+    "head_repository_id": 5
+}"##;
+}
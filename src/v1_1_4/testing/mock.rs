@@ -0,0 +1,338 @@
+//! Scripted responses for unit tests.
+//!
+//! ```ignore
+//! let mock = MockTransport::new();
+//! mock.expect(
+//!     Expectation::new("issues_create")
+//!         .param("owner", "octocat")
+//!         .body(json!({"title": "Found a bug"}))
+//!         .respond(422, json!({"message": "Validation Failed"})),
+//! );
+//! let github = Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+//! // ... code under test ...
+//! mock.verify();
+//! ```
+
+use ::std::convert::Infallible;
+use ::std::fmt;
+use ::std::future::{ready, Ready};
+use ::std::io;
+use ::std::pin::Pin;
+use ::std::sync::{Arc, Mutex};
+use ::std::task::{Context, Poll};
+
+use ::http::{HeaderMap, HeaderValue, StatusCode, Uri};
+use ::hyper::client::connect::{Connected, Connection};
+use ::hyper::service::{service_fn, Service};
+use ::hyper::{Body, Request, Response};
+use ::tokio::io::{AsyncRead, AsyncWrite, DuplexStream, ReadBuf};
+
+use crate::v1_1_4::config::Configuration;
+
+use super::{error, json, route, Handler, Params, RecordedRequest};
+
+/// Base URL used by [`MockTransport::config`]. No name lookup occurs.
+pub const MOCK_BASE_URL: &str = "http://api.github.mock";
+
+/// An expected call of an operation, and its response.
+///
+/// By default, an expectation is met by exactly one call, and responds with the successful
+/// status of the operation and an example body, as returned by [`super::FakeGitHub`].
+#[derive(Clone, Debug)]
+pub struct Expectation {
+    operation: String,
+    params: Vec<(String, String)>,
+    body: Option<::serde_json::Value>,
+    times: usize,
+    calls: usize,
+    status: Option<StatusCode>,
+    headers: HeaderMap,
+    response: Option<Vec<u8>>,
+}
+
+impl Expectation {
+    /// Expect a call of `operation`, e.g. `issues_create`.
+    pub fn new(operation: &str) -> Self {
+        Expectation {
+            operation: operation.to_owned(),
+            params: Vec::new(),
+            body: None,
+            times: 1,
+            calls: 0,
+            status: None,
+            headers: HeaderMap::new(),
+            response: None,
+        }
+    }
+
+    /// Only match calls where path parameter `name` is `value`, e.g. `("owner", "octocat")`.
+    pub fn param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.push((name.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Only match calls with a JSON request body equal to `body`.
+    pub fn body(mut self, body: impl ::serde::Serialize) -> Self {
+        self.body = Some(::serde_json::to_value(body).unwrap_or_default());
+        self
+    }
+
+    /// Expect exactly `times` calls.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+
+    /// Respond with `status` and a JSON body.
+    pub fn respond(mut self, status: u16, body: impl ::serde::Serialize) -> Self {
+        self.status = Some(StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR));
+        self.response = Some(::serde_json::to_vec(&body).unwrap_or_default());
+        self
+    }
+
+    /// Respond with `status` and no body.
+    pub fn respond_empty(mut self, status: u16) -> Self {
+        self.status = Some(StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR));
+        self.response = None;
+        self
+    }
+
+    /// Add a header to the response, e.g. `x-ratelimit-remaining`.
+    pub fn header(mut self, name: ::http::header::HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    fn matches(&self, operation: &str, params: &Params, body: &[u8]) -> bool {
+        self.calls < self.times
+            && self.operation == operation
+            && self
+                .params
+                .iter()
+                .all(|(name, value)| params.get(name.as_str()) == Some(value))
+            && match &self.body {
+                Some(expected) => ::serde_json::from_slice::<::serde_json::Value>(body).ok().as_ref() == Some(expected),
+                None => true,
+            }
+    }
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.operation)?;
+        for (name, value) in &self.params {
+            write!(f, " {}={}", name, value)?;
+        }
+        if let Some(body) = &self.body {
+            write!(f, " body={}", body)?;
+        }
+        write!(f, " (called {} of {} times)", self.calls, self.times)
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    expectations: Mutex<Vec<Expectation>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+    unexpected: Mutex<Vec<String>>,
+}
+
+/// Transport returning scripted responses to a `Caller`, without opening a socket.
+///
+/// Each request is answered by the first [`Expectation`] that matches it and has not been
+/// called the expected number of times. Other requests receive `501 Not Implemented`.
+///
+/// Dropping the transport panics if any expectation is unmet or any request was unexpected,
+/// unless the thread is already panicking.
+#[derive(Default)]
+pub struct MockTransport {
+    state: Arc<MockState>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an expected call.
+    pub fn expect(&self, expectation: Expectation) {
+        if let Ok(mut expectations) = self.state.expectations.lock() {
+            expectations.push(expectation);
+        }
+    }
+
+    /// Client connecting to this transport. This must be used within a Tokio runtime.
+    pub fn client(&self) -> ::hyper::Client<MockConnector> {
+        let state = self.state.clone();
+        MockConnector::client(Arc::new(move |request| {
+            let state = state.clone();
+            Box::pin(async move { state.respond(request).await })
+        }))
+    }
+
+    /// Configuration using [`MOCK_BASE_URL`] for API requests and uploads.
+    pub fn config(&self) -> Configuration {
+        Configuration {
+            base_url: MOCK_BASE_URL.into(),
+            uploads_base_url: MOCK_BASE_URL.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    /// Describe unmet expectations and unexpected requests, if any.
+    pub fn check(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+        if let Ok(expectations) = self.state.expectations.lock() {
+            for expectation in expectations.iter().filter(|expectation| expectation.calls != expectation.times) {
+                problems.push(format!("Unmet expectation: {}", expectation));
+            }
+        }
+        if let Ok(unexpected) = self.state.unexpected.lock() {
+            for request in unexpected.iter() {
+                problems.push(format!("Unexpected request: {}", request));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n"))
+        }
+    }
+
+    /// Panic if any expectation is unmet or any request was unexpected.
+    pub fn verify(&self) {
+        if let Err(problems) = self.check() {
+            panic!("{}", problems);
+        }
+    }
+}
+
+impl Drop for MockTransport {
+    fn drop(&mut self) {
+        if !::std::thread::panicking() {
+            self.verify();
+        }
+    }
+}
+
+impl MockState {
+    async fn respond(&self, request: Request<Body>) -> Response<Body> {
+        let (parts, body) = request.into_parts();
+        let body = match ::hyper::body::to_bytes(body).await {
+            Ok(body) => body,
+            Err(err) => return error(StatusCode::BAD_REQUEST, &err.to_string()),
+        };
+        let found = route(&parts.method, parts.uri.path());
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(RecordedRequest {
+                operation: found.as_ref().map(|(route, _)| route.operation),
+                method: parts.method.clone(),
+                uri: parts.uri.clone(),
+                headers: parts.headers,
+                body: body.to_vec(),
+            });
+        }
+        let matched = found.as_ref().and_then(|(route, params)| {
+            let mut expectations = self.expectations.lock().ok()?;
+            let expectation = expectations
+                .iter_mut()
+                .find(|expectation| expectation.matches(route.operation, params, &body))?;
+            expectation.calls += 1;
+            let status = expectation.status.unwrap_or_else(|| {
+                StatusCode::from_u16(route.status).unwrap_or(StatusCode::OK)
+            });
+            let response = match expectation.status {
+                Some(_) => expectation.response.clone(),
+                None => route.example.map(|example| example()),
+            };
+            Some(json(status, expectation.headers.clone(), response))
+        });
+        matched.unwrap_or_else(|| {
+            let description = match &found {
+                Some((route, _)) => format!("{} {} ({})", parts.method, parts.uri, route.operation),
+                None => format!("{} {}", parts.method, parts.uri),
+            };
+            if let Ok(mut unexpected) = self.unexpected.lock() {
+                unexpected.push(description.clone());
+            }
+            error(StatusCode::NOT_IMPLEMENTED, &format!("Unexpected request {}", description))
+        })
+    }
+}
+
+/// Connector for a `hyper::Client`, serving each connection in memory, e.g. from a
+/// [`MockTransport`].
+#[derive(Clone)]
+pub struct MockConnector {
+    handler: Handler,
+}
+
+impl MockConnector {
+    pub(crate) fn client(handler: Handler) -> ::hyper::Client<MockConnector> {
+        ::hyper::Client::builder().build(MockConnector { handler })
+    }
+}
+
+impl Service<Uri> for MockConnector {
+    type Response = MockStream;
+    type Error = Infallible;
+    type Future = Ready<Result<MockStream, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let (client, server) = ::tokio::io::duplex(64 * 1024);
+        let handler = self.handler.clone();
+        ::tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let response = handler(request);
+                async move { Ok::<_, Infallible>(response.await) }
+            });
+            ::hyper::server::conn::Http::new()
+                .serve_connection(server, service)
+                .await
+                .ok();
+        });
+        ready(Ok(MockStream(client)))
+    }
+}
+
+/// In-memory connection to a [`MockTransport`].
+pub struct MockStream(DuplexStream);
+
+impl Connection for MockStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for MockStream {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for MockStream {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,320 @@
+//! Fake GitHub server for testing without network access.
+//!
+//! Enabled by the `testing` feature. [`FakeGitHub`] serves every operation of the API on a
+//! local port, so that a `Caller` using [`FakeGitHub::config`] is exercised end to end.
+//!
+//! Operations on repositories, issues, pull requests and labels use a small in-memory
+//! model, e.g. an issue created using `issues_create` is returned by `issues_get`. Other
+//! operations return the successful status of the operation. Operations with a generated
+//! `response` module, such as `issues_get` or `actions_get_artifact`, return an example body
+//! of the response type of the operation, built from the examples of the API description;
+//! the others return an empty body.
+//!
+//! For unit tests, [`MockTransport`] instead returns scripted responses for expected
+//! operations, without opening a socket, and reports unmet and unexpected calls, and
+//! [`Cassette`] records interactions with GitHub to replay them later.
+//!
+//! ```ignore
+//! let server = FakeGitHub::start()?;
+//! server.create_repo("octocat", "hello-world");
+//! let github = Caller::new(hyper::Client::new(), server.config(), tokio::time::sleep);
+//! let response = github.issues_create("octocat", "hello-world", &issue).await?;
+//! assert_eq!(server.requests()[0].operation, Some("issues_create"));
+//! ```
+
+use ::std::collections::HashMap;
+use ::std::convert::Infallible;
+use ::std::sync::{Arc, Mutex};
+
+use ::futures_util::future::BoxFuture;
+use ::hyper::service::{make_service_fn, service_fn};
+use ::hyper::{Body, Request, Response, Server};
+
+use crate::v1_1_4::config::Configuration;
+use crate::v1_1_4::ApiError;
+
+mod cassette;
+mod examples;
+mod mock;
+mod model;
+mod routes;
+
+pub use cassette::*;
+pub use mock::*;
+use examples::Example;
+use model::Model;
+use routes::ROUTES;
+
+/// Login of the user authenticated by every request.
+pub const AUTHENTICATED_USER: &str = "octocat";
+
+/// An operation of the API.
+struct Route {
+    operation: &'static str,
+    method: ::http::Method,
+    /// Path template, e.g. `/repos/{owner}/{repo}`.
+    path: &'static str,
+    /// Status of a successful response.
+    status: u16,
+    /// Example body of a successful response, for operations with a generated response type.
+    example: Option<fn() -> Vec<u8>>,
+}
+
+fn example<T: Example>() -> Vec<u8> {
+    T::JSON.as_bytes().to_vec()
+}
+
+fn example_list<T: Example>() -> Vec<u8> {
+    format!("[{}]", T::JSON).into_bytes()
+}
+
+/// Path parameters of a request, by name.
+pub(crate) type Params = HashMap<&'static str, String>;
+
+/// Whether a template matches every segment of the path, and whether each of its segments
+/// is literal, ordered from least to most specific.
+type Specificity = (bool, Vec<bool>);
+
+/// Find the route for a request, and decode its path parameters.
+///
+/// A parameter matches a single path segment, unless it is the last segment of the template,
+/// in which case it can also match the remaining segments, e.g. the `{path}` of a file.
+///
+/// When several templates match, the most specific one is used: a template matching every
+/// segment wins over one whose last parameter matches the remaining segments, then a literal
+/// segment wins over a parameter at the first position where the templates differ, e.g.
+/// `/repos/{owner}/{repo}/issues/comments` over `/repos/{owner}/{repo}/issues/{issue_number}`.
+fn route(method: &::http::Method, path: &str) -> Option<(&'static Route, Params)> {
+    let segments = path.trim_end_matches('/').split('/').collect::<Vec<_>>();
+    let mut best: Option<(Specificity, &'static Route, Params)> = None;
+    for route in ROUTES.iter().filter(|route| route.method == method) {
+        let template = route.path.trim_end_matches('/').split('/').collect::<Vec<_>>();
+        if template.len() > segments.len() {
+            continue;
+        }
+        let mut params = Params::new();
+        let matched = template.iter().zip(&segments).all(|(expected, segment)| {
+            match param_name(expected) {
+                Some(name) => {
+                    params.insert(name, decode(segment));
+                    true
+                }
+                None => expected == segment,
+            }
+        });
+        if !matched {
+            continue;
+        }
+        let exact = template.len() == segments.len();
+        if !exact {
+            match template.last().and_then(|last| param_name(last)) {
+                Some(name) => {
+                    let rest = segments[template.len() - 1..].iter().map(|segment| decode(segment));
+                    params.insert(name, rest.collect::<Vec<_>>().join("/"));
+                }
+                None => continue,
+            }
+        }
+        let specificity: Specificity = (exact, template.iter().map(|segment| param_name(segment).is_none()).collect());
+        if !matches!(&best, Some((best, _, _)) if specificity <= *best) {
+            best = Some((specificity, route, params));
+        }
+    }
+    best.map(|(_, route, params)| (route, params))
+}
+
+/// Name of the parameter of a template segment, e.g. `owner` for `{owner}`.
+fn param_name(segment: &str) -> Option<&str> {
+    segment.strip_prefix('{').and_then(|name| name.strip_suffix('}'))
+}
+
+/// Operations of the API, as the name, method and path template of each, e.g.
+/// `("issues_get", GET, "/repos/{owner}/{repo}/issues/{issue_number}")`.
+pub fn operations() -> impl Iterator<Item = (&'static str, &'static ::http::Method, &'static str)> {
+    ROUTES.iter().map(|route| (route.operation, &route.method, route.path))
+}
+
+/// Name of the operation that [`FakeGitHub`] and [`MockTransport`] route a request to.
+pub fn operation_for(method: &::http::Method, path: &str) -> Option<&'static str> {
+    route(method, path).map(|(route, _)| route.operation)
+}
+
+fn decode(segment: &str) -> String {
+    ::percent_encoding::percent_decode_str(segment)
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+/// Responds to requests received by a local server or a [`MockConnector`].
+type Handler = Arc<dyn Fn(Request<Body>) -> BoxFuture<'static, Response<Body>> + Send + Sync>;
+
+/// Serve requests on a free local port, until the returned sender is used or dropped.
+fn serve(handler: Handler) -> Result<(String, ::tokio::sync::oneshot::Sender<()>), ApiError> {
+    let make_service = make_service_fn(move |_| {
+        let handler = handler.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = handler(request);
+                async move { Ok::<_, Infallible>(response.await) }
+            }))
+        }
+    });
+    let server = Server::try_bind(&([127, 0, 0, 1], 0).into())?.serve(make_service);
+    let base_url = format!("http://{}", server.local_addr());
+    let (shutdown, stop) = ::tokio::sync::oneshot::channel::<()>();
+    ::tokio::spawn(server.with_graceful_shutdown(async {
+        stop.await.ok();
+    }));
+    Ok((base_url, shutdown))
+}
+
+/// A request received by [`FakeGitHub`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    /// Operation matching the request, e.g. `issues_create`, or `None` if no operation matched.
+    pub operation: Option<&'static str>,
+    pub method: ::http::Method,
+    pub uri: ::http::Uri,
+    pub headers: ::http::HeaderMap,
+    pub body: Vec<u8>,
+}
+
+struct State {
+    model: Mutex<Model>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// Fake GitHub server, listening on a local port until dropped.
+pub struct FakeGitHub {
+    base_url: String,
+    state: Arc<State>,
+    shutdown: Option<::tokio::sync::oneshot::Sender<()>>,
+}
+
+impl FakeGitHub {
+    /// Start a server on a free local port. This must be called within a Tokio runtime.
+    pub fn start() -> Result<Self, ApiError> {
+        let state = Arc::new(State {
+            model: Mutex::new(Model::default()),
+            requests: Mutex::new(Vec::new()),
+        });
+        let handler: Handler = {
+            let state = state.clone();
+            Arc::new(move |request| {
+                let state = state.clone();
+                Box::pin(async move { respond(&state, request).await })
+            })
+        };
+        let (base_url, shutdown) = serve(handler)?;
+        Ok(FakeGitHub {
+            base_url,
+            state,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:49152`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Configuration using the server for API requests and uploads.
+    pub fn config(&self) -> Configuration {
+        Configuration {
+            base_url: self.base_url.clone().into(),
+            uploads_base_url: self.base_url.clone().into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add repository `owner/name` to the model, as if created by its owner.
+    pub fn create_repo(&self, owner: &str, name: &str) {
+        if let Ok(mut model) = self.state.model.lock() {
+            model.create_repo(owner, name, &::serde_json::Value::Null).ok();
+        }
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for FakeGitHub {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+async fn respond(state: &State, request: Request<Body>) -> Response<Body> {
+    let (parts, body) = request.into_parts();
+    let body = match ::hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) => return error(::http::StatusCode::BAD_REQUEST, &err.to_string()),
+    };
+    let found = route(&parts.method, parts.uri.path());
+    if let Ok(mut requests) = state.requests.lock() {
+        requests.push(RecordedRequest {
+            operation: found.as_ref().map(|(route, _)| route.operation),
+            method: parts.method.clone(),
+            uri: parts.uri.clone(),
+            headers: parts.headers.clone(),
+            body: body.to_vec(),
+        });
+    }
+    let (route, params) = match found {
+        Some(found) => found,
+        None => return error(::http::StatusCode::NOT_FOUND, "Not Found"),
+    };
+    // Modelled operations take JSON bodies, unlike e.g. release asset uploads.
+    let is_json = match parts.headers.get(::http::header::CONTENT_TYPE) {
+        Some(content_type) => content_type.to_str().is_ok_and(|content_type| content_type.contains("json")),
+        None => true,
+    };
+    let modelled = match state.model.lock() {
+        Ok(mut model) if is_json => model.handle(route.operation, &params, parts.uri.query(), &body),
+        _ => None,
+    };
+    let (status, body) = match modelled {
+        Some(Ok((status, value))) => (status, value.map(|value| value.to_string().into_bytes())),
+        Some(Err((status, message))) => return error(status, &message),
+        None => (
+            ::http::StatusCode::from_u16(route.status).unwrap_or(::http::StatusCode::OK),
+            route.example.map(|example| example()),
+        ),
+    };
+    json(status, ::http::HeaderMap::new(), body)
+}
+
+/// Response with a JSON body, or with no body.
+fn json(status: ::http::StatusCode, headers: ::http::HeaderMap, body: Option<Vec<u8>>) -> Response<Body> {
+    let mut response = match body {
+        Some(body) => {
+            let mut response = Response::new(Body::from(body));
+            response.headers_mut().insert(
+                ::http::header::CONTENT_TYPE,
+                ::http::HeaderValue::from_static("application/json; charset=utf-8"),
+            );
+            response
+        }
+        None => Response::new(Body::empty()),
+    };
+    *response.status_mut() = status;
+    response.headers_mut().extend(headers);
+    response
+}
+
+/// Error response in the format returned by GitHub.
+fn error(status: ::http::StatusCode, message: &str) -> Response<Body> {
+    let body = ::serde_json::json!({
+        "message": message,
+        "documentation_url": "https://docs.github.com/rest",
+    });
+    json(status, ::http::HeaderMap::new(), Some(body.to_string().into_bytes()))
+}
@@ -0,0 +1,620 @@
+//! In-memory model of repositories, issues, pull requests and labels.
+//!
+//! Responses are rendered from the default value of the schema type of each response, with
+//! the modelled fields replaced, so that they decode as the response type.
+
+use ::std::collections::BTreeMap;
+use ::std::sync::atomic::{AtomicI64, Ordering};
+
+use ::http::StatusCode;
+use ::serde_json::{json, Value};
+
+use crate::v1_1_4::schema::{
+    issue, Issue, Label, MinimalRepository, PullRequest, PullRequestSimple, Repository, SimpleUser,
+};
+
+use super::{Params, AUTHENTICATED_USER};
+
+/// Response to a modelled operation: a status and optional body, or an error status and message.
+pub(super) type Reply = Result<(StatusCode, Option<Value>), (StatusCode, String)>;
+
+#[derive(Default)]
+pub(super) struct Model {
+    /// Repositories, keyed by the lowercase full name.
+    repos: BTreeMap<String, Repo>,
+}
+
+struct Repo {
+    id: i64,
+    owner: String,
+    name: String,
+    description: Option<String>,
+    private: bool,
+    default_branch: String,
+    next_number: i64,
+    issues: BTreeMap<i64, IssueRecord>,
+    /// Labels, keyed by the lowercase name.
+    labels: BTreeMap<String, LabelRecord>,
+}
+
+/// An issue, or the issue of a pull request.
+struct IssueRecord {
+    id: i64,
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    user: String,
+    labels: Vec<String>,
+    pull: Option<PullRecord>,
+}
+
+struct PullRecord {
+    id: i64,
+    head: String,
+    base: String,
+    merged: bool,
+}
+
+struct LabelRecord {
+    id: i64,
+    name: String,
+    color: String,
+    description: Option<String>,
+}
+
+fn not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "Not Found".to_owned())
+}
+
+fn invalid(message: &str) -> (StatusCode, String) {
+    (StatusCode::UNPROCESSABLE_ENTITY, message.to_owned())
+}
+
+/// Replace the fields of `target` with `fields`, recursively.
+fn merge(target: &mut Value, fields: Value) {
+    match (target, fields) {
+        (Value::Object(target), Value::Object(fields)) => {
+            for (name, value) in fields {
+                merge(target.entry(name).or_insert(Value::Null), value);
+            }
+        }
+        (target, fields) => *target = fields,
+    }
+}
+
+/// Render the default value of `T`, with `fields` replaced.
+fn render<T: Default + ::serde::Serialize>(fields: Value) -> Value {
+    let mut value = ::serde_json::to_value(T::default()).unwrap_or(Value::Null);
+    merge(&mut value, fields);
+    value
+}
+
+fn user(login: &str) -> Value {
+    render::<SimpleUser>(json!({
+        "login": login,
+        "url": format!("https://api.github.com/users/{}", login),
+        "html_url": format!("https://github.com/{}", login),
+        "type": "User",
+    }))
+}
+
+/// Value of query parameter `name`.
+fn query_param(query: Option<&str>, name: &str) -> Option<String> {
+    ::url::form_urlencoded::parse(query?.as_bytes())
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+fn string(body: &Value, name: &str) -> Option<String> {
+    body.get(name).and_then(Value::as_str).map(str::to_owned)
+}
+
+/// Source of unique IDs.
+static NEXT_ID: AtomicI64 = AtomicI64::new(1);
+
+fn id() -> i64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+impl Model {
+    fn repo(&mut self, params: &Params) -> Result<&mut Repo, (StatusCode, String)> {
+        let owner = params.get("owner").map(String::as_str).unwrap_or_default();
+        let repo = params.get("repo").map(String::as_str).unwrap_or_default();
+        self.repos
+            .get_mut(&format!("{}/{}", owner, repo).to_lowercase())
+            .ok_or_else(not_found)
+    }
+
+    pub(super) fn create_repo(&mut self, owner: &str, name: &str, body: &Value) -> Reply {
+        let key = format!("{}/{}", owner, name).to_lowercase();
+        if name.is_empty() {
+            return Err(invalid("Repository name is required"));
+        }
+        if self.repos.contains_key(&key) {
+            return Err(invalid(
+                "Repository creation failed: name already exists on this account",
+            ));
+        }
+        let repo = Repo {
+            id: id(),
+            owner: owner.to_owned(),
+            name: name.to_owned(),
+            description: string(body, "description"),
+            private: body
+                .get("private")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            default_branch: "main".to_owned(),
+            next_number: 1,
+            issues: BTreeMap::new(),
+            labels: BTreeMap::new(),
+        };
+        let rendered = repo.render::<Repository>();
+        self.repos.insert(key, repo);
+        Ok((StatusCode::CREATED, Some(rendered)))
+    }
+
+    /// Handle a modelled operation, or return `None` if the operation is not modelled.
+    pub(super) fn handle(
+        &mut self,
+        operation: &str,
+        params: &Params,
+        query: Option<&str>,
+        body: &[u8],
+    ) -> Option<Reply> {
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            match ::serde_json::from_slice(body) {
+                Ok(body) => body,
+                Err(err) => {
+                    return Some(Err((
+                        StatusCode::BAD_REQUEST,
+                        format!("Problems parsing JSON: {}", err),
+                    )))
+                }
+            }
+        };
+        let param = |name: &str| params.get(name).cloned().unwrap_or_default();
+        let reply = match operation {
+            "repos_create_for_authenticated_user" => self.create_repo(
+                AUTHENTICATED_USER,
+                &string(&body, "name").unwrap_or_default(),
+                &body,
+            ),
+            "repos_create_in_org" => self.create_repo(
+                &param("org"),
+                &string(&body, "name").unwrap_or_default(),
+                &body,
+            ),
+            "repos_get" => self
+                .repo(params)
+                .map(|repo| (StatusCode::OK, Some(repo.render::<Repository>()))),
+            "repos_update" => self.repo(params).map(|repo| {
+                if let Some(description) = string(&body, "description") {
+                    repo.description = Some(description);
+                }
+                if let Some(private) = body.get("private").and_then(Value::as_bool) {
+                    repo.private = private;
+                }
+                if let Some(default_branch) = string(&body, "default_branch") {
+                    repo.default_branch = default_branch;
+                }
+                (StatusCode::OK, Some(repo.render::<Repository>()))
+            }),
+            "repos_delete" => {
+                let key = format!("{}/{}", param("owner"), param("repo")).to_lowercase();
+                match self.repos.remove(&key) {
+                    Some(_) => Ok((StatusCode::NO_CONTENT, None)),
+                    None => Err(not_found()),
+                }
+            }
+            "repos_list_for_org" | "repos_list_for_user" => {
+                let owner = params
+                    .get("org")
+                    .or_else(|| params.get("username"))
+                    .cloned()
+                    .unwrap_or_default();
+                let repos = self
+                    .repos
+                    .values()
+                    .filter(|repo| repo.owner.eq_ignore_ascii_case(&owner))
+                    .map(Repo::render::<MinimalRepository>)
+                    .collect();
+                Ok((StatusCode::OK, Some(Value::Array(repos))))
+            }
+            "issues_create" | "pulls_create" => self.repo(params).and_then(|repo| {
+                let title = string(&body, "title").ok_or_else(|| invalid("Title is required"))?;
+                let pull = if operation == "pulls_create" {
+                    Some(PullRecord {
+                        id: id(),
+                        head: string(&body, "head").ok_or_else(|| invalid("Head is required"))?,
+                        base: string(&body, "base").ok_or_else(|| invalid("Base is required"))?,
+                        merged: false,
+                    })
+                } else {
+                    None
+                };
+                let labels = body
+                    .get("labels")
+                    .and_then(Value::as_array)
+                    .map(|labels| {
+                        labels
+                            .iter()
+                            .filter_map(|label| label.as_str().map(str::to_owned))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let number = repo.next_number;
+                repo.next_number += 1;
+                let issue = IssueRecord {
+                    id: id(),
+                    number,
+                    title,
+                    body: string(&body, "body"),
+                    state: "open".to_owned(),
+                    user: AUTHENTICATED_USER.to_owned(),
+                    labels,
+                    pull,
+                };
+                for label in &issue.labels {
+                    repo.ensure_label(label);
+                }
+                repo.issues.insert(number, issue);
+                let rendered = if operation == "pulls_create" {
+                    repo.render_pull(number)
+                } else {
+                    repo.render_issue(number)
+                };
+                Ok((StatusCode::CREATED, rendered))
+            }),
+            "issues_get" => self
+                .repo(params)
+                .and_then(|repo| {
+                    repo.issue(&param("issue_number"))
+                        .map(|number| repo.render_issue(number))
+                })
+                .map(|issue| (StatusCode::OK, issue)),
+            "pulls_get" => self
+                .repo(params)
+                .and_then(|repo| {
+                    repo.pull(&param("pull_number"))
+                        .map(|number| repo.render_pull(number))
+                })
+                .map(|pull| (StatusCode::OK, pull)),
+            "issues_update" | "pulls_update" => {
+                let number_param = if operation == "pulls_update" {
+                    "pull_number"
+                } else {
+                    "issue_number"
+                };
+                self.repo(params).and_then(|repo| {
+                    let number = if operation == "pulls_update" {
+                        repo.pull(&param(number_param))?
+                    } else {
+                        repo.issue(&param(number_param))?
+                    };
+                    if let Some(issue) = repo.issues.get_mut(&number) {
+                        if let Some(title) = string(&body, "title") {
+                            issue.title = title;
+                        }
+                        if let Some(text) = string(&body, "body") {
+                            issue.body = Some(text);
+                        }
+                        match string(&body, "state").as_deref() {
+                            Some(state @ ("open" | "closed")) => issue.state = state.to_owned(),
+                            Some(_) => return Err(invalid("State is invalid")),
+                            None => {}
+                        }
+                        if let (Some(pull), Some(base)) = (&mut issue.pull, string(&body, "base")) {
+                            pull.base = base;
+                        }
+                    }
+                    let rendered = if operation == "pulls_update" {
+                        repo.render_pull(number)
+                    } else {
+                        repo.render_issue(number)
+                    };
+                    Ok((StatusCode::OK, rendered))
+                })
+            }
+            "issues_list_for_repo" | "pulls_list" => {
+                let state = query_param(query, "state").unwrap_or_else(|| "open".to_owned());
+                self.repo(params).map(|repo| {
+                    let items = repo
+                        .issues
+                        .values()
+                        .rev()
+                        .filter(|issue| state == "all" || issue.state == state)
+                        .filter(|issue| operation == "issues_list_for_repo" || issue.pull.is_some())
+                        .filter_map(|issue| {
+                            if operation == "pulls_list" {
+                                repo.render_pull_as::<PullRequestSimple>(issue.number)
+                            } else {
+                                repo.render_issue(issue.number)
+                            }
+                        })
+                        .collect();
+                    (StatusCode::OK, Some(Value::Array(items)))
+                })
+            }
+            "pulls_merge" => self.repo(params).and_then(|repo| {
+                let number = repo.pull(&param("pull_number"))?;
+                let issue = repo.issues.get_mut(&number).ok_or_else(not_found)?;
+                match &mut issue.pull {
+                    Some(pull) if !pull.merged && issue.state == "open" => {
+                        pull.merged = true;
+                        issue.state = "closed".to_owned();
+                        Ok((
+                            StatusCode::OK,
+                            Some(json!({
+                                "sha": format!("{:040x}", pull.id),
+                                "merged": true,
+                                "message": "Pull Request successfully merged",
+                            })),
+                        ))
+                    }
+                    _ => Err((
+                        StatusCode::METHOD_NOT_ALLOWED,
+                        "Pull Request is not mergeable".to_owned(),
+                    )),
+                }
+            }),
+            "issues_create_label" => self.repo(params).and_then(|repo| {
+                let name = string(&body, "name").ok_or_else(|| invalid("Name is required"))?;
+                if repo.labels.contains_key(&name.to_lowercase()) {
+                    return Err(invalid("Validation Failed"));
+                }
+                let label = LabelRecord {
+                    id: id(),
+                    color: string(&body, "color").unwrap_or_else(|| "ededed".to_owned()),
+                    description: string(&body, "description"),
+                    name,
+                };
+                let rendered = label.render();
+                repo.labels.insert(label.name.to_lowercase(), label);
+                Ok((StatusCode::CREATED, Some(rendered)))
+            }),
+            "issues_get_label" => self.repo(params).and_then(|repo| {
+                let label = repo
+                    .labels
+                    .get(&param("name").to_lowercase())
+                    .ok_or_else(not_found)?;
+                Ok((StatusCode::OK, Some(label.render())))
+            }),
+            "issues_update_label" => self.repo(params).and_then(|repo| {
+                let mut label = repo
+                    .labels
+                    .remove(&param("name").to_lowercase())
+                    .ok_or_else(not_found)?;
+                if let Some(name) = string(&body, "new_name") {
+                    for issue in repo.issues.values_mut() {
+                        for issue_label in &mut issue.labels {
+                            if issue_label.eq_ignore_ascii_case(&label.name) {
+                                *issue_label = name.clone();
+                            }
+                        }
+                    }
+                    label.name = name;
+                }
+                if let Some(color) = string(&body, "color") {
+                    label.color = color;
+                }
+                if let Some(description) = string(&body, "description") {
+                    label.description = Some(description);
+                }
+                let rendered = label.render();
+                repo.labels.insert(label.name.to_lowercase(), label);
+                Ok((StatusCode::OK, Some(rendered)))
+            }),
+            "issues_delete_label" => self.repo(params).and_then(|repo| {
+                let label = repo
+                    .labels
+                    .remove(&param("name").to_lowercase())
+                    .ok_or_else(not_found)?;
+                for issue in repo.issues.values_mut() {
+                    issue
+                        .labels
+                        .retain(|name| !name.eq_ignore_ascii_case(&label.name));
+                }
+                Ok((StatusCode::NO_CONTENT, None))
+            }),
+            "issues_list_labels_for_repo" => self.repo(params).map(|repo| {
+                let labels = repo.labels.values().map(LabelRecord::render).collect();
+                (StatusCode::OK, Some(Value::Array(labels)))
+            }),
+            "issues_list_labels_on_issue" => self.repo(params).and_then(|repo| {
+                let number = repo.issue(&param("issue_number"))?;
+                Ok((StatusCode::OK, Some(repo.render_labels(number))))
+            }),
+            "issues_add_labels" | "issues_set_labels" => self.repo(params).and_then(|repo| {
+                let number = repo.issue(&param("issue_number"))?;
+                let names = match &body {
+                    Value::Array(labels) => labels.clone(),
+                    body => body
+                        .get("labels")
+                        .and_then(Value::as_array)
+                        .cloned()
+                        .unwrap_or_default(),
+                };
+                let names = names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>();
+                for name in &names {
+                    repo.ensure_label(name);
+                }
+                if let Some(issue) = repo.issues.get_mut(&number) {
+                    if operation == "issues_set_labels" {
+                        issue.labels.clear();
+                    }
+                    for name in names {
+                        if !issue
+                            .labels
+                            .iter()
+                            .any(|label| label.eq_ignore_ascii_case(&name))
+                        {
+                            issue.labels.push(name);
+                        }
+                    }
+                }
+                Ok((StatusCode::OK, Some(repo.render_labels(number))))
+            }),
+            "issues_remove_label" => self.repo(params).and_then(|repo| {
+                let number = repo.issue(&param("issue_number"))?;
+                let name = param("name");
+                let issue = repo.issues.get_mut(&number).ok_or_else(not_found)?;
+                let count = issue.labels.len();
+                issue
+                    .labels
+                    .retain(|label| !label.eq_ignore_ascii_case(&name));
+                if issue.labels.len() == count {
+                    return Err(not_found());
+                }
+                Ok((StatusCode::OK, Some(repo.render_labels(number))))
+            }),
+            _ => return None,
+        };
+        Some(reply)
+    }
+}
+
+impl Repo {
+    fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+
+    fn render<T: Default + ::serde::Serialize>(&self) -> Value {
+        let full_name = self.full_name();
+        render::<T>(json!({
+            "id": self.id,
+            "name": self.name,
+            "full_name": full_name,
+            "owner": user(&self.owner),
+            "private": self.private,
+            "description": self.description,
+            "default_branch": self.default_branch,
+            "visibility": if self.private { "private" } else { "public" },
+            "url": format!("https://api.github.com/repos/{}", full_name),
+            "html_url": format!("https://github.com/{}", full_name),
+        }))
+    }
+
+    /// Number of an issue, which may be a pull request.
+    fn issue(&self, number: &str) -> Result<i64, (StatusCode, String)> {
+        number
+            .parse()
+            .ok()
+            .filter(|number| self.issues.contains_key(number))
+            .ok_or_else(not_found)
+    }
+
+    /// Number of a pull request.
+    fn pull(&self, number: &str) -> Result<i64, (StatusCode, String)> {
+        number
+            .parse()
+            .ok()
+            .filter(|number| matches!(self.issues.get(number), Some(issue) if issue.pull.is_some()))
+            .ok_or_else(not_found)
+    }
+
+    fn ensure_label(&mut self, name: &str) {
+        self.labels
+            .entry(name.to_lowercase())
+            .or_insert_with(|| LabelRecord {
+                id: id(),
+                name: name.to_owned(),
+                color: "ededed".to_owned(),
+                description: None,
+            });
+    }
+
+    fn render_labels(&self, number: i64) -> Value {
+        let labels = self
+            .issues
+            .get(&number)
+            .map(|issue| issue.labels.as_slice())
+            .unwrap_or_default();
+        Value::Array(
+            labels
+                .iter()
+                .filter_map(|name| self.labels.get(&name.to_lowercase()))
+                .map(LabelRecord::render)
+                .collect(),
+        )
+    }
+
+    fn render_issue(&self, number: i64) -> Option<Value> {
+        let issue = self.issues.get(&number)?;
+        let url = format!(
+            "https://api.github.com/repos/{}/issues/{}",
+            self.full_name(),
+            number
+        );
+        let mut fields = json!({
+            "id": issue.id,
+            "number": issue.number,
+            "title": issue.title,
+            "body": issue.body,
+            "state": issue.state,
+            "user": user(&issue.user),
+            "labels": self.render_labels(number),
+            "url": url,
+            "html_url": format!("https://github.com/{}/issues/{}", self.full_name(), number),
+            "repository_url": format!("https://api.github.com/repos/{}", self.full_name()),
+        });
+        if issue.pull.is_some() {
+            fields["pull_request"] = render::<issue::PullRequest>(json!({
+                "url": format!("https://api.github.com/repos/{}/pulls/{}", self.full_name(), number),
+                "html_url": format!("https://github.com/{}/pull/{}", self.full_name(), number),
+            }));
+        }
+        Some(render::<Issue>(fields))
+    }
+
+    fn render_pull(&self, number: i64) -> Option<Value> {
+        self.render_pull_as::<PullRequest>(number)
+    }
+
+    fn render_pull_as<T: Default + ::serde::Serialize>(&self, number: i64) -> Option<Value> {
+        let issue = self.issues.get(&number)?;
+        let pull = issue.pull.as_ref()?;
+        let branch = |name: &str| {
+            json!({
+                "label": format!("{}:{}", self.owner, name),
+                "ref": name,
+                "sha": format!("{:040x}", pull.id),
+                "user": user(&self.owner),
+                "repo": self.render::<Repository>(),
+            })
+        };
+        Some(render::<T>(json!({
+            "id": pull.id,
+            "number": issue.number,
+            "title": issue.title,
+            "body": issue.body,
+            "state": issue.state,
+            "user": user(&issue.user),
+            "labels": self.render_labels(number),
+            "head": branch(&pull.head),
+            "base": branch(&pull.base),
+            "merged": pull.merged,
+            "url": format!("https://api.github.com/repos/{}/pulls/{}", self.full_name(), number),
+            "html_url": format!("https://github.com/{}/pull/{}", self.full_name(), number),
+        })))
+    }
+}
+
+impl LabelRecord {
+    fn render(&self) -> Value {
+        render::<Label>(json!({
+            "id": self.id,
+            "name": self.name,
+            "color": self.color,
+            "description": self.description,
+            "default": false,
+        }))
+    }
+}
@@ -0,0 +1,6014 @@
+//! Routes of the GitHub API, with the status and example body of a successful response.
+//!
+//! Generated from the same description as the [`request`](crate::v1_1_4::request) modules.
+
+use super::{example, example_list, Route};
+
+pub(super) static ROUTES: &[Route] = &[
+    Route {
+        operation: "actions_add_custom_labels_to_self_hosted_runner_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_add_custom_labels_to_self_hosted_runner_for_repo",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_add_repo_access_to_self_hosted_runner_group_in_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_add_selected_repo_to_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_add_self_hosted_runner_to_group_for_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_approve_workflow_run",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/approve",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_cancel_workflow_run",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/cancel",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_or_update_environment_secret",
+        method: ::http::Method::PUT,
+        path: "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_or_update_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_or_update_repo_secret",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_registration_token_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/actions/runners/registration-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_registration_token_for_repo",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runners/registration-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_remove_token_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/actions/runners/remove-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_remove_token_for_repo",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runners/remove-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_self_hosted_runner_group_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/actions/runner-groups",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_create_workflow_dispatch",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_artifact",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_environment_secret",
+        method: ::http::Method::DELETE,
+        path: "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_org_secret",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_repo_secret",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_self_hosted_runner_from_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runners/{runner_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_self_hosted_runner_from_repo",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_self_hosted_runner_group_from_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_workflow_run",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_delete_workflow_run_logs",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_disable_selected_repository_github_actions_organization",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_disable_workflow",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_download_artifact",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_download_job_logs_for_workflow_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/jobs/{job_id}/logs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_download_workflow_run_attempt_logs",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/logs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_download_workflow_run_logs",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_enable_selected_repository_github_actions_organization",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_enable_workflow",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_actions_cache_usage",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/cache/usage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_actions_cache_usage_by_repo_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/cache/usage-by-repository",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_actions_cache_usage_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/cache/usage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_actions_cache_usage_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/cache/usage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_allowed_actions_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_allowed_actions_repository",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_artifact",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Artifact<'static>>),
+    },
+    Route {
+        operation: "actions_get_environment_public_key",
+        method: ::http::Method::GET,
+        path: "/repositories/{repository_id}/environments/{environment_name}/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::ActionsPublicKey<'static>>),
+    },
+    Route {
+        operation: "actions_get_environment_secret",
+        method: ::http::Method::GET,
+        path: "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_github_actions_default_workflow_permissions_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_github_actions_default_workflow_permissions_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_github_actions_default_workflow_permissions_repository",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_github_actions_permissions_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_github_actions_permissions_repository",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_job_for_workflow_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/jobs/{job_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_org_public_key",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::ActionsPublicKey<'static>>),
+    },
+    Route {
+        operation: "actions_get_org_secret",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_pending_deployments_for_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_repo_public_key",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::ActionsPublicKey<'static>>),
+    },
+    Route {
+        operation: "actions_get_repo_secret",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_reviews_for_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/approvals",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_self_hosted_runner_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runners/{runner_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_self_hosted_runner_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_self_hosted_runner_group_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_workflow",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_workflow_access_to_repository",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/permissions/access",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_workflow_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::WorkflowRun<'static>>),
+    },
+    Route {
+        operation: "actions_get_workflow_run_attempt",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_workflow_run_usage",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/timing",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_get_workflow_usage",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_artifacts_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/artifacts",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::request::actions_list_artifacts_for_repo::response::Ok200<'static>>),
+    },
+    Route {
+        operation: "actions_list_environment_secrets",
+        method: ::http::Method::GET,
+        path: "/repositories/{repository_id}/environments/{environment_name}/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_jobs_for_workflow_run",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/jobs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_jobs_for_workflow_run_attempt",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/jobs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_labels_for_self_hosted_runner_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_labels_for_self_hosted_runner_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_org_secrets",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_repo_access_to_self_hosted_runner_group_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_repo_secrets",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_repo_workflows",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/workflows",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::request::actions_list_repo_workflows::response::Ok200<'static>>),
+    },
+    Route {
+        operation: "actions_list_runner_applications_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runners/downloads",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_runner_applications_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runners/downloads",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_selected_repos_for_org_secret",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_selected_repositories_enabled_github_actions_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/permissions/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_self_hosted_runner_groups_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runner-groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_self_hosted_runners_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_self_hosted_runners_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_self_hosted_runners_in_group_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_workflow_run_artifacts",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_list_workflow_runs",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::request::actions_list_workflow_runs::response::Ok200<'static>>),
+    },
+    Route {
+        operation: "actions_list_workflow_runs_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/actions/runs",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::request::actions_list_workflow_runs_for_repo::response::Ok200<'static>>),
+    },
+    Route {
+        operation: "actions_re_run_job_for_workflow_run",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/jobs/{job_id}/rerun",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_re_run_workflow",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_re_run_workflow_failed_jobs",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun-failed-jobs",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_all_custom_labels_from_self_hosted_runner_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_all_custom_labels_from_self_hosted_runner_for_repo",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_custom_label_from_self_hosted_runner_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runners/{runner_id}/labels/{name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_custom_label_from_self_hosted_runner_for_repo",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels/{name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_repo_access_to_self_hosted_runner_group_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_selected_repo_from_org_secret",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_remove_self_hosted_runner_from_group_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "actions_review_pending_deployments_for_run",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_allowed_actions_organization",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_allowed_actions_repository",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_custom_labels_for_self_hosted_runner_for_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_custom_labels_for_self_hosted_runner_for_repo",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_github_actions_default_workflow_permissions_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_github_actions_default_workflow_permissions_organization",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_github_actions_default_workflow_permissions_repository",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/permissions/workflow",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_github_actions_permissions_organization",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_github_actions_permissions_repository",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_repo_access_to_self_hosted_runner_group_in_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_selected_repos_for_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_selected_repositories_enabled_github_actions_organization",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/permissions/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_self_hosted_runners_in_group_for_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_set_workflow_access_to_repository",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/actions/permissions/access",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "actions_update_self_hosted_runner_group_for_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_check_repo_is_starred_by_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/starred/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_delete_repo_subscription",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/subscription",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "activity_delete_thread_subscription",
+        method: ::http::Method::DELETE,
+        path: "/notifications/threads/{thread_id}/subscription",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "activity_get_feeds",
+        method: ::http::Method::GET,
+        path: "/feeds",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_get_repo_subscription",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/subscription",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_get_thread",
+        method: ::http::Method::GET,
+        path: "/notifications/threads/{thread_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_get_thread_subscription_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/notifications/threads/{thread_id}/subscription",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_events_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_notifications_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/notifications",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_org_events_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/events/orgs/{org}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_public_events",
+        method: ::http::Method::GET,
+        path: "/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_public_events_for_repo_network",
+        method: ::http::Method::GET,
+        path: "/networks/{owner}/{repo}/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_public_events_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/events/public",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_public_org_events",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_received_events_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/received_events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_received_public_events_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/received_events/public",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_repo_events",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_repo_notifications_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/notifications",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_repos_starred_by_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/starred",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_repos_starred_by_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/starred",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_repos_watched_by_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/subscriptions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_stargazers_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stargazers",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_watched_repos_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/subscriptions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_list_watchers_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/subscribers",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_mark_notifications_as_read",
+        method: ::http::Method::PUT,
+        path: "/notifications",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_mark_repo_notifications_as_read",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/notifications",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_mark_thread_as_read",
+        method: ::http::Method::PATCH,
+        path: "/notifications/threads/{thread_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_set_repo_subscription",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/subscription",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_set_thread_subscription",
+        method: ::http::Method::PUT,
+        path: "/notifications/threads/{thread_id}/subscription",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_star_repo_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/starred/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "activity_unstar_repo_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/starred/{owner}/{repo}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_add_repo_to_installation_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/installations/{installation_id}/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_check_token",
+        method: ::http::Method::POST,
+        path: "/applications/{client_id}/token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "apps_create_from_manifest",
+        method: ::http::Method::POST,
+        path: "/app-manifests/{code}/conversions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "apps_create_installation_access_token",
+        method: ::http::Method::POST,
+        path: "/app/installations/{installation_id}/access_tokens",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::InstallationToken<'static>>),
+    },
+    Route {
+        operation: "apps_delete_authorization",
+        method: ::http::Method::DELETE,
+        path: "/applications/{client_id}/grant",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_delete_installation",
+        method: ::http::Method::DELETE,
+        path: "/app/installations/{installation_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_delete_token",
+        method: ::http::Method::DELETE,
+        path: "/applications/{client_id}/token",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_get_authenticated",
+        method: ::http::Method::GET,
+        path: "/app",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Integration<'static>>),
+    },
+    Route {
+        operation: "apps_get_by_slug",
+        method: ::http::Method::GET,
+        path: "/apps/{app_slug}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_get_installation",
+        method: ::http::Method::GET,
+        path: "/app/installations/{installation_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Installation<'static>>),
+    },
+    Route {
+        operation: "apps_get_org_installation",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/installation",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Installation<'static>>),
+    },
+    Route {
+        operation: "apps_get_repo_installation",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/installation",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Installation<'static>>),
+    },
+    Route {
+        operation: "apps_get_subscription_plan_for_account",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/accounts/{account_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_get_subscription_plan_for_account_stubbed",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/stubbed/accounts/{account_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_get_user_installation",
+        method: ::http::Method::GET,
+        path: "/users/{username}/installation",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Installation<'static>>),
+    },
+    Route {
+        operation: "apps_get_webhook_config_for_app",
+        method: ::http::Method::GET,
+        path: "/app/hook/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_get_webhook_delivery",
+        method: ::http::Method::GET,
+        path: "/app/hook/deliveries/{delivery_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_accounts_for_plan",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/plans/{plan_id}/accounts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_accounts_for_plan_stubbed",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/stubbed/plans/{plan_id}/accounts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_installation_repos_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/installations/{installation_id}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_installations",
+        method: ::http::Method::GET,
+        path: "/app/installations",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Installation<'static>>),
+    },
+    Route {
+        operation: "apps_list_installations_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/installations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_plans",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/plans",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_plans_stubbed",
+        method: ::http::Method::GET,
+        path: "/marketplace_listing/stubbed/plans",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_repos_accessible_to_installation",
+        method: ::http::Method::GET,
+        path: "/installation/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_subscriptions_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/marketplace_purchases",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_subscriptions_for_authenticated_user_stubbed",
+        method: ::http::Method::GET,
+        path: "/user/marketplace_purchases/stubbed",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_list_webhook_deliveries",
+        method: ::http::Method::GET,
+        path: "/app/hook/deliveries",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_redeliver_webhook_delivery",
+        method: ::http::Method::POST,
+        path: "/app/hook/deliveries/{delivery_id}/attempts",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "apps_remove_repo_from_installation_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/installations/{installation_id}/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_reset_token",
+        method: ::http::Method::PATCH,
+        path: "/applications/{client_id}/token",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_revoke_installation_access_token",
+        method: ::http::Method::DELETE,
+        path: "/installation/token",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_scope_token",
+        method: ::http::Method::POST,
+        path: "/applications/{client_id}/token/scoped",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "apps_suspend_installation",
+        method: ::http::Method::PUT,
+        path: "/app/installations/{installation_id}/suspended",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "apps_unsuspend_installation",
+        method: ::http::Method::DELETE,
+        path: "/app/installations/{installation_id}/suspended",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "apps_update_webhook_config_for_app",
+        method: ::http::Method::PATCH,
+        path: "/app/hook/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_actions_billing_ghe",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/settings/billing/actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_actions_billing_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/settings/billing/actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_actions_billing_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/settings/billing/actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_advanced_security_billing_ghe",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/settings/billing/advanced-security",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_advanced_security_billing_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/settings/billing/advanced-security",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_packages_billing_ghe",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/settings/billing/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_packages_billing_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/settings/billing/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_github_packages_billing_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/settings/billing/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_shared_storage_billing_ghe",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/settings/billing/shared-storage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_shared_storage_billing_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/settings/billing/shared-storage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "billing_get_shared_storage_billing_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/settings/billing/shared-storage",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "checks_create",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/check-runs",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::CheckRun<'static>>),
+    },
+    Route {
+        operation: "checks_create_suite",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/check-suites",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "checks_get",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::CheckRun<'static>>),
+    },
+    Route {
+        operation: "checks_get_suite",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "checks_list_annotations",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::CheckAnnotation<'static>>),
+    },
+    Route {
+        operation: "checks_list_for_ref",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{ref}/check-runs",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::request::checks_list_for_ref::response::Ok200<'static>>),
+    },
+    Route {
+        operation: "checks_list_for_suite",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "checks_list_suites_for_ref",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{ref}/check-suites",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "checks_rerequest_run",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/check-runs/{check_run_id}/rerequest",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "checks_rerequest_suite",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "checks_set_suites_preferences",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/check-suites/preferences",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "checks_update",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::CheckRun<'static>>),
+    },
+    Route {
+        operation: "code_scanning_delete_analysis",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_get_alert",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_get_analysis",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_get_sarif",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::CodeScanningSarifsStatus<'static>>),
+    },
+    Route {
+        operation: "code_scanning_list_alert_instances",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_list_alerts_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/code-scanning/alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_list_alerts_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_list_recent_analyses",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/code-scanning/analyses",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_update_alert",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "code_scanning_upload_sarif",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/code-scanning/sarifs",
+        status: 202,
+        example: Some(example::<crate::v1_1_4::schema::CodeScanningSarifsReceipt<'static>>),
+    },
+    Route {
+        operation: "codes_of_conduct_get_all_codes_of_conduct",
+        method: ::http::Method::GET,
+        path: "/codes_of_conduct",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codes_of_conduct_get_conduct_code",
+        method: ::http::Method::GET,
+        path: "/codes_of_conduct/{key}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_add_repository_for_secret_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_codespace_machines_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/{codespace_name}/machines",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_create_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/codespaces",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_create_or_update_repo_secret",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_create_or_update_secret_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/codespaces/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_create_with_pr_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/codespaces",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_create_with_repo_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/codespaces",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_delete_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/codespaces/{codespace_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_delete_repo_secret",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_delete_secret_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/codespaces/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_export_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/codespaces/{codespace_name}/exports",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_get_export_details_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/{codespace_name}/exports/{export_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_get_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/{codespace_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_get_public_key_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/secrets/public-key",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_get_repo_public_key",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codespaces/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::CodespacesPublicKey<'static>>),
+    },
+    Route {
+        operation: "codespaces_get_repo_secret",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_get_secret_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_list_in_repository_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codespaces",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_list_repo_secrets",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codespaces/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_list_repositories_for_secret_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_list_secrets_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/codespaces/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_remove_repository_for_secret_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_repo_machines_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codespaces/machines",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_set_repositories_for_secret_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/codespaces/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_start_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/codespaces/{codespace_name}/start",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_stop_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/codespaces/{codespace_name}/stop",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "codespaces_update_for_authenticated_user",
+        method: ::http::Method::PATCH,
+        path: "/user/codespaces/{codespace_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_add_selected_repo_to_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_create_or_update_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_create_or_update_repo_secret",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_delete_org_secret",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_delete_repo_secret",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_get_org_public_key",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/dependabot/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::DependabotPublicKey<'static>>),
+    },
+    Route {
+        operation: "dependabot_get_org_secret",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_get_repo_public_key",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/dependabot/secrets/public-key",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::DependabotPublicKey<'static>>),
+    },
+    Route {
+        operation: "dependabot_get_repo_secret",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_list_org_secrets",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/dependabot/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_list_repo_secrets",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/dependabot/secrets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_list_selected_repos_for_org_secret",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_remove_selected_repo_from_org_secret",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "dependabot_set_selected_repos_for_org_secret",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "dependency_graph_diff_range",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/dependency-graph/compare/{basehead}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "emojis_get",
+        method: ::http::Method::GET,
+        path: "/emojis",
+        status: 200,
+        example: Some(example::<::std::collections::HashMap<::std::borrow::Cow<'static, str>, ::std::borrow::Cow<'static, str>>>),
+    },
+    Route {
+        operation: "enterprise_admin_add_custom_labels_to_self_hosted_runner_for_enterprise",
+        method: ::http::Method::POST,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_add_org_access_to_self_hosted_runner_group_in_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_add_self_hosted_runner_to_group_for_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_create_registration_token_for_enterprise",
+        method: ::http::Method::POST,
+        path: "/enterprises/{enterprise}/actions/runners/registration-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_create_remove_token_for_enterprise",
+        method: ::http::Method::POST,
+        path: "/enterprises/{enterprise}/actions/runners/remove-token",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_create_self_hosted_runner_group_for_enterprise",
+        method: ::http::Method::POST,
+        path: "/enterprises/{enterprise}/actions/runner-groups",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_delete_scim_group_from_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_delete_self_hosted_runner_from_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_delete_self_hosted_runner_group_from_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_delete_user_from_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_disable_selected_organization_github_actions_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_enable_selected_organization_github_actions_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_allowed_actions_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_audit_log",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/audit-log",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_github_actions_permissions_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_provisioning_information_for_enterprise_group",
+        method: ::http::Method::GET,
+        path: "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_provisioning_information_for_enterprise_user",
+        method: ::http::Method::GET,
+        path: "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_self_hosted_runner_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_get_self_hosted_runner_group_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_labels_for_self_hosted_runner_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_org_access_to_self_hosted_runner_group_in_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_provisioned_groups_enterprise",
+        method: ::http::Method::GET,
+        path: "/scim/v2/enterprises/{enterprise}/Groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_provisioned_identities_enterprise",
+        method: ::http::Method::GET,
+        path: "/scim/v2/enterprises/{enterprise}/Users",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_runner_applications_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runners/downloads",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_selected_organizations_enabled_github_actions_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/permissions/organizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_self_hosted_runner_groups_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runner-groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_self_hosted_runners_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_list_self_hosted_runners_in_group_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_provision_and_invite_enterprise_group",
+        method: ::http::Method::POST,
+        path: "/scim/v2/enterprises/{enterprise}/Groups",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_provision_and_invite_enterprise_user",
+        method: ::http::Method::POST,
+        path: "/scim/v2/enterprises/{enterprise}/Users",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_remove_all_custom_labels_from_self_hosted_runner_for_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_remove_custom_label_from_self_hosted_runner_for_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}/labels/{name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_remove_org_access_to_self_hosted_runner_group_in_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_remove_self_hosted_runner_from_group_for_enterprise",
+        method: ::http::Method::DELETE,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_allowed_actions_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/permissions/selected-actions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_custom_labels_for_self_hosted_runner_for_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_github_actions_permissions_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/permissions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_information_for_provisioned_enterprise_group",
+        method: ::http::Method::PUT,
+        path: "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_information_for_provisioned_enterprise_user",
+        method: ::http::Method::PUT,
+        path: "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_org_access_to_self_hosted_runner_group_in_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_selected_organizations_enabled_github_actions_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/permissions/organizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_set_self_hosted_runners_in_group_for_enterprise",
+        method: ::http::Method::PUT,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_update_attribute_for_enterprise_group",
+        method: ::http::Method::PATCH,
+        path: "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_update_attribute_for_enterprise_user",
+        method: ::http::Method::PATCH,
+        path: "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "enterprise_admin_update_self_hosted_runner_group_for_enterprise",
+        method: ::http::Method::PATCH,
+        path: "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_check_is_starred",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/star",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_create",
+        method: ::http::Method::POST,
+        path: "/gists",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "gists_create_comment",
+        method: ::http::Method::POST,
+        path: "/gists/{gist_id}/comments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "gists_delete",
+        method: ::http::Method::DELETE,
+        path: "/gists/{gist_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "gists_delete_comment",
+        method: ::http::Method::DELETE,
+        path: "/gists/{gist_id}/comments/{comment_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "gists_fork",
+        method: ::http::Method::POST,
+        path: "/gists/{gist_id}/forks",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "gists_get",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_get_comment",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_get_revision",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/{sha}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list",
+        method: ::http::Method::GET,
+        path: "/gists",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_comments",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_commits",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/commits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/gists",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_forks",
+        method: ::http::Method::GET,
+        path: "/gists/{gist_id}/forks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_public",
+        method: ::http::Method::GET,
+        path: "/gists/public",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_list_starred",
+        method: ::http::Method::GET,
+        path: "/gists/starred",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_star",
+        method: ::http::Method::PUT,
+        path: "/gists/{gist_id}/star",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_unstar",
+        method: ::http::Method::DELETE,
+        path: "/gists/{gist_id}/star",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "gists_update",
+        method: ::http::Method::PATCH,
+        path: "/gists/{gist_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "gists_update_comment",
+        method: ::http::Method::PATCH,
+        path: "/gists/{gist_id}/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "git_create_blob",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/git/blobs",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::ShortBlob<'static>>),
+    },
+    Route {
+        operation: "git_create_commit",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/git/commits",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::GitCommit<'static>>),
+    },
+    Route {
+        operation: "git_create_ref",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/git/refs",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::GitRef<'static>>),
+    },
+    Route {
+        operation: "git_create_tag",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/git/tags",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "git_create_tree",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/git/trees",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::GitTree<'static>>),
+    },
+    Route {
+        operation: "git_delete_ref",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/git/refs/{ref}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "git_get_blob",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/blobs/{file_sha}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Blob<'static>>),
+    },
+    Route {
+        operation: "git_get_commit",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/commits/{commit_sha}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::GitCommit<'static>>),
+    },
+    Route {
+        operation: "git_get_ref",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/ref/{ref}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::GitRef<'static>>),
+    },
+    Route {
+        operation: "git_get_tag",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/tags/{tag_sha}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "git_get_tree",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/trees/{tree_sha}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::GitTree<'static>>),
+    },
+    Route {
+        operation: "git_list_matching_refs",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/git/matching-refs/{ref}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "git_update_ref",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/git/refs/{ref}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::GitRef<'static>>),
+    },
+    Route {
+        operation: "gitignore_get_all_templates",
+        method: ::http::Method::GET,
+        path: "/gitignore/templates",
+        status: 200,
+        example: Some(example_list::<::std::borrow::Cow<'static, str>>),
+    },
+    Route {
+        operation: "gitignore_get_template",
+        method: ::http::Method::GET,
+        path: "/gitignore/templates/{name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_get_restrictions_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_get_restrictions_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_get_restrictions_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_remove_restrictions_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/interaction-limits",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "interactions_remove_restrictions_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/interaction-limits",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "interactions_remove_restrictions_for_repo",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/interaction-limits",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "interactions_set_restrictions_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/user/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_set_restrictions_for_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "interactions_set_restrictions_for_repo",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/interaction-limits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_add_assignees",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "issues_add_labels",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Label<'static>>),
+    },
+    Route {
+        operation: "issues_check_user_can_be_assigned",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/assignees/{assignee}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_create",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::Issue<'static>>),
+    },
+    Route {
+        operation: "issues_create_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::IssueComment<'static>>),
+    },
+    Route {
+        operation: "issues_create_label",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/labels",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::Label<'static>>),
+    },
+    Route {
+        operation: "issues_create_milestone",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/milestones",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "issues_delete_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_delete_label",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/labels/{name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_delete_milestone",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_get",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Issue<'static>>),
+    },
+    Route {
+        operation: "issues_get_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_get_event",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/events/{event_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_get_label",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/labels/{name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_get_milestone",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list",
+        method: ::http::Method::GET,
+        path: "/issues",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_assignees",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/assignees",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "issues_list_comments",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_comments_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_events",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_events_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/events",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_events_for_timeline",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/timeline",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/issues",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/issues",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Issue<'static>>),
+    },
+    Route {
+        operation: "issues_list_labels_for_milestone",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/milestones/{milestone_number}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_labels_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/labels",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Label<'static>>),
+    },
+    Route {
+        operation: "issues_list_labels_on_issue",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_list_milestones",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/milestones",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_lock",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_remove_all_labels",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_remove_assignees",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_remove_label",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_set_labels",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_unlock",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "issues_update",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Issue<'static>>),
+    },
+    Route {
+        operation: "issues_update_comment",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_update_label",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/labels/{name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "issues_update_milestone",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "licenses_get",
+        method: ::http::Method::GET,
+        path: "/licenses/{license}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "licenses_get_all_commonly_used",
+        method: ::http::Method::GET,
+        path: "/licenses",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "licenses_get_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/license",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "markdown_render",
+        method: ::http::Method::POST,
+        path: "/markdown",
+        status: 200,
+        example: Some(example::<::std::string::String>),
+    },
+    Route {
+        operation: "markdown_render_raw",
+        method: ::http::Method::POST,
+        path: "/markdown/raw",
+        status: 200,
+        example: Some(example::<::std::string::String>),
+    },
+    Route {
+        operation: "meta_get",
+        method: ::http::Method::GET,
+        path: "/meta",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::ApiOverview<'static>>),
+    },
+    Route {
+        operation: "meta_get_octocat",
+        method: ::http::Method::GET,
+        path: "/octocat",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "meta_get_zen",
+        method: ::http::Method::GET,
+        path: "/zen",
+        status: 200,
+        example: Some(example::<::std::string::String>),
+    },
+    Route {
+        operation: "meta_root",
+        method: ::http::Method::GET,
+        path: "/",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_cancel_import",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/import",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "migrations_delete_archive_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/migrations/{migration_id}/archive",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "migrations_delete_archive_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/migrations/{migration_id}/archive",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "migrations_download_archive_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/migrations/{migration_id}/archive",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_archive_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/migrations/{migration_id}/archive",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_commit_authors",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/import/authors",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_import_status",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/import",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_large_files",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/import/large_files",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_status_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/migrations/{migration_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_get_status_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/migrations/{migration_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/migrations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_list_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/migrations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_list_repos_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/migrations/{migration_id}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_list_repos_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/migrations/{migration_id}/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_map_commit_author",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/import/authors/{author_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_set_lfs_preference",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/import/lfs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_start_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/migrations",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "migrations_start_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/migrations",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "migrations_start_import",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/import",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "migrations_unlock_repo_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/migrations/{migration_id}/repos/{repo_name}/lock",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "migrations_unlock_repo_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "migrations_update_import",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/import",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_create_authorization",
+        method: ::http::Method::POST,
+        path: "/authorizations",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_delete_authorization",
+        method: ::http::Method::DELETE,
+        path: "/authorizations/{authorization_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_delete_grant",
+        method: ::http::Method::DELETE,
+        path: "/applications/grants/{grant_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_get_authorization",
+        method: ::http::Method::GET,
+        path: "/authorizations/{authorization_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_get_grant",
+        method: ::http::Method::GET,
+        path: "/applications/grants/{grant_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_get_or_create_authorization_for_app",
+        method: ::http::Method::PUT,
+        path: "/authorizations/clients/{client_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_get_or_create_authorization_for_app_and_fingerprint",
+        method: ::http::Method::PUT,
+        path: "/authorizations/clients/{client_id}/{fingerprint}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_list_authorizations",
+        method: ::http::Method::GET,
+        path: "/authorizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_list_grants",
+        method: ::http::Method::GET,
+        path: "/applications/grants",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "oauth_authorizations_update_authorization",
+        method: ::http::Method::PATCH,
+        path: "/authorizations/{authorization_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_block_user",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/blocks/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_cancel_invitation",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/invitations/{invitation_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_check_blocked_user",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/blocks/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_check_membership_for_user",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/members/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_check_public_membership_for_user",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/public_members/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_convert_member_to_outside_collaborator",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/outside_collaborators/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_create_invitation",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/invitations",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "orgs_create_webhook",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/hooks",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "orgs_delete_webhook",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/hooks/{hook_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_audit_log",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/audit-log",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_membership_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/memberships/orgs/{org}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_membership_for_user",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_webhook",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/hooks/{hook_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_webhook_config_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/hooks/{hook_id}/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_get_webhook_delivery",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list",
+        method: ::http::Method::GET,
+        path: "/organizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_app_installations",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/installations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_blocked_users",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/blocks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_custom_roles",
+        method: ::http::Method::GET,
+        path: "/organizations/{organization_id}/custom_roles",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_failed_invitations",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/failed_invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/orgs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/orgs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_invitation_teams",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/invitations/{invitation_id}/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_members",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/members",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "orgs_list_memberships_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/memberships/orgs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_outside_collaborators",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/outside_collaborators",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_pending_invitations",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_public_members",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/public_members",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_saml_sso_authorizations",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/credential-authorizations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_webhook_deliveries",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/hooks/{hook_id}/deliveries",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_list_webhooks",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/hooks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_ping_webhook",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/hooks/{hook_id}/pings",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "orgs_redeliver_webhook_delivery",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "orgs_remove_member",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/members/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_remove_membership_for_user",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/memberships/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_remove_outside_collaborator",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/outside_collaborators/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_remove_public_membership_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/public_members/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_remove_saml_sso_authorization",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/credential-authorizations/{credential_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_set_membership_for_user",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_set_public_membership_for_authenticated_user",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/public_members/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_unblock_user",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/blocks/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "orgs_update",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_update_membership_for_authenticated_user",
+        method: ::http::Method::PATCH,
+        path: "/user/memberships/orgs/{org}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_update_webhook",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/hooks/{hook_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "orgs_update_webhook_config_for_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/hooks/{hook_id}/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/packages/{package_type}/{package_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_for_user",
+        method: ::http::Method::DELETE,
+        path: "/users/{username}/packages/{package_type}/{package_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_version_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_version_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_delete_package_version_for_user",
+        method: ::http::Method::DELETE,
+        path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_all_package_versions_for_package_owned_by_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/packages/{package_type}/{package_name}/versions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_all_package_versions_for_package_owned_by_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}/versions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_all_package_versions_for_package_owned_by_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/packages/{package_type}/{package_name}/versions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/packages/{package_type}/{package_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_for_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/packages/{package_type}/{package_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_version_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_version_for_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_get_package_version_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_list_packages_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_list_packages_for_organization",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_list_packages_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/packages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/packages/{package_type}/{package_name}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_for_user",
+        method: ::http::Method::POST,
+        path: "/users/{username}/packages/{package_type}/{package_name}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_version_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_version_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "packages_restore_package_version_for_user",
+        method: ::http::Method::POST,
+        path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_add_collaborator",
+        method: ::http::Method::PUT,
+        path: "/projects/{project_id}/collaborators/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_create_card",
+        method: ::http::Method::POST,
+        path: "/projects/columns/{column_id}/cards",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_create_column",
+        method: ::http::Method::POST,
+        path: "/projects/{project_id}/columns",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_create_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/projects",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_create_for_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/projects",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_create_for_repo",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/projects",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_delete",
+        method: ::http::Method::DELETE,
+        path: "/projects/{project_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "projects_delete_card",
+        method: ::http::Method::DELETE,
+        path: "/projects/columns/cards/{card_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "projects_delete_column",
+        method: ::http::Method::DELETE,
+        path: "/projects/columns/{column_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "projects_get",
+        method: ::http::Method::GET,
+        path: "/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_get_card",
+        method: ::http::Method::GET,
+        path: "/projects/columns/cards/{card_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_get_column",
+        method: ::http::Method::GET,
+        path: "/projects/columns/{column_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_get_permission_for_user",
+        method: ::http::Method::GET,
+        path: "/projects/{project_id}/collaborators/{username}/permission",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_cards",
+        method: ::http::Method::GET,
+        path: "/projects/columns/{column_id}/cards",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_collaborators",
+        method: ::http::Method::GET,
+        path: "/projects/{project_id}/collaborators",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_columns",
+        method: ::http::Method::GET,
+        path: "/projects/{project_id}/columns",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/projects",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/projects",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_list_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/projects",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_move_card",
+        method: ::http::Method::POST,
+        path: "/projects/columns/cards/{card_id}/moves",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_move_column",
+        method: ::http::Method::POST,
+        path: "/projects/columns/{column_id}/moves",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "projects_remove_collaborator",
+        method: ::http::Method::DELETE,
+        path: "/projects/{project_id}/collaborators/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "projects_update",
+        method: ::http::Method::PATCH,
+        path: "/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_update_card",
+        method: ::http::Method::PATCH,
+        path: "/projects/columns/cards/{card_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "projects_update_column",
+        method: ::http::Method::PATCH,
+        path: "/projects/columns/{column_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_check_if_merged",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_create",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::PullRequest<'static>>),
+    },
+    Route {
+        operation: "pulls_create_reply_for_review_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "pulls_create_review",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "pulls_create_review_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "pulls_delete_pending_review",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "pulls_delete_review_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "pulls_dismiss_review",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_get",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::PullRequest<'static>>),
+    },
+    Route {
+        operation: "pulls_get_review",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_get_review_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::PullRequestSimple<'static>>),
+    },
+    Route {
+        operation: "pulls_list_comments_for_review",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_commits",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/commits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_files",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/files",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_requested_reviewers",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_review_comments",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_review_comments_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_list_reviews",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_merge",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_remove_requested_reviewers",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "pulls_request_reviewers",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "pulls_submit_review",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "pulls_update",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::PullRequest<'static>>),
+    },
+    Route {
+        operation: "pulls_update_branch",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/update-branch",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_update_review",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "pulls_update_review_comment",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "rate_limit_get",
+        method: ::http::Method::GET,
+        path: "/rate_limit",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::RateLimitOverview<'static>>),
+    },
+    Route {
+        operation: "reactions_create_for_commit_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_issue",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_issue_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_pull_request_review_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_release",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/releases/{release_id}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_team_discussion_comment_in_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_team_discussion_comment_legacy",
+        method: ::http::Method::POST,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_team_discussion_in_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_create_for_team_discussion_legacy",
+        method: ::http::Method::POST,
+        path: "/teams/{team_id}/discussions/{discussion_number}/reactions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_commit_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_issue",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_issue_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_pull_request_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_release",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/releases/{release_id}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_team_discussion",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_delete_for_team_discussion_comment",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_commit_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_issue",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_issue_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_pull_request_review_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_release",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/{release_id}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_team_discussion_comment_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_team_discussion_comment_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_team_discussion_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "reactions_list_for_team_discussion_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions/{discussion_number}/reactions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_accept_invitation_for_authenticated_user",
+        method: ::http::Method::PATCH,
+        path: "/user/repository_invitations/{invitation_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_add_app_access_restrictions",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_add_collaborator",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/collaborators/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_add_status_check_contexts",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_add_team_access_restrictions",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_add_user_access_restrictions",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_check_collaborator",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/collaborators/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_check_vulnerability_alerts",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/vulnerability-alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_codeowners_errors",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/codeowners/errors",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_compare_commits",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/compare/{basehead}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_autolink",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/autolinks",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_commit_comment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_commit_signature_protection",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_commit_status",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/statuses/{sha}",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_deploy_key",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/keys",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_deployment",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/deployments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_deployment_status",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_dispatch_event",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/dispatches",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/repos",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::Repository<'static>>),
+    },
+    Route {
+        operation: "repos_create_fork",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/forks",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_in_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/repos",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::Repository<'static>>),
+    },
+    Route {
+        operation: "repos_create_or_update_environment",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/environments/{environment_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_or_update_file_contents",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/contents/{path}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::FileCommit<'static>>),
+    },
+    Route {
+        operation: "repos_create_pages_site",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pages",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_release",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/releases",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::Release<'static>>),
+    },
+    Route {
+        operation: "repos_create_tag_protection",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/tags/protection",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_using_template",
+        method: ::http::Method::POST,
+        path: "/repos/{template_owner}/{template_repo}/generate",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_create_webhook",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/hooks",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_decline_invitation_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/repository_invitations/{invitation_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_access_restrictions",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_admin_branch_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_an_environment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/environments/{environment_name}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_autolink",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_branch_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_commit_comment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_commit_signature_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_deploy_key",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/keys/{key_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_deployment",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/deployments/{deployment_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_file",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/contents/{path}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::FileCommit<'static>>),
+    },
+    Route {
+        operation: "repos_delete_invitation",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/invitations/{invitation_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_pages_site",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/pages",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_pull_request_review_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_release",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/releases/{release_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_release_asset",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_tag_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/tags/protection/{tag_protection_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_delete_webhook",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_disable_automated_security_fixes",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/automated-security-fixes",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_disable_lfs_for_repo",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/lfs",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_disable_vulnerability_alerts",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/vulnerability-alerts",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_download_tarball_archive",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/tarball/{ref}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_download_zipball_archive",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/zipball/{ref}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_enable_automated_security_fixes",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/automated-security-fixes",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_enable_lfs_for_repo",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/lfs",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_enable_vulnerability_alerts",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/vulnerability-alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_generate_release_notes",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/releases/generate-notes",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_get",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_access_restrictions",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_admin_branch_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_all_environments",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/environments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_all_status_check_contexts",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_all_topics",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/topics",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_apps_with_access_to_protected_branch",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_autolink",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_branch",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_branch_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_clones",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/traffic/clones",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_code_frequency_stats",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stats/code_frequency",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_collaborator_permission_level",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/collaborators/{username}/permission",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_combined_status_for_ref",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{ref}/status",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_commit",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{ref}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_commit_activity_stats",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stats/commit_activity",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_commit_comment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_commit_signature_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_community_profile_metrics",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/community/profile",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_content",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/contents/{path}",
+        status: 200,
+        example: Some(example::<::serde_json::value::Value>),
+    },
+    Route {
+        operation: "repos_get_contributors_stats",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stats/contributors",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_deploy_key",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/keys/{key_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_deployment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/deployments/{deployment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_deployment_status",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_environment",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/environments/{environment_name}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_latest_pages_build",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pages/builds/latest",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_latest_release",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/latest",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_pages",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_pages_build",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pages/builds/{build_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_pages_health_check",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pages/health",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_participation_stats",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stats/participation",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_pull_request_review_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_punch_card_stats",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/stats/punch_card",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_readme",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/readme",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_readme_in_directory",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/readme/{dir}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_release",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/{release_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::Release<'static>>),
+    },
+    Route {
+        operation: "repos_get_release_asset",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+        status: 200,
+        example: Some(example::<crate::v1_1_4::schema::ReleaseAsset<'static>>),
+    },
+    Route {
+        operation: "repos_get_release_by_tag",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/tags/{tag}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_status_checks_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_teams_with_access_to_protected_branch",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_top_paths",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/traffic/popular/paths",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_top_referrers",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/traffic/popular/referrers",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_users_with_access_to_protected_branch",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_views",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/traffic/views",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_webhook",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_webhook_config_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_get_webhook_delivery",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_autolinks",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/autolinks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_branches",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/branches",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_branches_for_head_commit",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_collaborators",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/collaborators",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_comments_for_commit",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_commit_comments_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_commit_statuses_for_ref",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{ref}/statuses",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_commits",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_contributors",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/contributors",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_deploy_keys",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/keys",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_deployment_statuses",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_deployments",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/deployments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/repos",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Repository<'static>>),
+    },
+    Route {
+        operation: "repos_list_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/repos",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::MinimalRepository<'static>>),
+    },
+    Route {
+        operation: "repos_list_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/repos",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::MinimalRepository<'static>>),
+    },
+    Route {
+        operation: "repos_list_forks",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/forks",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::MinimalRepository<'static>>),
+    },
+    Route {
+        operation: "repos_list_invitations",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_invitations_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/repository_invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_languages",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/languages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_pages_builds",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/pages/builds",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_public",
+        method: ::http::Method::GET,
+        path: "/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_pull_requests_associated_with_commit",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/commits/{commit_sha}/pulls",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_release_assets",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases/{release_id}/assets",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_releases",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/releases",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::Release<'static>>),
+    },
+    Route {
+        operation: "repos_list_tag_protection",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/tags/protection",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_tags",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/tags",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_teams",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_webhook_deliveries",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_list_webhooks",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/hooks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_merge",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/merges",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_merge_upstream",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/merge-upstream",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_ping_webhook",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/pings",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_redeliver_webhook_delivery",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_app_access_restrictions",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_collaborator",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/collaborators/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_status_check_contexts",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_status_check_protection",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_team_access_restrictions",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_remove_user_access_restrictions",
+        method: ::http::Method::DELETE,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "repos_rename_branch",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/rename",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_replace_all_topics",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/topics",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_request_pages_build",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/pages/builds",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_set_admin_branch_protection",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_set_app_access_restrictions",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_set_status_check_contexts",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_set_team_access_restrictions",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_set_user_access_restrictions",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_test_push_webhook",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/tests",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_transfer",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/transfer",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "repos_update",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_branch_protection",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_commit_comment",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/comments/{comment_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_information_about_pages_site",
+        method: ::http::Method::PUT,
+        path: "/repos/{owner}/{repo}/pages",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_invitation",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/invitations/{invitation_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_pull_request_review_protection",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_release",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/releases/{release_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_release_asset",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_status_check_protection",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_webhook",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_update_webhook_config_for_repo",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "repos_upload_release_asset",
+        method: ::http::Method::POST,
+        path: "/repos/{owner}/{repo}/releases/{release_id}/assets",
+        status: 201,
+        example: Some(example::<crate::v1_1_4::schema::ReleaseAsset<'static>>),
+    },
+    Route {
+        operation: "scim_delete_user_from_org",
+        method: ::http::Method::DELETE,
+        path: "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "scim_get_provisioning_information_for_user",
+        method: ::http::Method::GET,
+        path: "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "scim_list_provisioned_identities",
+        method: ::http::Method::GET,
+        path: "/scim/v2/organizations/{org}/Users",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "scim_provision_and_invite_user",
+        method: ::http::Method::POST,
+        path: "/scim/v2/organizations/{org}/Users",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "scim_set_information_for_provisioned_user",
+        method: ::http::Method::PUT,
+        path: "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "scim_update_attribute_for_user",
+        method: ::http::Method::PATCH,
+        path: "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_code",
+        method: ::http::Method::GET,
+        path: "/search/code",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_commits",
+        method: ::http::Method::GET,
+        path: "/search/commits",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_issues_and_pull_requests",
+        method: ::http::Method::GET,
+        path: "/search/issues",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_labels",
+        method: ::http::Method::GET,
+        path: "/search/labels",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_repos",
+        method: ::http::Method::GET,
+        path: "/search/repositories",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_topics",
+        method: ::http::Method::GET,
+        path: "/search/topics",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "search_users",
+        method: ::http::Method::GET,
+        path: "/search/users",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_get_alert",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_list_alerts_for_enterprise",
+        method: ::http::Method::GET,
+        path: "/enterprises/{enterprise}/secret-scanning/alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_list_alerts_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/secret-scanning/alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_list_alerts_for_repo",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/secret-scanning/alerts",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_list_locations_for_alert",
+        method: ::http::Method::GET,
+        path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}/locations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "secret_scanning_update_alert",
+        method: ::http::Method::PATCH,
+        path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_member_legacy",
+        method: ::http::Method::PUT,
+        path: "/teams/{team_id}/members/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_membership_for_user_in_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_membership_for_user_legacy",
+        method: ::http::Method::PUT,
+        path: "/teams/{team_id}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_project_permissions_in_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_project_permissions_legacy",
+        method: ::http::Method::PUT,
+        path: "/teams/{team_id}/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_repo_permissions_in_org",
+        method: ::http::Method::PUT,
+        path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_add_or_update_repo_permissions_legacy",
+        method: ::http::Method::PUT,
+        path: "/teams/{team_id}/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_check_permissions_for_project_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_check_permissions_for_project_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/projects/{project_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_check_permissions_for_repo_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_check_permissions_for_repo_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/repos/{owner}/{repo}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_create",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/teams",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_discussion_comment_in_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_discussion_comment_legacy",
+        method: ::http::Method::POST,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_discussion_in_org",
+        method: ::http::Method::POST,
+        path: "/orgs/{org}/teams/{team_slug}/discussions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_discussion_legacy",
+        method: ::http::Method::POST,
+        path: "/teams/{team_id}/discussions",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_or_update_idp_group_connections_in_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_create_or_update_idp_group_connections_legacy",
+        method: ::http::Method::PATCH,
+        path: "/teams/{team_id}/team-sync/group-mappings",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_discussion_comment_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_discussion_comment_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_discussion_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_discussion_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/discussions/{discussion_number}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_delete_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_external_idp_group_info_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/external-group/{group_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_by_name",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_discussion_comment_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_discussion_comment_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_discussion_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_discussion_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions/{discussion_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_member_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/members/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_membership_for_user_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_get_membership_for_user_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/memberships/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_link_external_idp_group_to_team_for_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/teams/{team_slug}/external-groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_child_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_child_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_discussion_comments_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_discussion_comments_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_discussions_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/discussions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_discussions_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/discussions",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_external_idp_groups_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/external-groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/teams",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_idp_groups_for_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/team-sync/group-mappings",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_idp_groups_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/team-sync/groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_idp_groups_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_linked_external_idp_groups_to_team_for_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/external-groups",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_members_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/members",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_members_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/members",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_pending_invitations_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_pending_invitations_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/invitations",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_projects_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/projects",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_projects_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/projects",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_repos_in_org",
+        method: ::http::Method::GET,
+        path: "/orgs/{org}/teams/{team_slug}/repos",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_list_repos_legacy",
+        method: ::http::Method::GET,
+        path: "/teams/{team_id}/repos",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_member_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/members/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_membership_for_user_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_membership_for_user_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/memberships/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_project_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_project_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/projects/{project_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_repo_in_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_remove_repo_legacy",
+        method: ::http::Method::DELETE,
+        path: "/teams/{team_id}/repos/{owner}/{repo}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_unlink_external_idp_group_from_team_for_org",
+        method: ::http::Method::DELETE,
+        path: "/orgs/{org}/teams/{team_slug}/external-groups",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_discussion_comment_in_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_discussion_comment_legacy",
+        method: ::http::Method::PATCH,
+        path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_discussion_in_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_discussion_legacy",
+        method: ::http::Method::PATCH,
+        path: "/teams/{team_id}/discussions/{discussion_number}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_in_org",
+        method: ::http::Method::PATCH,
+        path: "/orgs/{org}/teams/{team_slug}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "teams_update_legacy",
+        method: ::http::Method::PATCH,
+        path: "/teams/{team_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_add_email_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/emails",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "users_block",
+        method: ::http::Method::PUT,
+        path: "/user/blocks/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_check_blocked",
+        method: ::http::Method::GET,
+        path: "/user/blocks/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_check_following_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/following/{target_user}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_check_person_is_followed_by_authenticated",
+        method: ::http::Method::GET,
+        path: "/user/following/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_create_gpg_key_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/gpg_keys",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "users_create_public_ssh_key_for_authenticated_user",
+        method: ::http::Method::POST,
+        path: "/user/keys",
+        status: 201,
+        example: None,
+    },
+    Route {
+        operation: "users_delete_email_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/emails",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "users_delete_gpg_key_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/gpg_keys/{gpg_key_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "users_delete_public_ssh_key_for_authenticated_user",
+        method: ::http::Method::DELETE,
+        path: "/user/keys/{key_id}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "users_follow",
+        method: ::http::Method::PUT,
+        path: "/user/following/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_get_authenticated",
+        method: ::http::Method::GET,
+        path: "/user",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_get_by_username",
+        method: ::http::Method::GET,
+        path: "/users/{username}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_get_context_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/hovercard",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_get_gpg_key_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/gpg_keys/{gpg_key_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_get_public_ssh_key_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/keys/{key_id}",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list",
+        method: ::http::Method::GET,
+        path: "/users",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "users_list_blocked_by_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/blocks",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_emails_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/emails",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_followed_by_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/following",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_followers_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/followers",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "users_list_followers_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/followers",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "users_list_following_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/following",
+        status: 200,
+        example: Some(example_list::<crate::v1_1_4::schema::SimpleUser<'static>>),
+    },
+    Route {
+        operation: "users_list_gpg_keys_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/gpg_keys",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_gpg_keys_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/gpg_keys",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_public_emails_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/public_emails",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_public_keys_for_user",
+        method: ::http::Method::GET,
+        path: "/users/{username}/keys",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_list_public_ssh_keys_for_authenticated_user",
+        method: ::http::Method::GET,
+        path: "/user/keys",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_set_primary_email_visibility_for_authenticated_user",
+        method: ::http::Method::PATCH,
+        path: "/user/email/visibility",
+        status: 200,
+        example: None,
+    },
+    Route {
+        operation: "users_unblock",
+        method: ::http::Method::DELETE,
+        path: "/user/blocks/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "users_unfollow",
+        method: ::http::Method::DELETE,
+        path: "/user/following/{username}",
+        status: 204,
+        example: None,
+    },
+    Route {
+        operation: "users_update_authenticated",
+        method: ::http::Method::PATCH,
+        path: "/user",
+        status: 200,
+        example: None,
+    },
+];
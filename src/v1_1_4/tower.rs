@@ -0,0 +1,401 @@
+//! Integration with `tower` middleware.
+//!
+//! [`Caller::from_service`] creates a `Caller` sending requests through any
+//! `tower::Service`, such as a `hyper::Client` or a stack of middleware built using
+//! `ServiceBuilder`. [`TransportService`] turns any [`Transport`] into a `Service`, to
+//! use at the bottom of a stack.
+//!
+//! The layers in this module handle the GitHub-specific parts of the request path:
+//! - [`DefaultHeadersLayer`] adds the `User-Agent` and `Accept` headers from a
+//!   `Configuration`, if a request has none.
+//! - [`AuthenticationLayer`] authenticates each request using an [`AuthScheme`].
+//! - [`RateLimitLayer`] waits for and retries rate limited requests.
+//!
+//! ```ignore
+//! let service = ServiceBuilder::new()
+//!     .layer(AuthenticationLayer::new(config, tokio::time::sleep))
+//!     .layer(RateLimitLayer::new(RateLimitPolicy::default(), tokio::time::sleep))
+//!     .timeout(Duration::from_secs(30))
+//!     .service(TransportService::new(hyper::Client::new()));
+//! let github = Caller::from_service(service, Configuration::default(), tokio::time::sleep);
+//! ```
+//!
+//! When the layers authenticate requests and handle rate limits, the `Caller` should use
+//! `Authentication::None` and no rate limit policy, so that they are not applied twice.
+
+use ::std::future::Future;
+use ::std::marker::PhantomData;
+use ::std::sync::Arc;
+use ::std::task::{Context, Poll};
+use ::std::time::Duration;
+
+use ::authentic::{AuthenticationProtocol, AuthenticationStep};
+use ::futures_util::future::{BoxFuture, FutureExt, TryFutureExt};
+use ::http::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use ::http::Request;
+use ::tower::{BoxError, Layer, Service, ServiceExt};
+
+use crate::v1_1_4::caller::{AuthScheme, Caller, WithAuthentication};
+use crate::v1_1_4::config::Configuration;
+use crate::v1_1_4::rate_limit::{self, RateLimitPolicy, RateLimitState, RateLimitTracker};
+use crate::v1_1_4::transport::{Transport, TransportResponse};
+use crate::v1_1_4::ApiError;
+
+/// Convert a service error, unwrapping errors returned by the services in this module.
+fn service_error<E: Into<BoxError>>(err: E) -> ApiError {
+    match err.into().downcast::<ApiError>() {
+        Ok(err) => *err,
+        Err(source) => ApiError::Service { source },
+    }
+}
+
+/// [`Transport`] sending requests using a `tower::Service`.
+///
+/// The body of each request is converted from `Vec<u8>` to the body type `B` of the service.
+pub struct ServiceTransport<S, B = Vec<u8>> {
+    service: S,
+    body: PhantomData<fn() -> B>,
+}
+
+impl<S, B> ServiceTransport<S, B> {
+    pub fn new(service: S) -> Self {
+        ServiceTransport {
+            service,
+            body: PhantomData,
+        }
+    }
+}
+
+impl<S: Clone, B> Clone for ServiceTransport<S, B> {
+    fn clone(&self) -> Self {
+        ServiceTransport::new(self.service.clone())
+    }
+}
+
+impl<S, B> Transport for ServiceTransport<S, B>
+where
+    S: Service<Request<B>> + Clone + Send + 'static,
+    S::Response: TransportResponse,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: From<Vec<u8>> + Send + 'static,
+{
+    type Response = S::Response;
+    type Future = BoxFuture<'static, Result<S::Response, ApiError>>;
+
+    fn send(&self, request: ::http::Request<Vec<u8>>) -> Self::Future {
+        self.service
+            .clone()
+            .oneshot(request.map(B::from))
+            .map_err(service_error)
+            .boxed()
+    }
+}
+
+impl<S, B, Sleep, SleepFut, SleepOut> Caller<ServiceTransport<S, B>, Sleep, SleepFut, SleepOut>
+where
+    ServiceTransport<S, B>: Transport,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = SleepOut>
+{
+    /// Create a `Caller` sending requests using `service`.
+    pub fn from_service(
+        service: S,
+        config: Configuration,
+        sleep: Sleep,
+    ) -> Caller<ServiceTransport<S, B>, Sleep, SleepFut, SleepOut> {
+        Caller::new(ServiceTransport::new(service), config, sleep)
+    }
+}
+
+/// `tower::Service` sending requests using a [`Transport`], e.g. a `hyper` or `reqwest`
+/// client.
+#[derive(Clone, Debug)]
+pub struct TransportService<T> {
+    transport: T,
+}
+
+impl<T> TransportService<T> {
+    pub fn new(transport: T) -> Self {
+        TransportService { transport }
+    }
+}
+
+impl<T: Transport> Service<Request<Vec<u8>>> for TransportService<T> {
+    type Response = T::Response;
+    type Error = ApiError;
+    type Future = T::Future;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Vec<u8>>) -> Self::Future {
+        self.transport.send(request)
+    }
+}
+
+/// Layer adding the `User-Agent` and `Accept` headers of a `Configuration` to requests
+/// without them.
+#[derive(Clone, Debug)]
+pub struct DefaultHeadersLayer {
+    headers: HeaderMap,
+}
+
+impl DefaultHeadersLayer {
+    pub fn new(config: &Configuration) -> Result<Self, ApiError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_str(&config.user_agent)?);
+        if let Some(accept) = &config.accept {
+            headers.insert(ACCEPT, HeaderValue::from_str(accept)?);
+        }
+        Ok(DefaultHeadersLayer { headers })
+    }
+}
+
+impl<S> Layer<S> for DefaultHeadersLayer {
+    type Service = DefaultHeaders<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DefaultHeaders {
+            inner,
+            headers: self.headers.clone(),
+        }
+    }
+}
+
+/// Service created by [`DefaultHeadersLayer`].
+#[derive(Clone, Debug)]
+pub struct DefaultHeaders<S> {
+    inner: S,
+    headers: HeaderMap,
+}
+
+impl<S, B> Service<Request<B>> for DefaultHeaders<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        for (name, value) in &self.headers {
+            if !request.headers().contains_key(name) {
+                request.headers_mut().insert(name.clone(), value.clone());
+            }
+        }
+        self.inner.call(request)
+    }
+}
+
+/// Layer authenticating requests using the `authentication` of a `Configuration`.
+///
+/// Requests to renew an installation access token are sent using the inner service.
+#[derive(Clone)]
+pub struct AuthenticationLayer<Sleep> {
+    config: Arc<Configuration>,
+    sleep: Sleep,
+}
+
+impl<Sleep> AuthenticationLayer<Sleep> {
+    pub fn new(config: Configuration, sleep: Sleep) -> Self {
+        AuthenticationLayer {
+            config: Arc::new(config),
+            sleep,
+        }
+    }
+}
+
+impl<S, Sleep: Clone> Layer<S> for AuthenticationLayer<Sleep> {
+    type Service = Authenticate<S, Sleep>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Authenticate {
+            inner,
+            config: self.config.clone(),
+            sleep: self.sleep.clone(),
+        }
+    }
+}
+
+/// Service created by [`AuthenticationLayer`].
+#[derive(Clone)]
+pub struct Authenticate<S, Sleep> {
+    inner: S,
+    config: Arc<Configuration>,
+    sleep: Sleep,
+}
+
+impl<S, B, Sleep, SleepFut> Service<Request<B>> for Authenticate<S, Sleep>
+where
+    S: Service<Request<B>> + Clone + Send + 'static,
+    S::Response: TransportResponse + Send,
+    <S::Response as TransportResponse>::Bytes: Send,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: From<Vec<u8>> + Send + 'static,
+    Sleep: Fn(Duration) -> SleepFut + Clone + Send + 'static,
+    SleepFut: Future + Send,
+{
+    type Response = S::Response;
+    type Error = ApiError;
+    type Future = BoxFuture<'static, Result<S::Response, ApiError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(service_error)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        // Use the service that was polled ready, leaving a clone for the next request.
+        let clone = self.inner.clone();
+        let mut inner = ::std::mem::replace(&mut self.inner, clone);
+        let config = self.config.clone();
+        let sleep = self.sleep.clone();
+        async move {
+            let mut scheme = AuthScheme::from(config.as_ref());
+            while let Some(step) = scheme.step()? {
+                match step {
+                    AuthenticationStep::Request(auth_request) => {
+                        let auth_response = (&mut inner)
+                            .oneshot(auth_request.map(B::from))
+                            .await
+                            .map_err(service_error);
+                        let auth_response = match auth_response {
+                            Ok(auth_response) => auth_response.into_bytes().await,
+                            Err(err) => Err(err),
+                        };
+                        scheme.respond(auth_response);
+                    }
+                    AuthenticationStep::WaitFor(duration) => {
+                        sleep(duration).await;
+                    }
+                }
+            }
+            let builder = ::http::Request::builder().with_authentication(&scheme)?;
+            if let Some(headers) = builder.headers_ref() {
+                for (name, value) in headers {
+                    request.headers_mut().insert(name.clone(), value.clone());
+                }
+            }
+            inner.oneshot(request).await.map_err(service_error)
+        }
+        .boxed()
+    }
+}
+
+/// Layer waiting for and retrying rate limited requests, according to a
+/// [`RateLimitPolicy`].
+#[derive(Clone)]
+pub struct RateLimitLayer<Sleep> {
+    policy: RateLimitPolicy,
+    sleep: Sleep,
+    tracker: Arc<RateLimitTracker>,
+}
+
+impl<Sleep> RateLimitLayer<Sleep> {
+    pub fn new(policy: RateLimitPolicy, sleep: Sleep) -> Self {
+        RateLimitLayer {
+            policy,
+            sleep,
+            tracker: Arc::default(),
+        }
+    }
+
+    /// The rate limit reported in the most recent response through any service created by
+    /// this layer, if any.
+    pub fn rate_limit(&self) -> Option<RateLimitState> {
+        self.tracker.latest()
+    }
+}
+
+impl<S, Sleep: Clone> Layer<S> for RateLimitLayer<Sleep> {
+    type Service = RateLimit<S, Sleep>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            policy: self.policy.clone(),
+            sleep: self.sleep.clone(),
+            tracker: self.tracker.clone(),
+        }
+    }
+}
+
+/// Service created by [`RateLimitLayer`].
+#[derive(Clone)]
+pub struct RateLimit<S, Sleep> {
+    inner: S,
+    policy: RateLimitPolicy,
+    sleep: Sleep,
+    tracker: Arc<RateLimitTracker>,
+}
+
+impl<S, B, Sleep, SleepFut> Service<Request<B>> for RateLimit<S, Sleep>
+where
+    S: Service<Request<B>> + Clone + Send + 'static,
+    S::Response: TransportResponse + Send,
+    <S::Response as TransportResponse>::Bytes: Send,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: Clone + Send + 'static,
+    Sleep: Fn(Duration) -> SleepFut + Clone + Send + 'static,
+    SleepFut: Future + Send,
+{
+    type Response = S::Response;
+    type Error = ApiError;
+    type Future = BoxFuture<'static, Result<S::Response, ApiError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(service_error)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        // Use the service that was polled ready, leaving a clone for the next request.
+        let clone = self.inner.clone();
+        let mut inner = ::std::mem::replace(&mut self.inner, clone);
+        let policy = self.policy.clone();
+        let sleep = self.sleep.clone();
+        let tracker = self.tracker.clone();
+        async move {
+            let (parts, body) = request.into_parts();
+            let mut attempt = 0;
+            loop {
+                let mut request = Request::new(body.clone());
+                *request.method_mut() = parts.method.clone();
+                *request.uri_mut() = parts.uri.clone();
+                *request.version_mut() = parts.version;
+                *request.headers_mut() = parts.headers.clone();
+
+                let response = (&mut inner).oneshot(request).await.map_err(service_error)?;
+                tracker.observe(response.headers());
+                let (response, limited) = match rate_limit::check(response.status(), response.headers()) {
+                    rate_limit::Check::Ok => return Ok(response),
+                    rate_limit::Check::Limited(limited) => (response, limited),
+                    rate_limit::Check::Body => {
+                        let bytes = response.into_bytes().await?;
+                        let secondary = rate_limit::is_secondary_message(bytes.body());
+                        let response = S::Response::from_bytes(bytes)?;
+                        if !secondary {
+                            return Ok(response);
+                        }
+                        (response, rate_limit::Limited::Secondary)
+                    }
+                };
+                match policy.retry_wait(attempt, limited) {
+                    Some(duration) => {
+                        ::log::info!("Rate limited, retrying in {:?}", duration);
+                        sleep(duration).await;
+                        attempt += 1;
+                    }
+                    None => return Ok(response),
+                }
+            }
+        }
+        .boxed()
+    }
+}
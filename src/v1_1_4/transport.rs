@@ -0,0 +1,172 @@
+//! HTTP clients used by a [`Caller`](crate::v1_1_4::caller::Caller).
+//!
+//! A [`Transport`] sends a request, with its body already in memory, and returns a response
+//! implementing [`TransportResponse`]. The `hyper` and `reqwest` clients implement
+//! `Transport` when the corresponding feature is enabled. Another client, or a test double,
+//! can be used by implementing `Transport` for it:
+//!
+//! ```ignore
+//! struct Canned;
+//!
+//! impl Transport for Canned {
+//!     type Response = http::Response<Vec<u8>>;
+//!     type Future = std::future::Ready<Result<Self::Response, ApiError>>;
+//!
+//!     fn send(&self, request: http::Request<Vec<u8>>) -> Self::Future {
+//!         std::future::ready(Ok(http::Response::new(b"{}".to_vec())))
+//!     }
+//! }
+//!
+//! let github = Caller::new(Canned, Configuration::default(), tokio::time::sleep);
+//! ```
+//!
+//! A blocking client returns futures that are always ready. Outside of the blocking
+//! `reqwest` caller, they can be completed using any executor, e.g.
+//! `futures_executor::block_on`.
+
+use ::std::future::{ready, Future, Ready};
+#[cfg(any(feature = "hyper", feature = "reqwest-async"))]
+use ::std::pin::Pin;
+#[cfg(any(feature = "hyper", feature = "reqwest-async"))]
+use ::std::task::{Context, Poll};
+
+use ::http::{HeaderMap, StatusCode};
+
+use crate::v1_1_4::ApiError;
+
+/// An HTTP client.
+pub trait Transport {
+    /// Response returned to callers, e.g. `hyper::Response<hyper::Body>`.
+    type Response: TransportResponse;
+    type Future: Future<Output = Result<Self::Response, ApiError>>;
+
+    /// Send a request.
+    fn send(&self, request: ::http::Request<Vec<u8>>) -> Self::Future;
+}
+
+/// A response returned by a [`Transport`].
+pub trait TransportResponse: Sized {
+    type Bytes: Future<Output = Result<::http::Response<Vec<u8>>, ApiError>>;
+
+    fn status(&self) -> StatusCode;
+
+    fn headers(&self) -> &HeaderMap;
+
+    /// Read the body of the response into memory.
+    fn into_bytes(self) -> Self::Bytes;
+
+    /// Rebuild a response from a response returned by [`TransportResponse::into_bytes`].
+    fn from_bytes(response: ::http::Response<Vec<u8>>) -> Result<Self, ApiError>;
+
+    /// URI of the request answered by the response, if known.
+    ///
+    /// Used to resolve a relative `Location` header.
+    fn request_uri(&self) -> Option<::http::Uri> {
+        None
+    }
+}
+
+/// Extension of an `http::Response`, recording the URI of the request it answers.
+///
+/// Returned by [`TransportResponse::request_uri`] for `http` and `hyper` responses.
+#[derive(Clone, Debug)]
+pub struct RequestUri(pub ::http::Uri);
+
+impl TransportResponse for ::http::Response<Vec<u8>> {
+    type Bytes = Ready<Result<::http::Response<Vec<u8>>, ApiError>>;
+
+    fn status(&self) -> StatusCode {
+        self.status()
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        self.headers()
+    }
+
+    fn into_bytes(self) -> Self::Bytes {
+        ready(Ok(self))
+    }
+
+    fn from_bytes(response: ::http::Response<Vec<u8>>) -> Result<Self, ApiError> {
+        Ok(response)
+    }
+
+    fn request_uri(&self) -> Option<::http::Uri> {
+        self.extensions().get::<RequestUri>().map(|uri| uri.0.clone())
+    }
+}
+
+/// A response whose body can be read a chunk at a time, without reading it all into memory.
+///
+/// Used to stream downloads, see [`Caller::download`](crate::v1_1_4::caller::Caller::download).
+pub trait StreamingResponse: TransportResponse {
+    type Chunk<'a>: Future<Output = Result<Option<Vec<u8>>, ApiError>> + 'a
+    where
+        Self: 'a;
+
+    /// Read the next chunk of the body, or `None` at the end of the body.
+    fn chunk(&mut self) -> Self::Chunk<'_>;
+}
+
+impl StreamingResponse for ::http::Response<Vec<u8>> {
+    type Chunk<'a> = Ready<Result<Option<Vec<u8>>, ApiError>>;
+
+    fn chunk(&mut self) -> Self::Chunk<'_> {
+        let body = ::std::mem::take(self.body_mut());
+        ready(Ok(Some(body).filter(|body| !body.is_empty())))
+    }
+}
+
+/// A [`Transport`] that can send a request body read from `Reader`, without reading it into
+/// memory, e.g. to upload a large release asset.
+///
+/// The `hyper` and asynchronous `reqwest` clients accept any `futures::io::AsyncRead`, and
+/// the blocking `reqwest` client accepts any `std::io::Read`.
+pub trait UploadTransport<Reader>: Transport {
+    type Upload: Future<Output = Result<Self::Response, ApiError>>;
+
+    /// Send a request, whose body of `len` bytes is read from `reader`.
+    fn send_upload(&self, request: ::http::Request<()>, reader: Reader, len: u64) -> Self::Upload;
+}
+
+/// Stream of the chunks read from an `AsyncRead`.
+#[cfg(any(feature = "hyper", feature = "reqwest-async"))]
+pub(crate) struct ReaderStream<Reader> {
+    reader: Reader,
+    buf: Vec<u8>,
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest-async"))]
+impl<Reader> ReaderStream<Reader> {
+    pub(crate) fn new(reader: Reader) -> Self {
+        ReaderStream {
+            reader,
+            buf: vec![0; 64 * 1024],
+        }
+    }
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest-async"))]
+impl<Reader> ::futures_util::stream::Stream for ReaderStream<Reader>
+where
+    Reader: ::futures_util::io::AsyncRead + Unpin,
+{
+    type Item = ::std::io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.reader).poll_read(cx, &mut this.buf) {
+            Poll::Ready(Ok(0)) => Poll::Ready(None),
+            Poll::Ready(Ok(len)) => Poll::Ready(Some(Ok(this.buf[..len].to_vec()))),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Complete a future on the current thread, e.g. a call using a blocking [`Transport`] and a
+/// blocking sleep function.
+#[cfg(feature = "reqwest-blocking")]
+pub(crate) fn wait<F: Future>(future: F) -> F::Output {
+    ::futures_executor::block_on(future)
+}
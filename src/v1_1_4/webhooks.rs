@@ -0,0 +1,1769 @@
+//! Receiving webhooks.
+//!
+//! GitHub signs each webhook delivery with the webhook secret, in the
+//! `X-Hub-Signature-256` header, and names the event in the `X-GitHub-Event` header.
+//! [`WebhookSecret::event`] verifies the signature of a delivery with a JSON payload and
+//! decodes the payload into a [`WebhookEvent`]:
+//!
+//! ```ignore
+//! let secret = WebhookSecret::new(webhook_secret);
+//! match secret.event(request.headers(), &body)? {
+//!     WebhookEvent::Push(push) => println!("Pushed {} to {}", push.after, push.r#ref),
+//!     WebhookEvent::PullRequest(event) => println!("Pull request #{} {}", event.number, event.action),
+//!     _ => {}
+//! }
+//! ```
+//!
+//! Payloads use the schema types returned by the REST API for the main object of an event,
+//! where the webhook sends the same object, and `Webhook*` types with the fields common to
+//! all deliveries otherwise. Events without a typed payload, such as events added after this
+//! version of the API, are returned as [`WebhookEvent::Other`].
+
+use ::std::borrow::Cow;
+
+use ::hmac::{Hmac, Mac};
+use ::sha2::Sha256;
+use ::thiserror::Error;
+
+use crate::v1_1_4::schema::{
+    CheckRun, Installation, Issue, IssueComment, Label, Milestone, OrganizationSimple, PullRequest,
+    PullRequestSimple, Release, SimpleUser, TeamSimple, Workflow, WorkflowRun,
+};
+
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("Missing {0} header")]
+    MissingHeader(&'static str),
+
+    #[error("Invalid webhook signature")]
+    InvalidSignature,
+
+    #[error("JsonSerializationError")]
+    JsonSerializationError {
+        #[from]
+        source: ::serde_json::error::Error,
+    },
+}
+
+/// Secret used to sign webhook deliveries.
+pub struct WebhookSecret {
+    secret: Vec<u8>,
+}
+
+impl WebhookSecret {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self { secret: secret.into() }
+    }
+
+    /// Verify the value of the `X-Hub-Signature-256` header, e.g. `sha256=757107ea...`,
+    /// against the raw body of the delivery.
+    ///
+    /// The signature is compared in constant time.
+    pub fn verify(&self, signature: &str, body: &[u8]) -> Result<(), WebhookError> {
+        let signature = signature
+            .strip_prefix("sha256=")
+            .and_then(|hex| ::hex::decode(hex).ok())
+            .ok_or(WebhookError::InvalidSignature)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .map_err(|_| WebhookError::InvalidSignature)?;
+        mac.update(body);
+        mac.verify_slice(&signature)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+
+    /// Verify the signature of a delivery, and decode its payload using the event named in
+    /// the `X-GitHub-Event` header.
+    pub fn event<'a>(&self, headers: &::http::HeaderMap, body: &'a [u8]) -> Result<WebhookEvent<'a>, WebhookError> {
+        let signature = header(headers, "x-hub-signature-256")?;
+        self.verify(signature, body)?;
+        let event = header(headers, "x-github-event")?;
+        WebhookEvent::parse(event, body)
+    }
+}
+
+fn header<'h>(headers: &'h ::http::HeaderMap, name: &'static str) -> Result<&'h str, WebhookError> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(WebhookError::MissingHeader(name))
+}
+
+/// Payload of a webhook delivery.
+#[derive(Clone, Debug)]
+pub enum WebhookEvent<'a> {
+    Ping(Box<PingEvent<'a>>),
+    Push(Box<PushEvent<'a>>),
+    Create(Box<CreateEvent<'a>>),
+    Delete(Box<DeleteEvent<'a>>),
+    PullRequest(Box<PullRequestEvent<'a>>),
+    Issues(Box<IssuesEvent<'a>>),
+    IssueComment(Box<IssueCommentEvent<'a>>),
+    CheckRun(Box<CheckRunEvent<'a>>),
+    CheckSuite(Box<CheckSuiteEvent<'a>>),
+    WorkflowRun(Box<WorkflowRunEvent<'a>>),
+    Release(Box<ReleaseEvent<'a>>),
+    Installation(Box<InstallationEvent<'a>>),
+    InstallationRepositories(Box<InstallationRepositoriesEvent<'a>>),
+    BranchProtectionRule(Box<BranchProtectionRuleEvent<'a>>),
+    CodeScanningAlert(Box<CodeScanningAlertEvent<'a>>),
+    CommitComment(Box<CommitCommentEvent<'a>>),
+    DependabotAlert(Box<DependabotAlertEvent<'a>>),
+    DeployKey(Box<DeployKeyEvent<'a>>),
+    Deployment(Box<DeploymentEvent<'a>>),
+    DeploymentStatus(Box<DeploymentStatusEvent<'a>>),
+    Discussion(Box<DiscussionEvent<'a>>),
+    DiscussionComment(Box<DiscussionCommentEvent<'a>>),
+    Fork(Box<ForkEvent<'a>>),
+    GithubAppAuthorization(Box<GithubAppAuthorizationEvent<'a>>),
+    Gollum(Box<GollumEvent<'a>>),
+    InstallationTarget(Box<InstallationTargetEvent<'a>>),
+    Label(Box<LabelEvent<'a>>),
+    MarketplacePurchase(Box<MarketplacePurchaseEvent<'a>>),
+    Member(Box<MemberEvent<'a>>),
+    Membership(Box<MembershipEvent<'a>>),
+    MergeGroup(Box<MergeGroupEvent<'a>>),
+    Meta(Box<MetaEvent<'a>>),
+    Milestone(Box<MilestoneEvent<'a>>),
+    OrgBlock(Box<OrgBlockEvent<'a>>),
+    Organization(Box<OrganizationEvent<'a>>),
+    Package(Box<PackageEvent<'a>>),
+    PageBuild(Box<PageBuildEvent<'a>>),
+    Project(Box<ProjectEvent<'a>>),
+    ProjectCard(Box<ProjectCardEvent<'a>>),
+    ProjectColumn(Box<ProjectColumnEvent<'a>>),
+    ProjectsV2Item(Box<ProjectsV2ItemEvent<'a>>),
+    Public(Box<PublicEvent<'a>>),
+    PullRequestReview(Box<PullRequestReviewEvent<'a>>),
+    PullRequestReviewComment(Box<PullRequestReviewCommentEvent<'a>>),
+    PullRequestReviewThread(Box<PullRequestReviewThreadEvent<'a>>),
+    RegistryPackage(Box<RegistryPackageEvent<'a>>),
+    Repository(Box<RepositoryEvent<'a>>),
+    RepositoryDispatch(Box<RepositoryDispatchEvent<'a>>),
+    RepositoryImport(Box<RepositoryImportEvent<'a>>),
+    RepositoryVulnerabilityAlert(Box<RepositoryVulnerabilityAlertEvent<'a>>),
+    SecretScanningAlert(Box<SecretScanningAlertEvent<'a>>),
+    SecretScanningAlertLocation(Box<SecretScanningAlertLocationEvent<'a>>),
+    SecurityAdvisory(Box<SecurityAdvisoryEvent<'a>>),
+    SecurityAndAnalysis(Box<SecurityAndAnalysisEvent<'a>>),
+    Sponsorship(Box<SponsorshipEvent<'a>>),
+    Star(Box<StarEvent<'a>>),
+    Status(Box<StatusEvent<'a>>),
+    Team(Box<TeamEvent<'a>>),
+    TeamAdd(Box<TeamAddEvent<'a>>),
+    Watch(Box<WatchEvent<'a>>),
+    WorkflowDispatch(Box<WorkflowDispatchEvent<'a>>),
+    WorkflowJob(Box<WorkflowJobEvent<'a>>),
+    /// An event without a typed payload.
+    Other {
+        event: String,
+        payload: ::serde_json::value::Value,
+    },
+}
+
+impl<'a> WebhookEvent<'a> {
+    /// Decode the JSON payload of event `event`, named in the `X-GitHub-Event` header.
+    ///
+    /// The signature of the delivery is not verified.
+    pub fn parse(event: &str, body: &'a [u8]) -> Result<Self, WebhookError> {
+        Ok(match event {
+            "ping" => WebhookEvent::Ping(::serde_json::from_slice(body)?),
+            "push" => WebhookEvent::Push(::serde_json::from_slice(body)?),
+            "create" => WebhookEvent::Create(::serde_json::from_slice(body)?),
+            "delete" => WebhookEvent::Delete(::serde_json::from_slice(body)?),
+            "pull_request" => WebhookEvent::PullRequest(::serde_json::from_slice(body)?),
+            "issues" => WebhookEvent::Issues(::serde_json::from_slice(body)?),
+            "issue_comment" => WebhookEvent::IssueComment(::serde_json::from_slice(body)?),
+            "check_run" => WebhookEvent::CheckRun(::serde_json::from_slice(body)?),
+            "check_suite" => WebhookEvent::CheckSuite(::serde_json::from_slice(body)?),
+            "workflow_run" => WebhookEvent::WorkflowRun(::serde_json::from_slice(body)?),
+            "release" => WebhookEvent::Release(::serde_json::from_slice(body)?),
+            "installation" => WebhookEvent::Installation(::serde_json::from_slice(body)?),
+            "installation_repositories" => WebhookEvent::InstallationRepositories(::serde_json::from_slice(body)?),
+            "branch_protection_rule" => WebhookEvent::BranchProtectionRule(::serde_json::from_slice(body)?),
+            "code_scanning_alert" => WebhookEvent::CodeScanningAlert(::serde_json::from_slice(body)?),
+            "commit_comment" => WebhookEvent::CommitComment(::serde_json::from_slice(body)?),
+            "dependabot_alert" => WebhookEvent::DependabotAlert(::serde_json::from_slice(body)?),
+            "deploy_key" => WebhookEvent::DeployKey(::serde_json::from_slice(body)?),
+            "deployment" => WebhookEvent::Deployment(::serde_json::from_slice(body)?),
+            "deployment_status" => WebhookEvent::DeploymentStatus(::serde_json::from_slice(body)?),
+            "discussion" => WebhookEvent::Discussion(::serde_json::from_slice(body)?),
+            "discussion_comment" => WebhookEvent::DiscussionComment(::serde_json::from_slice(body)?),
+            "fork" => WebhookEvent::Fork(::serde_json::from_slice(body)?),
+            "github_app_authorization" => WebhookEvent::GithubAppAuthorization(::serde_json::from_slice(body)?),
+            "gollum" => WebhookEvent::Gollum(::serde_json::from_slice(body)?),
+            "installation_target" => WebhookEvent::InstallationTarget(::serde_json::from_slice(body)?),
+            "label" => WebhookEvent::Label(::serde_json::from_slice(body)?),
+            "marketplace_purchase" => WebhookEvent::MarketplacePurchase(::serde_json::from_slice(body)?),
+            "member" => WebhookEvent::Member(::serde_json::from_slice(body)?),
+            "membership" => WebhookEvent::Membership(::serde_json::from_slice(body)?),
+            "merge_group" => WebhookEvent::MergeGroup(::serde_json::from_slice(body)?),
+            "meta" => WebhookEvent::Meta(::serde_json::from_slice(body)?),
+            "milestone" => WebhookEvent::Milestone(::serde_json::from_slice(body)?),
+            "org_block" => WebhookEvent::OrgBlock(::serde_json::from_slice(body)?),
+            "organization" => WebhookEvent::Organization(::serde_json::from_slice(body)?),
+            "package" => WebhookEvent::Package(::serde_json::from_slice(body)?),
+            "page_build" => WebhookEvent::PageBuild(::serde_json::from_slice(body)?),
+            "project" => WebhookEvent::Project(::serde_json::from_slice(body)?),
+            "project_card" => WebhookEvent::ProjectCard(::serde_json::from_slice(body)?),
+            "project_column" => WebhookEvent::ProjectColumn(::serde_json::from_slice(body)?),
+            "projects_v2_item" => WebhookEvent::ProjectsV2Item(::serde_json::from_slice(body)?),
+            "public" => WebhookEvent::Public(::serde_json::from_slice(body)?),
+            "pull_request_review" => WebhookEvent::PullRequestReview(::serde_json::from_slice(body)?),
+            "pull_request_review_comment" => WebhookEvent::PullRequestReviewComment(::serde_json::from_slice(body)?),
+            "pull_request_review_thread" => WebhookEvent::PullRequestReviewThread(::serde_json::from_slice(body)?),
+            "registry_package" => WebhookEvent::RegistryPackage(::serde_json::from_slice(body)?),
+            "repository" => WebhookEvent::Repository(::serde_json::from_slice(body)?),
+            "repository_dispatch" => WebhookEvent::RepositoryDispatch(::serde_json::from_slice(body)?),
+            "repository_import" => WebhookEvent::RepositoryImport(::serde_json::from_slice(body)?),
+            "repository_vulnerability_alert" => WebhookEvent::RepositoryVulnerabilityAlert(::serde_json::from_slice(body)?),
+            "secret_scanning_alert" => WebhookEvent::SecretScanningAlert(::serde_json::from_slice(body)?),
+            "secret_scanning_alert_location" => WebhookEvent::SecretScanningAlertLocation(::serde_json::from_slice(body)?),
+            "security_advisory" => WebhookEvent::SecurityAdvisory(::serde_json::from_slice(body)?),
+            "security_and_analysis" => WebhookEvent::SecurityAndAnalysis(::serde_json::from_slice(body)?),
+            "sponsorship" => WebhookEvent::Sponsorship(::serde_json::from_slice(body)?),
+            "star" => WebhookEvent::Star(::serde_json::from_slice(body)?),
+            "status" => WebhookEvent::Status(::serde_json::from_slice(body)?),
+            "team" => WebhookEvent::Team(::serde_json::from_slice(body)?),
+            "team_add" => WebhookEvent::TeamAdd(::serde_json::from_slice(body)?),
+            "watch" => WebhookEvent::Watch(::serde_json::from_slice(body)?),
+            "workflow_dispatch" => WebhookEvent::WorkflowDispatch(::serde_json::from_slice(body)?),
+            "workflow_job" => WebhookEvent::WorkflowJob(::serde_json::from_slice(body)?),
+            _ => WebhookEvent::Other {
+                event: event.to_owned(),
+                payload: ::serde_json::from_slice(body)?,
+            },
+        })
+    }
+
+    /// Name of the event, as in the `X-GitHub-Event` header.
+    pub fn name(&self) -> &str {
+        match self {
+            WebhookEvent::Ping(_) => "ping",
+            WebhookEvent::Push(_) => "push",
+            WebhookEvent::Create(_) => "create",
+            WebhookEvent::Delete(_) => "delete",
+            WebhookEvent::PullRequest(_) => "pull_request",
+            WebhookEvent::Issues(_) => "issues",
+            WebhookEvent::IssueComment(_) => "issue_comment",
+            WebhookEvent::CheckRun(_) => "check_run",
+            WebhookEvent::CheckSuite(_) => "check_suite",
+            WebhookEvent::WorkflowRun(_) => "workflow_run",
+            WebhookEvent::Release(_) => "release",
+            WebhookEvent::Installation(_) => "installation",
+            WebhookEvent::InstallationRepositories(_) => "installation_repositories",
+            WebhookEvent::BranchProtectionRule(_) => "branch_protection_rule",
+            WebhookEvent::CodeScanningAlert(_) => "code_scanning_alert",
+            WebhookEvent::CommitComment(_) => "commit_comment",
+            WebhookEvent::DependabotAlert(_) => "dependabot_alert",
+            WebhookEvent::DeployKey(_) => "deploy_key",
+            WebhookEvent::Deployment(_) => "deployment",
+            WebhookEvent::DeploymentStatus(_) => "deployment_status",
+            WebhookEvent::Discussion(_) => "discussion",
+            WebhookEvent::DiscussionComment(_) => "discussion_comment",
+            WebhookEvent::Fork(_) => "fork",
+            WebhookEvent::GithubAppAuthorization(_) => "github_app_authorization",
+            WebhookEvent::Gollum(_) => "gollum",
+            WebhookEvent::InstallationTarget(_) => "installation_target",
+            WebhookEvent::Label(_) => "label",
+            WebhookEvent::MarketplacePurchase(_) => "marketplace_purchase",
+            WebhookEvent::Member(_) => "member",
+            WebhookEvent::Membership(_) => "membership",
+            WebhookEvent::MergeGroup(_) => "merge_group",
+            WebhookEvent::Meta(_) => "meta",
+            WebhookEvent::Milestone(_) => "milestone",
+            WebhookEvent::OrgBlock(_) => "org_block",
+            WebhookEvent::Organization(_) => "organization",
+            WebhookEvent::Package(_) => "package",
+            WebhookEvent::PageBuild(_) => "page_build",
+            WebhookEvent::Project(_) => "project",
+            WebhookEvent::ProjectCard(_) => "project_card",
+            WebhookEvent::ProjectColumn(_) => "project_column",
+            WebhookEvent::ProjectsV2Item(_) => "projects_v2_item",
+            WebhookEvent::Public(_) => "public",
+            WebhookEvent::PullRequestReview(_) => "pull_request_review",
+            WebhookEvent::PullRequestReviewComment(_) => "pull_request_review_comment",
+            WebhookEvent::PullRequestReviewThread(_) => "pull_request_review_thread",
+            WebhookEvent::RegistryPackage(_) => "registry_package",
+            WebhookEvent::Repository(_) => "repository",
+            WebhookEvent::RepositoryDispatch(_) => "repository_dispatch",
+            WebhookEvent::RepositoryImport(_) => "repository_import",
+            WebhookEvent::RepositoryVulnerabilityAlert(_) => "repository_vulnerability_alert",
+            WebhookEvent::SecretScanningAlert(_) => "secret_scanning_alert",
+            WebhookEvent::SecretScanningAlertLocation(_) => "secret_scanning_alert_location",
+            WebhookEvent::SecurityAdvisory(_) => "security_advisory",
+            WebhookEvent::SecurityAndAnalysis(_) => "security_and_analysis",
+            WebhookEvent::Sponsorship(_) => "sponsorship",
+            WebhookEvent::Star(_) => "star",
+            WebhookEvent::Status(_) => "status",
+            WebhookEvent::Team(_) => "team",
+            WebhookEvent::TeamAdd(_) => "team_add",
+            WebhookEvent::Watch(_) => "watch",
+            WebhookEvent::WorkflowDispatch(_) => "workflow_dispatch",
+            WebhookEvent::WorkflowJob(_) => "workflow_job",
+            WebhookEvent::Other { event, .. } => event,
+        }
+    }
+}
+
+/// Repository in a webhook payload.
+///
+/// Only the fields common to all events are included, as the `push` event reports
+/// timestamps in a different format from other events.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookRepository<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub full_name: Cow<'a, str>,
+    pub private: bool,
+    pub owner: SimpleUser<'a>,
+    pub html_url: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
+    pub fork: bool,
+    #[serde(default)]
+    pub default_branch: Option<Cow<'a, str>>,
+}
+
+/// Installation of the GitHub App that received a webhook.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookInstallation<'a> {
+    pub id: i64,
+    #[serde(default)]
+    pub node_id: Option<Cow<'a, str>>,
+}
+
+/// Repository in an `installation` or `installation_repositories` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct InstallationRepository<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub full_name: Cow<'a, str>,
+    pub private: bool,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PingEvent<'a> {
+    pub zen: Cow<'a, str>,
+    pub hook_id: i64,
+    pub hook: ::serde_json::value::Value,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+/// Git author or committer in a `push` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct PushAuthor<'a> {
+    pub name: Cow<'a, str>,
+    #[serde(default)]
+    pub email: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub username: Option<Cow<'a, str>>,
+}
+
+/// Commit in a `push` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct PushCommit<'a> {
+    pub id: Cow<'a, str>,
+    pub tree_id: Cow<'a, str>,
+    pub distinct: bool,
+    pub message: Cow<'a, str>,
+    pub timestamp: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub author: PushAuthor<'a>,
+    pub committer: PushAuthor<'a>,
+    #[serde(default)]
+    pub added: Vec<Cow<'a, str>>,
+    #[serde(default)]
+    pub removed: Vec<Cow<'a, str>>,
+    #[serde(default)]
+    pub modified: Vec<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PushEvent<'a> {
+    /// Full name of the pushed ref, e.g. `refs/heads/main`.
+    pub r#ref: Cow<'a, str>,
+    pub before: Cow<'a, str>,
+    pub after: Cow<'a, str>,
+    pub created: bool,
+    pub deleted: bool,
+    pub forced: bool,
+    pub base_ref: Option<Cow<'a, str>>,
+    pub compare: Cow<'a, str>,
+    pub commits: Vec<PushCommit<'a>>,
+    pub head_commit: Option<PushCommit<'a>>,
+    pub pusher: PushAuthor<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct CreateEvent<'a> {
+    /// Name of the created branch or tag, without the `refs/heads/` or `refs/tags/` prefix.
+    pub r#ref: Cow<'a, str>,
+    /// `branch` or `tag`.
+    pub ref_type: Cow<'a, str>,
+    pub master_branch: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
+    pub pusher_type: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DeleteEvent<'a> {
+    /// Name of the deleted branch or tag, without the `refs/heads/` or `refs/tags/` prefix.
+    pub r#ref: Cow<'a, str>,
+    /// `branch` or `tag`.
+    pub ref_type: Cow<'a, str>,
+    pub pusher_type: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PullRequestEvent<'a> {
+    /// e.g. `opened`, `edited`, `closed`, `reopened` or `synchronize`.
+    pub action: Cow<'a, str>,
+    pub number: i64,
+    pub pull_request: PullRequest<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct IssuesEvent<'a> {
+    /// e.g. `opened`, `edited`, `closed`, `reopened`, `labeled` or `assigned`.
+    pub action: Cow<'a, str>,
+    pub issue: Issue<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct IssueCommentEvent<'a> {
+    /// `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub issue: Issue<'a>,
+    pub comment: IssueComment<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct CheckRunEvent<'a> {
+    /// `created`, `completed`, `rerequested` or `requested_action`.
+    pub action: Cow<'a, str>,
+    pub check_run: CheckRun<'a>,
+    /// The action requested by the user, for action `requested_action`.
+    #[serde(default)]
+    pub requested_action: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Check suite in a `check_suite` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookCheckSuite<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub head_branch: Option<Cow<'a, str>>,
+    pub head_sha: Cow<'a, str>,
+    pub status: Option<Cow<'a, str>>,
+    pub conclusion: Option<Cow<'a, str>>,
+    pub before: Option<Cow<'a, str>>,
+    pub after: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct CheckSuiteEvent<'a> {
+    /// `completed`, `requested` or `rerequested`.
+    pub action: Cow<'a, str>,
+    pub check_suite: WebhookCheckSuite<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WorkflowRunEvent<'a> {
+    /// `requested`, `in_progress` or `completed`.
+    pub action: Cow<'a, str>,
+    pub workflow_run: WorkflowRun<'a>,
+    #[serde(default)]
+    pub workflow: Option<Workflow<'a>>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ReleaseEvent<'a> {
+    /// e.g. `published`, `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub release: Release<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct InstallationEvent<'a> {
+    /// e.g. `created`, `deleted`, `suspend`, `unsuspend` or `new_permissions_accepted`.
+    pub action: Cow<'a, str>,
+    pub installation: Installation<'a>,
+    /// Repositories the installation can access, for action `created`.
+    #[serde(default)]
+    pub repositories: Option<Vec<InstallationRepository<'a>>>,
+    #[serde(default)]
+    pub requester: Option<SimpleUser<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct InstallationRepositoriesEvent<'a> {
+    /// `added` or `removed`.
+    pub action: Cow<'a, str>,
+    pub installation: Installation<'a>,
+    /// `all` or `selected`.
+    pub repository_selection: Cow<'a, str>,
+    pub repositories_added: Vec<InstallationRepository<'a>>,
+    pub repositories_removed: Vec<InstallationRepository<'a>>,
+    #[serde(default)]
+    pub requester: Option<SimpleUser<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Branch protection rule in a `branch_protection_rule` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookBranchProtectionRule<'a> {
+    pub id: i64,
+    pub repository_id: i64,
+    /// Branch name pattern, e.g. `main` or `release/*`.
+    pub name: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct BranchProtectionRuleEvent<'a> {
+    /// `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub rule: WebhookBranchProtectionRule<'a>,
+    /// The previous values of the changed settings, for action `edited`.
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Code scanning alert in a `code_scanning_alert` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookCodeScanningAlert<'a> {
+    pub number: i64,
+    pub created_at: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    /// `open`, `dismissed` or `fixed`.
+    pub state: Cow<'a, str>,
+    #[serde(default)]
+    pub dismissed_by: Option<SimpleUser<'a>>,
+    #[serde(default)]
+    pub dismissed_at: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub dismissed_reason: Option<Cow<'a, str>>,
+    pub rule: ::serde_json::value::Value,
+    pub tool: ::serde_json::value::Value,
+    #[serde(default)]
+    pub most_recent_instance: Option<::serde_json::value::Value>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct CodeScanningAlertEvent<'a> {
+    /// e.g. `created`, `fixed`, `closed_by_user`, `reopened` or `appeared_in_branch`.
+    pub action: Cow<'a, str>,
+    pub alert: WebhookCodeScanningAlert<'a>,
+    /// Full name of the ref of the analysis, e.g. `refs/heads/main`.
+    pub r#ref: Cow<'a, str>,
+    pub commit_oid: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    /// The user who performed the action, or GitHub for alerts created by an analysis.
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+/// Comment on a commit in a `commit_comment` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookCommitComment<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub body: Cow<'a, str>,
+    pub commit_id: Cow<'a, str>,
+    /// Path of the commented file, or `None` for a comment on the whole commit.
+    pub path: Option<Cow<'a, str>>,
+    pub position: Option<i64>,
+    pub line: Option<i64>,
+    pub user: SimpleUser<'a>,
+    pub author_association: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct CommitCommentEvent<'a> {
+    /// `created`.
+    pub action: Cow<'a, str>,
+    pub comment: WebhookCommitComment<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Dependabot alert in a `dependabot_alert` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookDependabotAlert<'a> {
+    pub number: i64,
+    /// `open`, `dismissed`, `fixed` or `auto_dismissed`.
+    pub state: Cow<'a, str>,
+    pub dependency: ::serde_json::value::Value,
+    pub security_advisory: ::serde_json::value::Value,
+    pub security_vulnerability: ::serde_json::value::Value,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    #[serde(default)]
+    pub dismissed_at: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub dismissed_by: Option<SimpleUser<'a>>,
+    #[serde(default)]
+    pub dismissed_reason: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub dismissed_comment: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub fixed_at: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DependabotAlertEvent<'a> {
+    /// e.g. `created`, `dismissed`, `fixed`, `reintroduced` or `reopened`.
+    pub action: Cow<'a, str>,
+    pub alert: WebhookDependabotAlert<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Deploy key in a `deploy_key` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookDeployKey<'a> {
+    pub id: i64,
+    pub key: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub verified: bool,
+    pub read_only: bool,
+    pub created_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DeployKeyEvent<'a> {
+    /// `created` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub key: WebhookDeployKey<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Deployment in a `deployment` or `deployment_status` event.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookDeployment<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub sha: Cow<'a, str>,
+    /// The ref that was deployed, e.g. a branch, tag or SHA.
+    pub r#ref: Cow<'a, str>,
+    /// e.g. `deploy`.
+    pub task: Cow<'a, str>,
+    pub payload: ::serde_json::value::Value,
+    pub environment: Cow<'a, str>,
+    #[serde(default)]
+    pub original_environment: Option<Cow<'a, str>>,
+    pub description: Option<Cow<'a, str>>,
+    pub creator: Option<SimpleUser<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    pub statuses_url: Cow<'a, str>,
+    pub repository_url: Cow<'a, str>,
+    #[serde(default)]
+    pub transient_environment: Option<bool>,
+    #[serde(default)]
+    pub production_environment: Option<bool>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DeploymentEvent<'a> {
+    /// `created`.
+    pub action: Cow<'a, str>,
+    pub deployment: WebhookDeployment<'a>,
+    /// The workflow run that created the deployment, if any.
+    #[serde(default)]
+    pub workflow_run: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Deployment status in a `deployment_status` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookDeploymentStatus<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    /// e.g. `pending`, `in_progress`, `success`, `failure`, `error` or `inactive`.
+    pub state: Cow<'a, str>,
+    pub creator: Option<SimpleUser<'a>>,
+    #[serde(default)]
+    pub description: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub environment: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub target_url: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub log_url: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub environment_url: Option<Cow<'a, str>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    pub deployment_url: Cow<'a, str>,
+    pub repository_url: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DeploymentStatusEvent<'a> {
+    /// `created`.
+    pub action: Cow<'a, str>,
+    pub deployment_status: WebhookDeploymentStatus<'a>,
+    pub deployment: WebhookDeployment<'a>,
+    /// The check run that reported the status, if any.
+    #[serde(default)]
+    pub check_run: Option<::serde_json::value::Value>,
+    /// The workflow run that reported the status, if any.
+    #[serde(default)]
+    pub workflow_run: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Discussion in a `discussion` or `discussion_comment` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookDiscussion<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub number: i64,
+    pub title: Cow<'a, str>,
+    pub body: Option<Cow<'a, str>>,
+    pub html_url: Cow<'a, str>,
+    pub repository_url: Cow<'a, str>,
+    pub category: ::serde_json::value::Value,
+    /// `open`, `closed` or `locked`.
+    pub state: Cow<'a, str>,
+    pub locked: bool,
+    pub comments: i64,
+    pub user: SimpleUser<'a>,
+    pub author_association: Cow<'a, str>,
+    #[serde(default)]
+    pub answer_html_url: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub answer_chosen_at: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub answer_chosen_by: Option<SimpleUser<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DiscussionEvent<'a> {
+    /// e.g. `created`, `edited`, `answered`, `closed`, `labeled` or `transferred`.
+    pub action: Cow<'a, str>,
+    pub discussion: WebhookDiscussion<'a>,
+    /// The comment chosen as the answer, for action `answered`.
+    #[serde(default)]
+    pub answer: Option<WebhookDiscussionComment<'a>>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Comment on a discussion in a `discussion` or `discussion_comment` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookDiscussionComment<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    /// The comment replied to, if any.
+    pub parent_id: Option<i64>,
+    pub child_comment_count: i64,
+    pub repository_url: Cow<'a, str>,
+    pub discussion_id: i64,
+    pub author_association: Cow<'a, str>,
+    pub user: SimpleUser<'a>,
+    pub body: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct DiscussionCommentEvent<'a> {
+    /// `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub comment: WebhookDiscussionComment<'a>,
+    pub discussion: WebhookDiscussion<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ForkEvent<'a> {
+    /// The new fork.
+    pub forkee: WebhookRepository<'a>,
+    /// The forked repository.
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct GithubAppAuthorizationEvent<'a> {
+    /// `revoked`.
+    pub action: Cow<'a, str>,
+    /// The user who revoked their authorization of the app.
+    pub sender: SimpleUser<'a>,
+}
+
+/// Wiki page in a `gollum` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct GollumPage<'a> {
+    pub page_name: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub summary: Option<Cow<'a, str>>,
+    /// `created` or `edited`.
+    pub action: Cow<'a, str>,
+    /// Latest commit SHA of the page.
+    pub sha: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct GollumEvent<'a> {
+    pub pages: Vec<GollumPage<'a>>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct InstallationTargetEvent<'a> {
+    /// `renamed`.
+    pub action: Cow<'a, str>,
+    /// The renamed user or organization.
+    pub account: ::serde_json::value::Value,
+    pub changes: ::serde_json::value::Value,
+    /// `User` or `Organization`.
+    pub target_type: Cow<'a, str>,
+    pub installation: WebhookInstallation<'a>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct LabelEvent<'a> {
+    /// `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub label: Label<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// GitHub Marketplace plan purchase in a `marketplace_purchase` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookMarketplacePurchase<'a> {
+    /// The user or organization that purchased the plan.
+    pub account: ::serde_json::value::Value,
+    /// `monthly` or `yearly`.
+    pub billing_cycle: Cow<'a, str>,
+    pub unit_count: i64,
+    pub on_free_trial: bool,
+    pub free_trial_ends_on: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub next_billing_date: Option<Cow<'a, str>>,
+    pub plan: ::serde_json::value::Value,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MarketplacePurchaseEvent<'a> {
+    /// e.g. `purchased`, `changed`, `cancelled` or `pending_change`.
+    pub action: Cow<'a, str>,
+    /// Date the change takes effect.
+    pub effective_date: Cow<'a, str>,
+    pub marketplace_purchase: WebhookMarketplacePurchase<'a>,
+    /// The plan before the change, for action `changed`.
+    #[serde(default)]
+    pub previous_marketplace_purchase: Option<WebhookMarketplacePurchase<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MemberEvent<'a> {
+    /// `added`, `edited` or `removed`.
+    pub action: Cow<'a, str>,
+    /// The collaborator.
+    pub member: SimpleUser<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MembershipEvent<'a> {
+    /// `added` or `removed`.
+    pub action: Cow<'a, str>,
+    /// `team`.
+    pub scope: Cow<'a, str>,
+    pub member: SimpleUser<'a>,
+    pub team: TeamSimple<'a>,
+    pub organization: OrganizationSimple<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Merge group in a `merge_group` event.
+#[derive(Clone, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookMergeGroup<'a> {
+    pub head_sha: Cow<'a, str>,
+    /// Full name of the temporary ref of the merge group.
+    pub head_ref: Cow<'a, str>,
+    pub base_sha: Cow<'a, str>,
+    /// Full name of the target branch, e.g. `refs/heads/main`.
+    pub base_ref: Cow<'a, str>,
+    pub head_commit: ::serde_json::value::Value,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MergeGroupEvent<'a> {
+    /// `checks_requested` or `destroyed`.
+    pub action: Cow<'a, str>,
+    pub merge_group: WebhookMergeGroup<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MetaEvent<'a> {
+    /// `deleted`.
+    pub action: Cow<'a, str>,
+    pub hook_id: i64,
+    pub hook: ::serde_json::value::Value,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct MilestoneEvent<'a> {
+    /// `created`, `closed`, `opened`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub milestone: Milestone<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct OrgBlockEvent<'a> {
+    /// `blocked` or `unblocked`.
+    pub action: Cow<'a, str>,
+    pub blocked_user: SimpleUser<'a>,
+    pub organization: OrganizationSimple<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct OrganizationEvent<'a> {
+    /// e.g. `member_added`, `member_removed`, `member_invited`, `renamed` or `deleted`.
+    pub action: Cow<'a, str>,
+    /// The membership of the user, for the `member_*` actions other than `member_invited`.
+    #[serde(default)]
+    pub membership: Option<::serde_json::value::Value>,
+    /// The invitation, for action `member_invited`.
+    #[serde(default)]
+    pub invitation: Option<::serde_json::value::Value>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub organization: OrganizationSimple<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Package in a `package` or `registry_package` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookPackage<'a> {
+    pub id: i64,
+    pub name: Cow<'a, str>,
+    pub namespace: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
+    /// e.g. `npm`, `maven`, `rubygems`, `docker` or `nuget`.
+    pub ecosystem: Cow<'a, str>,
+    pub package_type: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub created_at: Option<Cow<'a, str>>,
+    pub updated_at: Option<Cow<'a, str>>,
+    pub owner: SimpleUser<'a>,
+    /// The published or updated version.
+    #[serde(default)]
+    pub package_version: Option<::serde_json::value::Value>,
+    #[serde(default)]
+    pub registry: Option<::serde_json::value::Value>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PackageEvent<'a> {
+    /// `published` or `updated`.
+    pub action: Cow<'a, str>,
+    pub package: WebhookPackage<'a>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PageBuildEvent<'a> {
+    pub id: i64,
+    /// The GitHub Pages build, as returned by `repos_get_pages_build`.
+    pub build: ::serde_json::value::Value,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Classic project in a `project` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookProject<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub number: i64,
+    pub name: Cow<'a, str>,
+    pub body: Option<Cow<'a, str>>,
+    /// `open` or `closed`.
+    pub state: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub creator: Option<SimpleUser<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ProjectEvent<'a> {
+    /// `created`, `edited`, `closed`, `reopened` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub project: WebhookProject<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Card of a classic project in a `project_card` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookProjectCard<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    /// Text of the card, or `None` for a card referring to an issue or pull request.
+    pub note: Option<Cow<'a, str>>,
+    pub archived: bool,
+    pub column_id: i64,
+    pub url: Cow<'a, str>,
+    pub column_url: Cow<'a, str>,
+    pub project_url: Cow<'a, str>,
+    /// The referred issue or pull request, if any.
+    #[serde(default)]
+    pub content_url: Option<Cow<'a, str>>,
+    pub creator: Option<SimpleUser<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ProjectCardEvent<'a> {
+    /// `created`, `edited`, `moved`, `converted` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub project_card: WebhookProjectCard<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    /// The card the card was moved after, for action `moved`.
+    #[serde(default)]
+    pub after_id: Option<i64>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Column of a classic project in a `project_column` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookProjectColumn<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub project_url: Cow<'a, str>,
+    pub cards_url: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ProjectColumnEvent<'a> {
+    /// `created`, `edited`, `moved` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub project_column: WebhookProjectColumn<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    /// The column the column was moved after, for action `moved`.
+    #[serde(default)]
+    pub after_id: Option<i64>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Item of a project in a `projects_v2_item` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookProjectsV2Item<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub project_node_id: Cow<'a, str>,
+    /// Node ID of the issue, pull request or draft issue.
+    pub content_node_id: Cow<'a, str>,
+    /// `Issue`, `PullRequest` or `DraftIssue`.
+    pub content_type: Cow<'a, str>,
+    #[serde(default)]
+    pub creator: Option<SimpleUser<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    #[serde(default)]
+    pub archived_at: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct ProjectsV2ItemEvent<'a> {
+    /// e.g. `created`, `edited`, `archived`, `restored`, `converted`, `reordered` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub projects_v2_item: WebhookProjectsV2Item<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub organization: OrganizationSimple<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PublicEvent<'a> {
+    /// The repository made public.
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Pull request review in a `pull_request_review` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookReview<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub user: SimpleUser<'a>,
+    pub body: Option<Cow<'a, str>>,
+    pub commit_id: Cow<'a, str>,
+    pub submitted_at: Option<Cow<'a, str>>,
+    /// e.g. `approved`, `changes_requested`, `commented` or `dismissed`.
+    pub state: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub pull_request_url: Cow<'a, str>,
+    pub author_association: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PullRequestReviewEvent<'a> {
+    /// `submitted`, `edited` or `dismissed`.
+    pub action: Cow<'a, str>,
+    pub review: WebhookReview<'a>,
+    pub pull_request: PullRequestSimple<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Comment on the diff of a pull request in a `pull_request_review_comment` or
+/// `pull_request_review_thread` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookReviewComment<'a> {
+    pub id: i64,
+    pub node_id: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub pull_request_url: Cow<'a, str>,
+    pub pull_request_review_id: Option<i64>,
+    /// The comment replied to, if any.
+    #[serde(default)]
+    pub in_reply_to_id: Option<i64>,
+    pub diff_hunk: Cow<'a, str>,
+    pub path: Cow<'a, str>,
+    pub commit_id: Cow<'a, str>,
+    pub original_commit_id: Cow<'a, str>,
+    #[serde(default)]
+    pub line: Option<i64>,
+    #[serde(default)]
+    pub original_line: Option<i64>,
+    /// `LEFT` or `RIGHT`.
+    #[serde(default)]
+    pub side: Option<Cow<'a, str>>,
+    pub user: SimpleUser<'a>,
+    pub body: Cow<'a, str>,
+    pub author_association: Cow<'a, str>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PullRequestReviewCommentEvent<'a> {
+    /// `created`, `edited` or `deleted`.
+    pub action: Cow<'a, str>,
+    pub comment: WebhookReviewComment<'a>,
+    pub pull_request: PullRequestSimple<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Thread of comments on the diff of a pull request in a `pull_request_review_thread` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookReviewThread<'a> {
+    pub node_id: Cow<'a, str>,
+    pub comments: Vec<WebhookReviewComment<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct PullRequestReviewThreadEvent<'a> {
+    /// `resolved` or `unresolved`.
+    pub action: Cow<'a, str>,
+    pub thread: WebhookReviewThread<'a>,
+    pub pull_request: PullRequestSimple<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct RegistryPackageEvent<'a> {
+    /// `published` or `updated`.
+    pub action: Cow<'a, str>,
+    pub registry_package: WebhookPackage<'a>,
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct RepositoryEvent<'a> {
+    /// e.g. `created`, `deleted`, `archived`, `unarchived`, `edited`, `renamed`,
+    /// `transferred`, `publicized` or `privatized`.
+    pub action: Cow<'a, str>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct RepositoryDispatchEvent<'a> {
+    /// The `event_type` of the `repos_create_dispatch_event` request.
+    pub action: Cow<'a, str>,
+    /// The default branch of the repository.
+    pub branch: Cow<'a, str>,
+    /// The `client_payload` of the `repos_create_dispatch_event` request.
+    pub client_payload: Option<::serde_json::value::Value>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    pub installation: WebhookInstallation<'a>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct RepositoryImportEvent<'a> {
+    /// `success`, `cancelled` or `failure`.
+    pub status: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct RepositoryVulnerabilityAlertEvent<'a> {
+    /// `create`, `dismiss`, `reopen` or `resolve`.
+    pub action: Cow<'a, str>,
+    pub alert: ::serde_json::value::Value,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Secret scanning alert in a `secret_scanning_alert` or `secret_scanning_alert_location`
+/// event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookSecretScanningAlert<'a> {
+    pub number: i64,
+    pub created_at: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    /// e.g. `github_personal_access_token`.
+    pub secret_type: Cow<'a, str>,
+    #[serde(default)]
+    pub secret_type_display_name: Option<Cow<'a, str>>,
+    /// e.g. `false_positive`, `wont_fix`, `revoked` or `used_in_tests`, if resolved.
+    #[serde(default)]
+    pub resolution: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub resolved_by: Option<SimpleUser<'a>>,
+    #[serde(default)]
+    pub resolved_at: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct SecretScanningAlertEvent<'a> {
+    /// `created`, `reopened`, `resolved` or `revoked`.
+    pub action: Cow<'a, str>,
+    pub alert: WebhookSecretScanningAlert<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct SecretScanningAlertLocationEvent<'a> {
+    /// `created`.
+    pub action: Cow<'a, str>,
+    pub alert: WebhookSecretScanningAlert<'a>,
+    /// Where the secret was found, e.g. a file in a commit.
+    pub location: ::serde_json::value::Value,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Security advisory in a `security_advisory` event.
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WebhookSecurityAdvisory<'a> {
+    pub ghsa_id: Cow<'a, str>,
+    pub cve_id: Option<Cow<'a, str>>,
+    pub summary: Cow<'a, str>,
+    pub description: Cow<'a, str>,
+    /// `low`, `moderate`, `high` or `critical`.
+    pub severity: Cow<'a, str>,
+    pub identifiers: ::serde_json::value::Value,
+    pub references: ::serde_json::value::Value,
+    pub vulnerabilities: Vec<::serde_json::value::Value>,
+    pub published_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    pub withdrawn_at: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct SecurityAdvisoryEvent<'a> {
+    /// `published`, `updated`, `performed` or `withdrawn`.
+    pub action: Cow<'a, str>,
+    pub security_advisory: WebhookSecurityAdvisory<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct SecurityAndAnalysisEvent<'a> {
+    /// The previous security and analysis settings.
+    pub changes: ::serde_json::value::Value,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    #[serde(default)]
+    pub sender: Option<SimpleUser<'a>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct SponsorshipEvent<'a> {
+    /// e.g. `created`, `cancelled`, `edited`, `tier_changed`, `pending_cancellation` or
+    /// `pending_tier_change`.
+    pub action: Cow<'a, str>,
+    pub sponsorship: ::serde_json::value::Value,
+    /// Date a pending change takes effect.
+    #[serde(default)]
+    pub effective_date: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct StarEvent<'a> {
+    /// `created` or `deleted`.
+    pub action: Cow<'a, str>,
+    /// Time the star was created, or `None` for action `deleted`.
+    pub starred_at: Option<Cow<'a, str>>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Commit of a `status` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct StatusCommit<'a> {
+    pub sha: Cow<'a, str>,
+    pub node_id: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    #[serde(default)]
+    pub author: Option<SimpleUser<'a>>,
+    #[serde(default)]
+    pub committer: Option<SimpleUser<'a>>,
+}
+
+/// Branch containing the commit of a `status` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct StatusBranch<'a> {
+    pub name: Cow<'a, str>,
+    pub protected: bool,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct StatusEvent<'a> {
+    pub id: i64,
+    pub sha: Cow<'a, str>,
+    /// Full name of the repository.
+    pub name: Cow<'a, str>,
+    /// e.g. `continuous-integration/jenkins`.
+    pub context: Cow<'a, str>,
+    /// `pending`, `success`, `failure` or `error`.
+    pub state: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
+    pub target_url: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub avatar_url: Option<Cow<'a, str>>,
+    pub commit: StatusCommit<'a>,
+    pub branches: Vec<StatusBranch<'a>>,
+    pub created_at: Cow<'a, str>,
+    pub updated_at: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct TeamEvent<'a> {
+    /// `created`, `deleted`, `edited`, `added_to_repository` or `removed_from_repository`.
+    pub action: Cow<'a, str>,
+    pub team: TeamSimple<'a>,
+    #[serde(default)]
+    pub changes: Option<::serde_json::value::Value>,
+    /// The repository, for actions `added_to_repository` and `removed_from_repository`.
+    #[serde(default)]
+    pub repository: Option<WebhookRepository<'a>>,
+    pub organization: OrganizationSimple<'a>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct TeamAddEvent<'a> {
+    pub team: TeamSimple<'a>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WatchEvent<'a> {
+    /// `started`, i.e. the repository was starred.
+    pub action: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WorkflowDispatchEvent<'a> {
+    /// The inputs of the dispatched workflow, if any.
+    pub inputs: Option<::serde_json::value::Value>,
+    /// Full name of the ref of the dispatched workflow, e.g. `refs/heads/main`.
+    pub r#ref: Cow<'a, str>,
+    /// Path of the workflow file, e.g. `.github/workflows/build.yml`.
+    pub workflow: Cow<'a, str>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
+
+/// Step of a job in a `workflow_job` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookWorkflowStep<'a> {
+    pub name: Cow<'a, str>,
+    /// `queued`, `in_progress` or `completed`.
+    pub status: Cow<'a, str>,
+    pub conclusion: Option<Cow<'a, str>>,
+    pub number: i64,
+    pub started_at: Option<Cow<'a, str>>,
+    pub completed_at: Option<Cow<'a, str>>,
+}
+
+/// Job of a workflow run in a `workflow_job` event.
+#[derive(Clone, Eq, PartialEq, Debug, ::serde::Deserialize)]
+pub struct WebhookWorkflowJob<'a> {
+    pub id: i64,
+    pub run_id: i64,
+    pub run_attempt: i64,
+    pub run_url: Cow<'a, str>,
+    pub node_id: Cow<'a, str>,
+    pub name: Cow<'a, str>,
+    #[serde(default)]
+    pub workflow_name: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub head_branch: Option<Cow<'a, str>>,
+    pub head_sha: Cow<'a, str>,
+    pub url: Cow<'a, str>,
+    pub html_url: Cow<'a, str>,
+    pub check_run_url: Cow<'a, str>,
+    /// `queued`, `in_progress`, `completed` or `waiting`.
+    pub status: Cow<'a, str>,
+    pub conclusion: Option<Cow<'a, str>>,
+    pub started_at: Cow<'a, str>,
+    pub completed_at: Option<Cow<'a, str>>,
+    pub steps: Vec<WebhookWorkflowStep<'a>>,
+    /// Labels of the runners the job can run on, e.g. `ubuntu-latest`.
+    pub labels: Vec<Cow<'a, str>>,
+    pub runner_id: Option<i64>,
+    pub runner_name: Option<Cow<'a, str>>,
+    pub runner_group_id: Option<i64>,
+    pub runner_group_name: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, ::serde::Deserialize)]
+pub struct WorkflowJobEvent<'a> {
+    /// `queued`, `in_progress`, `completed` or `waiting`.
+    pub action: Cow<'a, str>,
+    pub workflow_job: WebhookWorkflowJob<'a>,
+    /// The deployment waiting for approval, for action `waiting`.
+    #[serde(default)]
+    pub deployment: Option<WebhookDeployment<'a>>,
+    pub repository: WebhookRepository<'a>,
+    #[serde(default)]
+    pub organization: Option<OrganizationSimple<'a>>,
+    #[serde(default)]
+    pub installation: Option<WebhookInstallation<'a>>,
+    pub sender: SimpleUser<'a>,
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "hyper-client")]
+
+use http::{Method, StatusCode};
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::serve_recorded;
+use github_api::config::Configuration;
+use github_api::hyper::AppClient;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("data/app-private-key.pem");
+
+#[tokio::test]
+async fn test_installation_cached() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::OK, vec![], r#"{"id": 42, "account": {"login": "octocat"}}"#),
+        (StatusCode::CREATED, vec![], r#"{"token": "ghs_42", "expires_at": "2099-01-01T00:00:00Z"}"#),
+        (StatusCode::OK, vec![], "Design for failure."),
+        (StatusCode::OK, vec![], "Keep it logically awesome."),
+    ]);
+
+    let config = Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    };
+    let app = AppClient::new(hyper::Client::new(), config, tokio::time::sleep, 12345, PRIVATE_KEY)?;
+
+    let github = app.for_repo("octocat", "Hello-World").await?;
+    assert_eq!(github.meta_get_zen().await?.status(), StatusCode::OK);
+    // Names are case-insensitive, and the token is shared with the first `Caller`.
+    let github = app.for_repo("OctoCat", "hello-world").await?;
+    assert_eq!(github.meta_get_zen().await?.status(), StatusCode::OK);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0].uri.path(), "/repos/octocat/Hello-World/installation");
+    assert!(requests[0].headers["authorization"].to_str()?.starts_with("Bearer ey"));
+    assert_eq!(requests[1].method, Method::POST);
+    assert_eq!(requests[1].uri.path(), "/app/installations/42/access_tokens");
+    for request in &requests[2..] {
+        assert_eq!(request.uri.path(), "/zen");
+        assert_eq!(request.headers["authorization"], "token ghs_42");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_installation_not_found() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _requests) = serve_recorded(vec![(
+        StatusCode::NOT_FOUND,
+        vec![],
+        r#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest/reference/apps#get-an-organization-installation-for-the-authenticated-app"}"#,
+    )]);
+
+    let config = Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    };
+    let app = AppClient::new(hyper::Client::new(), config, tokio::time::sleep, 12345, PRIVATE_KEY)?;
+
+    assert!(matches!(
+        app.for_org("github").await,
+        Err(github_api::ApiError::NotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_installation_shares_server_version() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![(
+        StatusCode::OK,
+        vec![("x-github-enterprise-version", "3.4.2".to_owned())],
+        r#"{"verifiable_password_authentication": true}"#,
+    )]);
+
+    let config = Configuration {
+        base_url: base_url.into(),
+        enterprise_server: true,
+        ..Default::default()
+    };
+    let app = AppClient::new(hyper::Client::new(), config, tokio::time::sleep, 12345, PRIVATE_KEY)?;
+
+    let version = Some(github_api::enterprise::ServerVersion::new(3, 4, 2));
+    assert_eq!(app.app().server_version().await?, version);
+    // The version read by the app is used by its installations, without another request.
+    assert_eq!(app.for_installation(42).server_version().await?, version);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    Ok(())
+}
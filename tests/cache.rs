@@ -0,0 +1,38 @@
+use http::HeaderMap;
+use jinxapi_github::v1_1_4::cache::{CachedResponse, DiskCache, LruCache, ResponseCache};
+
+fn response(body: &str) -> CachedResponse {
+    let mut headers = HeaderMap::new();
+    headers.insert("etag", format!("\"{}\"", body.len()).parse().unwrap());
+    headers.append("vary", "Accept".parse().unwrap());
+    headers.append("vary", "Authorization".parse().unwrap());
+    CachedResponse {
+        headers,
+        body: body.as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn test_lru_cache() {
+    let cache = LruCache::new(2);
+    cache.put("a", response("a"));
+    cache.put("b", response("b"));
+    // Using "a" makes "b" the least recently used.
+    assert_eq!(cache.get("a"), Some(response("a")));
+    cache.put("c", response("c"));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("a"), Some(response("a")));
+    assert_eq!(cache.get("c"), Some(response("c")));
+}
+
+#[test]
+fn test_disk_cache() {
+    let directory = std::env::temp_dir().join(format!("jinxapi-github-cache-{}", std::process::id()));
+    let cache = DiskCache::new(&directory);
+    assert_eq!(cache.get("a"), None);
+    cache.put("a", response("a"));
+    cache.put("b", response("b\n\nwith blank lines"));
+    assert_eq!(cache.get("a"), Some(response("a")));
+    assert_eq!(DiskCache::new(&directory).get("b"), Some(response("b\n\nwith blank lines")));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "hyper-client")]
+
+use std::sync::Arc;
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::serve_recorded;
+use github_api::cache::LruCache;
+
+#[tokio::test]
+async fn test_not_modified() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (
+            StatusCode::OK,
+            vec![("etag", r#""644b5b0155e6404a9cc4bd9d8b1ae730""#.to_owned())],
+            "Design for failure.",
+        ),
+        (
+            StatusCode::NOT_MODIFIED,
+            vec![
+                ("etag", r#""644b5b0155e6404a9cc4bd9d8b1ae730""#.to_owned()),
+                ("x-ratelimit-remaining", "4999".to_owned()),
+            ],
+            "",
+        ),
+    ]);
+
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        cache: Some(Arc::new(LruCache::new(10))),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, tokio::time::sleep);
+
+    for _ in 0..2 {
+        let response = github.meta_get_zen().await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        assert_eq!(body, "Design for failure.");
+    }
+
+    let requests = requests.lock().unwrap();
+    assert!(!requests[0].headers.contains_key("if-none-match"));
+    assert_eq!(requests[1].headers["if-none-match"], r#""644b5b0155e6404a9cc4bd9d8b1ae730""#);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_not_cached_without_validator() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::OK, vec![], "Design for failure."),
+        (StatusCode::OK, vec![], "Keep it logically awesome."),
+    ]);
+
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        cache: Some(Arc::new(LruCache::new(10))),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, tokio::time::sleep);
+
+    github.meta_get_zen().await?;
+    let response = github.meta_get_zen().await?;
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    assert_eq!(body, "Keep it logically awesome.");
+    assert!(!requests.lock().unwrap()[1].headers.contains_key("if-none-match"));
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "hyper-client")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{body, caller, serve_with_bodies};
+use github_api::checks::{truncate_output, Annotation, AnnotationLevel, Conclusion, MAX_OUTPUT_LENGTH};
+
+fn annotation(line: i64, level: AnnotationLevel) -> Annotation<'static> {
+    Annotation {
+        path: "src/lib.rs".into(),
+        start_line: line,
+        end_line: line,
+        annotation_level: level.as_str().into(),
+        message: format!("finding {}", line).into(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_check_run_batches_annotations() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::CREATED, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+    ]);
+    let github = caller(base_url);
+
+    let mut run = github.start_check_run("octocat", "hello-world", "lint", "ce587453").await?;
+    assert_eq!(run.check_run_id(), 4);
+    run.annotate_all((1..=119).map(|line| annotation(line, AnnotationLevel::Notice))).await?;
+    run.annotate(annotation(120, AnnotationLevel::Warning)).await?;
+    let response = run.finish().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    let create = body(&requests[0]);
+    assert_eq!(create["name"], "lint");
+    assert_eq!(create["head_sha"], "ce587453");
+    assert_eq!(create["status"], "in_progress");
+
+    let sizes: Vec<usize> = requests[1..]
+        .iter()
+        .map(|request| body(request)["output"]["annotations"].as_array().unwrap().len())
+        .collect();
+    assert_eq!(sizes, [50, 50, 20]);
+    for request in &requests[1..] {
+        assert_eq!(request.method(), http::Method::PATCH);
+        assert_eq!(request.uri().path(), "/repos/octocat/hello-world/check-runs/4");
+    }
+    assert!(body(&requests[2]).get("conclusion").is_none());
+
+    let finish = body(&requests[3]);
+    assert_eq!(finish["conclusion"], "neutral");
+    assert_eq!(finish["output"]["title"], "lint");
+    assert_eq!(finish["output"]["summary"], "0 failures, 1 warnings, 119 notices");
+    assert_eq!(finish["output"]["annotations"][19]["start_line"], 120);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_run_truncates_output() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::CREATED, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+    ]);
+    let github = caller(base_url);
+
+    let mut run = github.start_check_run("octocat", "hello-world", "test", "ce587453").await?;
+    run.annotate(annotation(1, AnnotationLevel::Failure)).await?;
+    run.set_summary("Tests failed");
+    run.set_text("é".repeat(70_000));
+    run.finish_with(Conclusion::ActionRequired).await?;
+
+    let requests = requests.lock().unwrap();
+    let finish = body(&requests[1]);
+    assert_eq!(finish["conclusion"], "action_required");
+    assert_eq!(finish["output"]["summary"], "Tests failed");
+    let text = finish["output"]["text"].as_str().unwrap();
+    assert_eq!(text.chars().count(), MAX_OUTPUT_LENGTH);
+    assert!(text.ends_with("**Output truncated.**"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_run_keeps_annotations_after_failure() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::CREATED, vec![], r#"{"id": 4}"#),
+        (StatusCode::UNPROCESSABLE_ENTITY, vec![], r#"{"message": "Validation Failed"}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+    ]);
+    let github = caller(base_url);
+
+    let mut run = github.start_check_run("octocat", "hello-world", "lint", "ce587453").await?;
+    run.annotate(annotation(1, AnnotationLevel::Failure)).await?;
+    assert!(run.flush().await.is_err());
+    // The batch is sent again by the next update.
+    run.flush().await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(body(&requests[1])["output"]["annotations"], body(&requests[2])["output"]["annotations"]);
+    assert_eq!(body(&requests[2])["output"]["annotations"][0]["start_line"], 1);
+    Ok(())
+}
+
+#[test]
+fn test_truncate_output() {
+    assert_eq!(truncate_output("short"), "short");
+    let exact = "x".repeat(MAX_OUTPUT_LENGTH);
+    assert_eq!(truncate_output(&exact), exact);
+    let long = "x".repeat(MAX_OUTPUT_LENGTH + 1);
+    assert_eq!(truncate_output(&long).chars().count(), MAX_OUTPUT_LENGTH);
+}
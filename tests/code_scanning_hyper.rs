@@ -0,0 +1,139 @@
+#![cfg(feature = "hyper-client")]
+
+use std::io::Read;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{caller, serve_with_bodies};
+use github_api::code_scanning::{encode_sarif, validate_sarif, SarifOptions};
+use github_api::ApiError;
+
+const SARIF: &str = r#"{
+  "version": "2.1.0",
+  "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+  "runs": [{
+    "tool": {"driver": {"name": "clippy"}},
+    "results": [{"ruleId": "clippy::needless_return", "message": {"text": "unneeded `return` statement"}}]
+  }]
+}"#;
+
+const RECEIPT: &str = r#"{"id": "47177e22-5596-11eb-80a1-c1e54ef945c6", "url": "https://api.github.com/repos/octocat/hello-world/code-scanning/sarifs/47177e22-5596-11eb-80a1-c1e54ef945c6"}"#;
+
+fn options() -> SarifOptions<'static> {
+    SarifOptions::new().with_poll_interval(Duration::from_millis(1))
+}
+
+fn decode(sarif: &serde_json::Value) -> String {
+    let compressed = STANDARD.decode(sarif.as_str().unwrap()).unwrap();
+    let mut document = String::new();
+    flate2::read::GzDecoder::new(&compressed[..]).read_to_string(&mut document).unwrap();
+    document
+}
+
+#[test]
+fn test_validate_sarif() {
+    assert!(validate_sarif(SARIF.as_bytes()).is_ok());
+    assert!(validate_sarif(b"not json").is_err());
+    assert!(validate_sarif(br#"{"version": "2.0.0", "runs": [{"tool": {"driver": {"name": "clippy"}}}]}"#).is_err());
+    assert!(validate_sarif(br#"{"version": "2.1.0", "runs": []}"#).is_err());
+    assert!(validate_sarif(br#"{"version": "2.1.0", "runs": [{"tool": {}}]}"#).is_err());
+
+    let results = vec![serde_json::json!({"message": {"text": "finding"}}); 25_001];
+    let document = serde_json::json!({"version": "2.1.0", "runs": [{"tool": {"driver": {"name": "lint"}}, "results": results}]});
+    assert!(validate_sarif(&serde_json::to_vec(&document).unwrap()).is_err());
+
+    let runs = vec![serde_json::json!({"tool": {"driver": {"name": "lint"}}}); 21];
+    let document = serde_json::json!({"version": "2.1.0", "runs": runs});
+    assert!(validate_sarif(&serde_json::to_vec(&document).unwrap()).is_err());
+
+    let extensions = vec![serde_json::json!({"name": "pack"}); 101];
+    let document = serde_json::json!({"version": "2.1.0", "runs": [{"tool": {"driver": {"name": "lint"}, "extensions": extensions}}]});
+    assert!(validate_sarif(&serde_json::to_vec(&document).unwrap()).is_err());
+}
+
+#[test]
+fn test_encode_sarif() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let encoded = encode_sarif(SARIF.as_bytes())?;
+    assert_eq!(decode(&encoded.into()), SARIF);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_sarif() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::ACCEPTED, vec![], RECEIPT),
+        (StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found"}"#),
+        (StatusCode::OK, vec![], r#"{"processing_status": "pending", "analyses_url": null}"#),
+        (StatusCode::OK, vec![], r#"{"processing_status": "complete", "analyses_url": "https://api.github.com/repos/octocat/hello-world/code-scanning/analyses?sarif_id=47177e22-5596-11eb-80a1-c1e54ef945c6"}"#),
+    ]);
+    let github = caller(base_url);
+
+    let options = options().with_tool_name("clippy");
+    let status = github
+        .upload_sarif("octocat", "hello-world", "4b6472266afd7b471e86085a6659e8c7f2b119da", "refs/heads/main", SARIF.as_bytes(), &options)
+        .await?;
+    assert_eq!(status.processing_status, "complete");
+    assert!(status.analyses_url.is_some());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[0].method(), http::Method::POST);
+    assert_eq!(requests[0].uri().path(), "/repos/octocat/hello-world/code-scanning/sarifs");
+    let body: serde_json::Value = serde_json::from_slice(requests[0].body())?;
+    assert_eq!(body["commit_sha"], "4b6472266afd7b471e86085a6659e8c7f2b119da");
+    assert_eq!(body["ref"], "refs/heads/main");
+    assert_eq!(body["tool_name"], "clippy");
+    assert_eq!(decode(&body["sarif"]), SARIF);
+    assert_eq!(
+        requests[3].uri().path(),
+        "/repos/octocat/hello-world/code-scanning/sarifs/47177e22-5596-11eb-80a1-c1e54ef945c6"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_sarif_failed() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::ACCEPTED, vec![], RECEIPT),
+        (StatusCode::OK, vec![], r#"{"processing_status": "failed", "errors": ["locationFromSarifResult: expected artifact location"]}"#),
+    ]);
+    let github = caller(base_url);
+
+    match github.upload_sarif("octocat", "hello-world", "4b6472266afd7b471e86085a6659e8c7f2b119da", "refs/heads/main", SARIF.as_bytes(), &options()).await {
+        Err(ApiError::SarifProcessing { sarif_id, errors }) => {
+            assert_eq!(sarif_id, "47177e22-5596-11eb-80a1-c1e54ef945c6");
+            assert_eq!(errors, vec!["locationFromSarifResult: expected artifact location"]);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    // An invalid document is not uploaded.
+    let result = github.upload_sarif("octocat", "hello-world", "4b6472266afd7b471e86085a6659e8c7f2b119da", "refs/heads/main", &b"{}"[..], &options()).await;
+    assert!(result.is_err());
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_sarif_unknown_status() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _requests) = serve_with_bodies(vec![
+        (StatusCode::ACCEPTED, vec![], RECEIPT),
+        (StatusCode::OK, vec![], r#"{"processing_status": "exploded"}"#),
+    ]);
+    let github = caller(base_url);
+
+    let result = github.upload_sarif("octocat", "hello-world", "4b6472266afd7b471e86085a6659e8c7f2b119da", "refs/heads/main", SARIF.as_bytes(), &options()).await;
+    assert!(matches!(result, Err(ApiError::Other(_))));
+
+    // A zero poll interval is rejected before uploading.
+    let options = SarifOptions { poll_interval: Duration::ZERO, ..options() };
+    let result = github.upload_sarif("octocat", "hello-world", "4b6472266afd7b471e86085a6659e8c7f2b119da", "refs/heads/main", SARIF.as_bytes(), &options).await;
+    assert!(result.is_err());
+    Ok(())
+}
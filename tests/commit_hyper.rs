@@ -0,0 +1,179 @@
+#![cfg(feature = "hyper-client")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{body, caller, serve_with_bodies};
+use github_api::commit::CommitBuilder;
+use github_api::contents::Signature;
+
+const COMMIT: &str = r#"{
+    "sha": "7638417db6d59f3c431d3e1f261cc637155684cd", "node_id": "", "url": "", "html_url": "",
+    "author": {"date": "2014-11-07T22:01:45Z", "name": "Monalisa Octocat", "email": "octocat@github.com"},
+    "committer": {"date": "2014-11-07T22:01:45Z", "name": "Monalisa Octocat", "email": "octocat@github.com"},
+    "message": "Release 1.0", "tree": {"sha": "cd8274d", "url": ""}, "parents": [],
+    "verification": {"verified": false, "reason": "unsigned", "signature": null, "payload": null}
+}"#;
+
+#[tokio::test]
+async fn test_commit_changes() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::CREATED, vec![], r#"{"sha": "3a0f86f", "url": ""}"#),
+        (StatusCode::OK, vec![], r#"{"ref": "refs/heads/main", "object": {"type": "commit", "sha": "aa218f5"}}"#),
+        (StatusCode::OK, vec![], r#"{"sha": "aa218f5", "tree": {"sha": "9fb037a"}}"#),
+        (
+            StatusCode::OK,
+            vec![],
+            r#"{"sha": "9fb037a", "truncated": false, "tree": [
+                {"path": "README.md", "mode": "100755", "type": "blob", "sha": "44b4fc6"},
+                {"path": "scripts", "mode": "040000", "type": "tree", "sha": "f484d24"}
+            ]}"#,
+        ),
+        (
+            StatusCode::OK,
+            vec![],
+            r#"{"sha": "f484d24", "truncated": false, "tree": [
+                {"path": "release", "mode": "100644", "type": "blob", "sha": "1ef7b3a"}
+            ]}"#,
+        ),
+        (StatusCode::CREATED, vec![], r#"{"sha": "cd8274d", "url": "", "tree": []}"#),
+        (StatusCode::CREATED, vec![], COMMIT),
+        (StatusCode::OK, vec![], r#"{"ref": "refs/heads/main", "object": {"sha": "7638417"}}"#),
+    ]);
+    let github = caller(base_url);
+
+    let changes = CommitBuilder::new("main", "Release 1.0")
+        .author(Signature { name: "Monalisa Octocat", email: "octocat@github.com" })
+        .modify("README.md", &b"# Hello"[..])
+        .delete("old.txt")
+        .rename("scripts/release", "bin/release")
+        .set_executable("bin/release", true);
+    let commit = github.commit_changes("octocat", "hello-world", &changes).await?;
+    assert_eq!(commit.sha, "7638417db6d59f3c431d3e1f261cc637155684cd");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 8);
+    let blob = body(&requests[0]);
+    assert_eq!(blob["content"], "IyBIZWxsbw==");
+    assert_eq!(blob["encoding"], "base64");
+
+    let tree = body(&requests[5]);
+    assert_eq!(tree["base_tree"], "9fb037a");
+    assert_eq!(
+        tree["tree"],
+        serde_json::json!([
+            {"path": "README.md", "mode": "100755", "type": "blob", "sha": "3a0f86f"},
+            {"path": "bin/release", "mode": "100755", "type": "blob", "sha": "1ef7b3a"},
+            {"path": "old.txt", "mode": "100644", "type": "blob", "sha": null},
+            {"path": "scripts/release", "mode": "100644", "type": "blob", "sha": null},
+        ])
+    );
+
+    let commit = body(&requests[6]);
+    assert_eq!(commit["tree"], "cd8274d");
+    assert_eq!(commit["parents"], serde_json::json!(["aa218f5"]));
+    assert_eq!(commit["author"]["name"], "Monalisa Octocat");
+
+    assert_eq!(requests[7].method(), http::Method::PATCH);
+    let update = body(&requests[7]);
+    assert_eq!(update["sha"], "7638417db6d59f3c431d3e1f261cc637155684cd");
+    assert_eq!(update["force"], false);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_retries_non_fast_forward() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::OK, vec![], r#"{"object": {"sha": "aa218f5"}}"#),
+        (StatusCode::OK, vec![], r#"{"tree": {"sha": "9fb037a"}}"#),
+        (StatusCode::CREATED, vec![], r#"{"sha": "cd8274d"}"#),
+        (StatusCode::CREATED, vec![], COMMIT),
+        (StatusCode::UNPROCESSABLE_ENTITY, vec![], r#"{"message": "Update is not a fast forward"}"#),
+        (StatusCode::OK, vec![], r#"{"object": {"sha": "bb3c2a1"}}"#),
+        (StatusCode::OK, vec![], r#"{"tree": {"sha": "5e1f0c7"}}"#),
+        (StatusCode::CREATED, vec![], r#"{"sha": "cd8274d"}"#),
+        (StatusCode::CREATED, vec![], COMMIT),
+        (StatusCode::OK, vec![], r#"{"object": {"sha": "7638417"}}"#),
+    ]);
+    let github = caller(base_url);
+
+    let changes = CommitBuilder::new("main", "Remove old.txt").delete("old.txt");
+    github.commit_changes("octocat", "hello-world", &changes).await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 10);
+    assert_eq!(body(&requests[7])["base_tree"], "5e1f0c7");
+    assert_eq!(body(&requests[8])["parents"], serde_json::json!(["bb3c2a1"]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_creates_branch() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found"}"#),
+        (StatusCode::OK, vec![], r#"{"object": {"sha": "aa218f5"}}"#),
+        (StatusCode::OK, vec![], r#"{"tree": {"sha": "9fb037a"}}"#),
+        (StatusCode::CREATED, vec![], r#"{"sha": "cd8274d"}"#),
+        (StatusCode::CREATED, vec![], COMMIT),
+        (StatusCode::CREATED, vec![], r#"{"ref": "refs/heads/feature", "object": {"sha": "7638417"}}"#),
+    ]);
+    let github = caller(base_url);
+
+    let changes = CommitBuilder::new("feature", "Remove old.txt").base("main").delete("old.txt");
+    github.commit_changes("octocat", "hello-world", &changes).await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[5].method(), http::Method::POST);
+    assert_eq!(requests[5].uri().path(), "/repos/octocat/hello-world/git/refs");
+    let create = body(&requests[5]);
+    assert_eq!(create["ref"], "refs/heads/feature");
+    assert_eq!(create["sha"], "7638417db6d59f3c431d3e1f261cc637155684cd");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_to_missing_branch_fails() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _) = serve_with_bodies(vec![(StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found"}"#)]);
+    let github = caller(base_url);
+
+    let changes = CommitBuilder::new("feature", "Remove old.txt").delete("old.txt");
+    let result = github.commit_changes("octocat", "hello-world", &changes).await;
+    assert!(matches!(result, Err(github_api::ApiError::NotFound { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_commit_chained_rename() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (StatusCode::OK, vec![], r#"{"ref": "refs/heads/main", "object": {"type": "commit", "sha": "aa218f5"}}"#),
+        (StatusCode::OK, vec![], r#"{"sha": "aa218f5", "tree": {"sha": "9fb037a"}}"#),
+        (
+            StatusCode::OK,
+            vec![],
+            r#"{"sha": "9fb037a", "truncated": false, "tree": [
+                {"path": "a.txt", "mode": "100644", "type": "blob", "sha": "44b4fc6"}
+            ]}"#,
+        ),
+        (StatusCode::CREATED, vec![], r#"{"sha": "cd8274d", "url": "", "tree": []}"#),
+        (StatusCode::CREATED, vec![], COMMIT),
+        (StatusCode::OK, vec![], r#"{"ref": "refs/heads/main", "object": {"sha": "7638417"}}"#),
+    ]);
+    let github = caller(base_url);
+
+    // `b.txt` is not in the base tree, so only `a.txt` is deleted.
+    let changes = CommitBuilder::new("main", "Rename").rename("a.txt", "b.txt").rename("b.txt", "c.txt");
+    github.commit_changes("octocat", "hello-world", &changes).await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 6);
+    assert_eq!(
+        body(&requests[3])["tree"],
+        serde_json::json!([
+            {"path": "a.txt", "mode": "100644", "type": "blob", "sha": null},
+            {"path": "c.txt", "mode": "100644", "type": "blob", "sha": "44b4fc6"},
+        ])
+    );
+    Ok(())
+}
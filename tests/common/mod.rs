@@ -0,0 +1,153 @@
+#![allow(dead_code)]
+
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use http::request::Parts;
+use http::StatusCode;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+#[cfg(feature = "hyper-client")]
+use jinxapi_github::v1_1_4::config::Configuration;
+
+/// Status, headers and body of a canned response.
+pub type Canned = (StatusCode, Vec<(&'static str, String)>, &'static str);
+
+/// Serve `responses` in turn, returning the base URL of the server.
+pub fn serve(responses: Vec<Canned>) -> String {
+    serve_recorded(responses).0
+}
+
+/// Serve `responses` in turn, returning the base URL of the server and the head of each
+/// request received.
+pub fn serve_recorded(responses: Vec<Canned>) -> (String, Arc<Mutex<Vec<Parts>>>) {
+    serve_mapped(responses, |request| request.into_parts().0)
+}
+
+/// Requests received by [`serve_with_bodies`].
+pub type Received = Arc<Mutex<Vec<Request<Vec<u8>>>>>;
+
+/// Serve `responses` in turn, after reading each request, returning the base URL of the
+/// server and each request received.
+///
+/// `{base_url}` in a header value is replaced by the base URL of the server, e.g. to link to
+/// the next page.
+pub fn serve_with_bodies(responses: Vec<Canned>) -> (String, Received) {
+    serve_mapped(responses, |request| request)
+}
+
+/// A request recorded by a test server, whose body is decoded by [`body`].
+pub trait RecordedBody {
+    fn body_bytes(&self) -> &[u8];
+}
+
+impl RecordedBody for Request<Vec<u8>> {
+    fn body_bytes(&self) -> &[u8] {
+        self.body()
+    }
+}
+
+#[cfg(feature = "testing")]
+impl RecordedBody for jinxapi_github::v1_1_4::testing::RecordedRequest {
+    fn body_bytes(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/// Body of a recorded request, decoded as JSON.
+pub fn body(request: &impl RecordedBody) -> serde_json::Value {
+    serde_json::from_slice(request.body_bytes()).unwrap()
+}
+
+/// Sleep function recording each duration in `slept`, without waiting.
+pub fn recording_sleep(
+    slept: &Arc<Mutex<Vec<std::time::Duration>>>,
+) -> impl Fn(std::time::Duration) -> std::future::Ready<()> + Clone + Send + 'static {
+    let slept = slept.clone();
+    move |duration| {
+        slept.lock().unwrap().push(duration);
+        std::future::ready(())
+    }
+}
+
+/// Serve `responses` in turn, recording each request received as returned by `record`.
+///
+/// Requests after the last response receive `500 Internal Server Error`.
+fn serve_mapped<R: Send + 'static>(
+    responses: Vec<Canned>,
+    record: fn(Request<Vec<u8>>) -> R,
+) -> (String, Arc<Mutex<Vec<R>>>) {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let responses = Arc::new(responses);
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let count = Arc::new(AtomicUsize::new(0));
+    let make_service = {
+        let base_url = base_url.clone();
+        let requests = requests.clone();
+        make_service_fn(move |_| {
+            let base_url = base_url.clone();
+            let responses = responses.clone();
+            let requests = requests.clone();
+            let count = count.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let base_url = base_url.clone();
+                    let responses = responses.clone();
+                    let requests = requests.clone();
+                    let index = count.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        let (parts, body) = request.into_parts();
+                        let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+                        requests.lock().unwrap().push(record(Request::from_parts(parts, body.to_vec())));
+                        // Answer unexpected requests with an error, rather than panic in
+                        // the server, so that the test fails instead of waiting.
+                        let (status, headers, body) = match responses.get(index) {
+                            Some(response) => response,
+                            None => {
+                                let message = format!("Unexpected request {} of {}", index + 1, responses.len());
+                                let response = Response::builder()
+                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                    .body(Body::from(format!(r#"{{"message": "{}"}}"#, message)));
+                                return Ok::<_, Infallible>(response.unwrap());
+                            }
+                        };
+                        let mut builder = Response::builder().status(*status);
+                        for (name, value) in headers {
+                            builder = builder.header(*name, value.replace("{base_url}", &base_url));
+                        }
+                        Ok::<_, Infallible>(builder.body(Body::from(*body)).unwrap())
+                    }
+                }))
+            }
+        })
+    };
+    let server = Server::from_tcp(listener).unwrap().serve(make_service);
+    tokio::spawn(server);
+    (base_url, requests)
+}
+
+/// Hyper `Caller` used by the tests.
+#[cfg(feature = "hyper-client")]
+pub type Caller = jinxapi_github::v1_1_4::hyper::Caller<
+    hyper::client::HttpConnector,
+    fn(std::time::Duration) -> tokio::time::Sleep,
+    tokio::time::Sleep,
+    (),
+>;
+
+/// Caller of the API at `base_url`, e.g. as returned by [`serve_with_bodies`].
+#[cfg(feature = "hyper-client")]
+pub fn caller(base_url: String) -> Caller {
+    caller_with(Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    })
+}
+
+/// Caller using `config`.
+#[cfg(feature = "hyper-client")]
+pub fn caller_with(config: Configuration) -> Caller {
+    jinxapi_github::v1_1_4::hyper::Caller::new(hyper::Client::new(), config, tokio::time::sleep)
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "testing")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+use serde_json::{json, Value};
+
+mod common;
+
+use common::body;
+use github_api::contents::{CommitOptions, Signature};
+use github_api::testing::{Expectation, MockTransport};
+
+type Caller = github_api::hyper::Caller<
+    github_api::testing::MockConnector,
+    fn(std::time::Duration) -> tokio::time::Sleep,
+    tokio::time::Sleep,
+    (),
+>;
+
+fn caller(mock: &MockTransport) -> Caller {
+    github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep)
+}
+
+/// An entry of type `kind` at `path`, as returned by `repos_get_content`.
+fn entry(kind: &str, path: &str, sha: &str, size: i64) -> Value {
+    json!({
+        "type": kind,
+        "size": size,
+        "name": path.rsplit('/').next(),
+        "path": path,
+        "sha": sha,
+        "url": "",
+        "git_url": null,
+        "html_url": null,
+        "download_url": null,
+        "_links": {"self": ""},
+    })
+}
+
+/// A file at `path`, with its `content` encoded as `encoding`.
+fn file(path: &str, sha: &str, size: i64, encoding: &str, content: &str) -> Value {
+    let mut file = entry("file", path, sha, size);
+    file["encoding"] = encoding.into();
+    file["content"] = content.into();
+    file
+}
+
+fn commit() -> Value {
+    json!({"content": null, "commit": {"sha": "7638417db6d59f3c431d3e1f261cc637155684cd"}})
+}
+
+#[tokio::test]
+async fn test_read_file() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("repos_get_content")
+            .param("path", "docs/README.md")
+            .respond(200, file("docs/README.md", "3d21ec53a331a6f037a91c368710b99387d012c1", 12, "base64", "SGVsbG8g\nd29ybGQh\n")),
+    );
+    let github = caller(&mock);
+
+    let file = github.read_file("octocat", "hello-world", "docs/README.md", Some("main")).await?;
+    assert_eq!(file.content, b"Hello world!");
+    assert_eq!(file.entry.r#type, "file");
+    assert_eq!(file.entry.sha, "3d21ec53a331a6f037a91c368710b99387d012c1");
+
+    assert_eq!(mock.requests()[0].uri.query(), Some("ref=main"));
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_large_file() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("repos_get_content")
+            .respond(200, file("data.bin", "95b966ae1c166bd92f8ae7d1c313e738c731dfc3", 2097152, "none", "")),
+    );
+    mock.expect(
+        Expectation::new("git_get_blob")
+            .param("file_sha", "95b966ae1c166bd92f8ae7d1c313e738c731dfc3")
+            .respond(200, json!({
+                "content": "Q29udGVudCBvZiB0aGUgYmxvYg==\n",
+                "encoding": "base64",
+                "url": "",
+                "node_id": "",
+                "sha": "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+                "size": 19,
+            })),
+    );
+    let github = caller(&mock);
+
+    let file = github.read_file("octocat", "hello-world", "data.bin", None).await?;
+    assert_eq!(file.content, b"Content of the blob");
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_dir() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mut symlink = entry("symlink", "src/docs", "b4c4a6e", 9);
+    symlink["target"] = "README.md".into();
+    let mut submodule = entry("submodule", "src/vendor", "fa8f3b2", 0);
+    submodule["submodule_git_url"] = "git://github.com/octocat/vendor.git".into();
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("repos_get_content")
+            .param("path", "src")
+            .respond(200, json!([
+                entry("file", "src/README.md", "3d21ec5", 12),
+                entry("dir", "src/bin", "a84d88e", 0),
+                symlink,
+                submodule,
+            ]))
+            .times(2),
+    );
+    let github = caller(&mock);
+
+    let entries = github.list_dir("octocat", "hello-world", "src", None).await?;
+    let kinds: Vec<&str> = entries.iter().map(|entry| entry.r#type.as_ref()).collect();
+    assert_eq!(kinds, ["file", "dir", "symlink", "submodule"]);
+    assert_eq!(entries[2].target.as_deref(), Some("README.md"));
+    assert_eq!(entries[3].submodule_git_url.as_deref(), Some("git://github.com/octocat/vendor.git"));
+
+    assert!(github.read_file("octocat", "hello-world", "src", None).await.is_err());
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_file_retries_conflict() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("repos_get_content").respond(404, json!({"message": "Not Found"})));
+    mock.expect(
+        Expectation::new("repos_create_or_update_file_contents")
+            .respond(409, json!({"message": "is at 3d21ec5 but expected none"})),
+    );
+    mock.expect(Expectation::new("repos_get_content").respond(200, file("a.txt", "3d21ec5", 2, "base64", "aGk=")));
+    mock.expect(Expectation::new("repos_create_or_update_file_contents").respond(200, commit()));
+    let github = caller(&mock);
+
+    let options = CommitOptions::new("Update a.txt")
+        .with_branch("main")
+        .with_committer(Signature { name: "Monalisa Octocat", email: "octocat@github.com" });
+    let commit = github.write_file("octocat", "hello-world", "a.txt", b"hello", &options).await?;
+    assert_eq!(commit.commit.sha.as_deref(), Some("7638417db6d59f3c431d3e1f261cc637155684cd"));
+
+    let requests = mock.requests();
+    let create = body(&requests[1]);
+    assert_eq!(create["content"], "aGVsbG8=");
+    assert_eq!(create["branch"], "main");
+    assert_eq!(create["committer"]["email"], "octocat@github.com");
+    assert!(create.get("sha").is_none());
+    assert_eq!(requests[2].uri.query(), Some("ref=main"));
+    assert_eq!(body(&requests[3])["sha"], "3d21ec5");
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_file_retries_created() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    // The file is created by another commit between looking up its `sha` and creating it.
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("repos_get_content").respond(404, json!({"message": "Not Found"})));
+    mock.expect(
+        Expectation::new("repos_create_or_update_file_contents")
+            .respond(422, json!({"message": "Invalid request.\n\n\"sha\" wasn't supplied."})),
+    );
+    mock.expect(Expectation::new("repos_get_content").respond(200, file("a.txt", "3d21ec5", 2, "base64", "aGk=")));
+    mock.expect(Expectation::new("repos_create_or_update_file_contents").respond(200, commit()));
+    let github = caller(&mock);
+
+    github
+        .write_file("octocat", "hello-world", "a.txt", b"hello", &CommitOptions::new("Create a.txt"))
+        .await?;
+
+    let requests = mock.requests();
+    assert!(body(&requests[1]).get("sha").is_none());
+    assert_eq!(body(&requests[3])["sha"], "3d21ec5");
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_file_with_sha_fails_on_conflict() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("repos_create_or_update_file_contents")
+            .respond(409, json!({"message": "a.txt does not match 3d21ec5"})),
+    );
+    let github = caller(&mock);
+
+    let options = CommitOptions::new("Update a.txt").with_sha("3d21ec5");
+    let result = github.write_file("octocat", "hello-world", "a.txt", b"hello", &options).await;
+    assert!(matches!(result, Err(github_api::ApiError::GitHub { status: StatusCode::CONFLICT, .. })));
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_file() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("repos_get_content").respond(200, file("a.txt", "3d21ec5", 2, "base64", "aGk=")));
+    mock.expect(
+        Expectation::new("repos_delete_file")
+            .param("path", "a.txt")
+            .respond(200, commit()),
+    );
+    let github = caller(&mock);
+
+    github
+        .delete_file("octocat", "hello-world", "a.txt", &CommitOptions::new("Remove a.txt"))
+        .await?;
+
+    let delete = body(&mock.requests()[1]);
+    assert_eq!(delete["sha"], "3d21ec5");
+    assert_eq!(delete["message"], "Remove a.txt");
+    mock.verify();
+    Ok(())
+}
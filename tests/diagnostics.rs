@@ -0,0 +1,55 @@
+use jinxapi_github::v1_1_4 as github_api;
+
+use github_api::diagnostics::Annotator;
+
+const CLIPPY: &str = r#"{"reason":"compiler-artifact","package_id":"hello 0.1.0","target":{"name":"hello"},"fresh":true}
+{"reason":"compiler-message","package_id":"hello 0.1.0","manifest_path":"/work/hello/crates/hello/Cargo.toml","message":{"rendered":"warning: unneeded `return` statement\n --> crates/hello/src/lib.rs:3:5\n","children":[],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"byte_end":40,"byte_start":30,"column_end":15,"column_start":5,"expansion":null,"file_name":"crates/hello/src/lib.rs","is_primary":true,"label":null,"line_end":3,"line_start":3,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}
+Compiling hello v0.1.0 (/work/hello/crates/hello)
+{"reason":"compiler-message","package_id":"hello 0.1.0","manifest_path":"/work/hello/crates/hello/Cargo.toml","message":{"rendered":"warning: unneeded `return` statement\n --> crates/hello/src/lib.rs:3:5\n","children":[],"code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"byte_end":40,"byte_start":30,"column_end":15,"column_start":5,"expansion":null,"file_name":"crates/hello/src/lib.rs","is_primary":true,"label":null,"line_end":3,"line_start":3,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}
+{"reason":"compiler-message","package_id":"hello 0.1.0","manifest_path":"/work/hello/crates/hello/Cargo.toml","message":{"rendered":"error[E0308]: mismatched types\n","children":[{"children":[],"code":null,"level":"note","message":"expected `u32`, found `&str`","rendered":null,"spans":[]}],"code":{"code":"E0308","explanation":"..."},"level":"error","message":"mismatched types","spans":[{"byte_end":90,"byte_start":60,"column_end":2,"column_start":9,"expansion":null,"file_name":"crates/hello/src/main.rs","is_primary":true,"label":"expected `u32`","line_end":12,"line_start":10,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}]}}
+{"reason":"compiler-message","package_id":"hello 0.1.0","manifest_path":"/work/hello/crates/hello/Cargo.toml","message":{"rendered":"error: format argument must be a string literal\n","children":[],"code":null,"level":"error","message":"format argument must be a string literal","spans":[{"byte_end":10,"byte_start":0,"column_end":30,"column_start":1,"file_name":"/rustc/library/core/src/macros/mod.rs","is_primary":true,"line_end":1,"line_start":1,"expansion":{"def_site_span":null,"macro_decl_name":"println!","span":{"byte_end":10,"byte_start":0,"column_end":26,"column_start":5,"expansion":null,"file_name":"crates/hello/src/main.rs","is_primary":false,"line_end":20,"line_start":20}}}]}}
+{"reason":"compiler-message","package_id":"hello 0.1.0","manifest_path":"/work/hello/crates/hello/Cargo.toml","message":{"rendered":"error: aborting due to 2 previous errors\n","children":[],"code":null,"level":"error","message":"aborting due to 2 previous errors","spans":[]}}
+{"reason":"build-finished","success":false}
+"#;
+
+#[test]
+fn test_annotations() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let annotations = Annotator::new("/work/hello").annotations(CLIPPY.as_bytes())?;
+    assert_eq!(annotations.len(), 3);
+
+    let warning = &annotations[0];
+    assert_eq!(warning.path, "crates/hello/src/lib.rs");
+    assert_eq!((warning.start_line, warning.end_line), (3, 3));
+    assert_eq!((warning.start_column, warning.end_column), (Some(5), Some(14)));
+    assert_eq!(warning.annotation_level, "warning");
+    assert_eq!(warning.message, "unneeded `return` statement");
+    assert_eq!(warning.title.as_deref(), Some("clippy::needless_return"));
+    assert!(warning.raw_details.as_deref().is_some_and(|details| details.starts_with("warning: unneeded")));
+
+    let error = &annotations[1];
+    assert_eq!(error.path, "crates/hello/src/main.rs");
+    assert_eq!((error.start_line, error.end_line), (10, 12));
+    assert_eq!((error.start_column, error.end_column), (None, None));
+    assert_eq!(error.annotation_level, "failure");
+    assert_eq!(error.title.as_deref(), Some("E0308"));
+
+    // Reported at the invocation of the macro.
+    let expansion = &annotations[2];
+    assert_eq!(expansion.path, "crates/hello/src/main.rs");
+    assert_eq!(expansion.start_line, 20);
+    assert_eq!(expansion.title, None);
+    Ok(())
+}
+
+#[test]
+fn test_workspace_root() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let line = r#"{"reason":"compiler-message","message":{"rendered":null,"code":null,"level":"warning","message":"unused variable: `x`","spans":[{"column_end":10,"column_start":9,"file_name":"src/lib.rs","is_primary":true,"line_end":2,"line_start":2}]}}"#;
+
+    let annotator = Annotator::new("/work/monorepo").workspace_root("rust/./hello");
+    let annotations = annotator.annotations(line.as_bytes())?;
+    assert_eq!(annotations[0].path, "rust/hello/src/lib.rs");
+
+    let annotator = Annotator::new(".").workspace_root("../other");
+    assert!(annotator.annotations(line.as_bytes())?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "futures-util")]
+
+use std::collections::VecDeque;
+use std::future::{ready, Ready};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_executor::block_on;
+use http::{HeaderMap, StatusCode};
+use jinxapi_github::v1_1_4 as github_api;
+
+use github_api::caller::{Caller, DownloadOptions};
+use github_api::config::Configuration;
+use github_api::transport::{StreamingResponse, Transport, TransportResponse};
+use github_api::ApiError;
+
+/// Response whose body is read in the given chunks, or fails.
+struct Chunked {
+    head: http::Response<()>,
+    chunks: VecDeque<Result<Vec<u8>, ApiError>>,
+}
+
+impl TransportResponse for Chunked {
+    type Bytes = Ready<Result<http::Response<Vec<u8>>, ApiError>>;
+
+    fn status(&self) -> StatusCode {
+        self.head.status()
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        self.head.headers()
+    }
+
+    fn into_bytes(self) -> Self::Bytes {
+        let body = self.chunks.into_iter().collect::<Result<Vec<_>, _>>().map(|chunks| chunks.concat());
+        ready(body.map(|body| self.head.map(|()| body)))
+    }
+
+    fn from_bytes(response: http::Response<Vec<u8>>) -> Result<Self, ApiError> {
+        let (parts, body) = response.into_parts();
+        Ok(Chunked {
+            head: http::Response::from_parts(parts, ()),
+            chunks: VecDeque::from(vec![Ok(body)]),
+        })
+    }
+}
+
+impl StreamingResponse for Chunked {
+    type Chunk<'a> = Ready<Result<Option<Vec<u8>>, ApiError>>;
+
+    fn chunk(&mut self) -> Self::Chunk<'_> {
+        ready(self.chunks.pop_front().transpose())
+    }
+}
+
+/// Transport returning scripted responses, and recording each request.
+#[derive(Default)]
+struct Scripted {
+    responses: Mutex<VecDeque<Chunked>>,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl Scripted {
+    fn respond(&self, status: StatusCode, headers: &[(&str, &str)], chunks: Vec<Result<&str, &str>>) {
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        let mut body = VecDeque::new();
+        for chunk in chunks {
+            body.push_back(match chunk {
+                Ok(data) => Ok(data.as_bytes().to_vec()),
+                Err(message) => Err(ApiError::Other(message.to_owned())),
+            });
+        }
+        let response = Chunked {
+            head: builder.body(()).unwrap(),
+            chunks: body,
+        };
+        self.responses.lock().unwrap().push_back(response);
+    }
+}
+
+impl Transport for Scripted {
+    type Response = Chunked;
+    type Future = Ready<Result<Chunked, ApiError>>;
+
+    fn send(&self, request: http::Request<Vec<u8>>) -> Self::Future {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().pop_front();
+        ready(response.ok_or_else(|| ApiError::Other("No response left".to_owned())))
+    }
+}
+
+fn no_sleep(_: Duration) -> Ready<()> {
+    ready(())
+}
+
+#[test]
+fn test_download_resumes_after_failure() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transport = Scripted::default();
+    let location = "https://pipelines.actions.githubusercontent.com/logs.zip";
+    transport.respond(StatusCode::FOUND, &[("location", location)], vec![]);
+    transport.respond(
+        StatusCode::OK,
+        &[("accept-ranges", "bytes"), ("content-length", "11")],
+        vec![Ok("hello"), Err("connection reset")],
+    );
+    transport.respond(
+        StatusCode::PARTIAL_CONTENT,
+        &[("content-range", "bytes 5-10/11")],
+        vec![Ok(" world")],
+    );
+    let requests = transport.requests.clone();
+    let github = Caller::new(transport, Configuration::default(), no_sleep);
+
+    let response = block_on(github.actions_download_workflow_run_logs("octocat", "hello-world", 30433642))?;
+    let mut file = Vec::new();
+    let size = block_on(github.download(response, &mut file, DownloadOptions::default()))?;
+    assert_eq!(size, 11);
+    assert_eq!(file, b"hello world");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].uri(), location);
+    assert_eq!(requests[2].headers()[http::header::RANGE], "bytes=5-");
+    Ok(())
+}
+
+#[test]
+fn test_download_fails_without_ranges() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transport = Scripted::default();
+    transport.respond(
+        StatusCode::FOUND,
+        &[("location", "https://codeload.github.com/octocat/hello-world/legacy.zip/main")],
+        vec![],
+    );
+    transport.respond(StatusCode::OK, &[], vec![Ok("hello"), Err("connection reset")]);
+    let github = Caller::new(transport, Configuration::default(), no_sleep);
+
+    let response = block_on(github.repos_download_zipball_archive("octocat", "hello-world", "main"))?;
+    let result = block_on(github.download(response, Vec::new(), DownloadOptions::default()));
+    assert!(matches!(result, Err(ApiError::Other(message)) if message == "connection reset"));
+    Ok(())
+}
+
+#[test]
+fn test_download_not_modified() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transport = Scripted::default();
+    transport.respond(StatusCode::NOT_MODIFIED, &[], vec![]);
+    let requests = transport.requests.clone();
+    let github = Caller::new(transport, Configuration::default(), no_sleep);
+
+    // A 304 has no Location, and is reported as the status of the response.
+    let response = block_on(github.repos_download_tarball_archive("octocat", "hello-world", "main"))?;
+    let result = block_on(github.download(response, Vec::new(), DownloadOptions::default()));
+    assert!(matches!(result, Err(ApiError::GitHub { status: StatusCode::NOT_MODIFIED, .. })));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    Ok(())
+}
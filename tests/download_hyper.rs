@@ -0,0 +1,165 @@
+#![cfg(feature = "hyper-client")]
+
+use std::sync::{Arc, Mutex};
+
+use authentic::credential::TokenCredential;
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{caller_with, serve_recorded};
+use github_api::config::{Authentication, Configuration};
+use github_api::hyper::{DownloadOptions, Progress};
+
+/// Configuration authenticating using an access token.
+fn config(base_url: String) -> Configuration {
+    Configuration {
+        base_url: base_url.into(),
+        authentication: Authentication::AccessToken(Arc::new(TokenCredential::new(b"secret".to_vec()))),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_download_redirect() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (storage_url, storage_requests) = serve_recorded(vec![(
+        StatusCode::OK,
+        vec![("content-length", "11".to_owned())],
+        "PK artifact",
+    )]);
+    let (base_url, requests) = serve_recorded(vec![(
+        StatusCode::FOUND,
+        vec![("location", format!("{}/artifacts/1?sig=abc", storage_url))],
+        "",
+    )]);
+    let github = caller_with(config(base_url));
+
+    let response = github.actions_download_artifact("octocat", "hello-world", 1, "zip").await?;
+    let progress = Mutex::new(Vec::new());
+    let mut file = Vec::new();
+    let options = DownloadOptions::default().on_progress(|update| progress.lock().unwrap().push(update));
+    let size = github.download(response, &mut file, options).await?;
+    assert_eq!(size, 11);
+    assert_eq!(file, b"PK artifact");
+    assert_eq!(
+        progress.into_inner().unwrap().last(),
+        Some(&Progress { downloaded: 11, total: Some(11) })
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers[http::header::AUTHORIZATION], "token secret");
+    let storage_requests = storage_requests.lock().unwrap();
+    assert_eq!(storage_requests[0].uri, "/artifacts/1?sig=abc");
+    assert!(!storage_requests[0].headers.contains_key(http::header::AUTHORIZATION));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_same_host() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::FOUND, vec![("location", "/storage/logs.zip".to_owned())], ""),
+        (StatusCode::OK, vec![], "logs"),
+    ]);
+    let github = caller_with(config(base_url));
+
+    let response = github.actions_download_workflow_run_logs("octocat", "hello-world", 30433642).await?;
+    let mut file = Vec::new();
+    github.download(response, &mut file, DownloadOptions::default()).await?;
+    assert_eq!(file, b"logs");
+
+    // Credentials are only sent to the host of the API.
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[1].uri, "/storage/logs.zip");
+    assert_eq!(requests[1].headers[http::header::AUTHORIZATION], "token secret");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_resume_from() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (storage_url, storage_requests) = serve_recorded(vec![
+        (
+            StatusCode::PARTIAL_CONTENT,
+            vec![("content-range", "bytes 6-10/11".to_owned())],
+            "world",
+        ),
+        // A server ignoring the range returns the whole file.
+        (StatusCode::OK, vec![], "hello world"),
+    ]);
+    let (base_url, _) = serve_recorded(vec![
+        (StatusCode::FOUND, vec![("location", format!("{}/tarball", storage_url))], ""),
+        (StatusCode::FOUND, vec![("location", format!("{}/tarball", storage_url))], ""),
+    ]);
+    let github = caller_with(config(base_url));
+
+    for _ in 0..2 {
+        let response = github.repos_download_tarball_archive("octocat", "hello-world", "main").await?;
+        let mut file = b"hello ".to_vec();
+        let size = github.download(response, &mut file, DownloadOptions::default().resume_from(6)).await?;
+        assert_eq!(size, 11);
+        assert_eq!(file, b"hello world");
+    }
+
+    let storage_requests = storage_requests.lock().unwrap();
+    assert_eq!(storage_requests[0].headers[http::header::RANGE], "bytes=6-");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_refuses_other_schemes() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _) = serve_recorded(vec![(
+        StatusCode::FOUND,
+        vec![("location", "ftp://example.com/archive.zip".to_owned())],
+        "",
+    )]);
+    let github = caller_with(config(base_url));
+
+    let response = github.migrations_download_archive_for_org("octo-org", 79).await?;
+    let result = github.download(response, Vec::new(), DownloadOptions::default()).await;
+    assert!(matches!(result, Err(github_api::ApiError::Other(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_relative_location() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::FOUND, vec![("location", "../../storage/logs".to_owned())], ""),
+        (StatusCode::FOUND, vec![("location", "logs.zip?sig=abc".to_owned())], ""),
+        (StatusCode::OK, vec![], "logs"),
+    ]);
+    let github = caller_with(config(base_url));
+
+    let response = github.actions_download_workflow_run_logs("octocat", "hello-world", 30433642).await?;
+    let mut file = Vec::new();
+    github.download(response, &mut file, DownloadOptions::default()).await?;
+    assert_eq!(file, b"logs");
+
+    // Each Location is resolved against the URL that was redirected.
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].uri, "/repos/octocat/hello-world/actions/runs/30433642/logs");
+    assert_eq!(requests[1].uri, "/repos/octocat/hello-world/actions/storage/logs");
+    assert_eq!(requests[2].uri, "/repos/octocat/hello-world/actions/storage/logs.zip?sig=abc");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_not_cached() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let file = (StatusCode::OK, vec![("etag", r#""a1b2c3""#.to_owned())], "PK archive");
+    let (base_url, requests) = serve_recorded(vec![file.clone(), file]);
+    let github = caller_with(Configuration {
+        cache: Some(Arc::new(github_api::cache::LruCache::new(10))),
+        ..config(base_url)
+    });
+
+    for _ in 0..2 {
+        let response = github.repos_download_zipball_archive("octocat", "hello-world", "main").await?;
+        let mut file = Vec::new();
+        github.download(response, &mut file, DownloadOptions::default()).await?;
+        assert_eq!(file, b"PK archive");
+    }
+
+    // A file returned without a redirect is streamed, not kept in the cache.
+    let requests = requests.lock().unwrap();
+    assert!(!requests[1].headers.contains_key(http::header::IF_NONE_MATCH));
+    Ok(())
+}
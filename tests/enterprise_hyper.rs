@@ -0,0 +1,90 @@
+#![cfg(feature = "hyper-client")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{caller, caller_with, serve_recorded};
+use github_api::config::Configuration;
+use github_api::enterprise::{required_feature, ServerVersion};
+use github_api::ApiError;
+
+#[test]
+fn test_enterprise_urls() {
+    let config = Configuration::enterprise("github.example.com");
+    assert_eq!(config.base_url, "https://github.example.com/api/v3");
+    assert_eq!(config.uploads_base_url, "https://github.example.com/api/uploads");
+    assert_eq!(config.graphql_url, "https://github.example.com/api/graphql");
+    assert!(config.enterprise_server);
+
+    let config = Configuration::enterprise("http://localhost:8080/");
+    assert_eq!(config.base_url, "http://localhost:8080/api/v3");
+}
+
+#[test]
+fn test_required_feature() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let actions = required_feature("/repos/octocat/actions/actions/workflows").unwrap();
+    assert_eq!(actions.minimum_version, Some("3.0".parse::<ServerVersion>()?));
+    assert!(actions.is_supported(ServerVersion::new(3, 4, 2)));
+    assert!(!actions.is_supported(ServerVersion::new(2, 22, 9)));
+    assert_eq!(required_feature("/orgs/octo-org/settings/billing/actions").unwrap().name, "Billing");
+    assert_eq!(required_feature("/repos/octocat/hello-world/dependabot/secrets").unwrap().name, "Dependabot");
+    assert_eq!(required_feature("/repos/octocat/hello-world/code-scanning/sarifs").unwrap().name, "Code scanning");
+    assert_eq!(required_feature("/orgs/octo-org/secret-scanning/alerts").unwrap().name, "Secret scanning");
+    assert_eq!(required_feature("/orgs/octo-org/packages").unwrap().name, "GitHub Packages");
+    assert_eq!(required_feature("/orgs/octo-org/codespaces").unwrap().minimum_version, None);
+    assert_eq!(required_feature("/user/codespaces/secrets").unwrap().name, "Codespaces");
+    // The repository name is not a feature.
+    assert_eq!(required_feature("/repos/octocat/import/issues"), None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unsupported_on_server() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (
+            StatusCode::OK,
+            vec![("x-github-enterprise-version", "2.22.9".to_owned())],
+            r#"{"verifiable_password_authentication": true}"#,
+        ),
+        (StatusCode::OK, vec![], r#"{"name": "hello-world"}"#),
+    ]);
+    let github = caller_with(Configuration::enterprise(&base_url));
+
+    match github.actions_list_repo_workflows("octocat", "hello-world", None, None).await {
+        Err(ApiError::UnsupportedOnServer { feature, server_version, minimum_version }) => {
+            assert_eq!(feature, "GitHub Actions");
+            assert_eq!(server_version, ServerVersion::new(2, 22, 9));
+            assert_eq!(minimum_version, Some(ServerVersion::new(3, 0, 0)));
+        }
+        result => panic!("Unexpected result {:?}", result.map(|response| response.status())),
+    }
+    let error = github.billing_get_github_actions_billing_org("octo-org").await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Billing is only available on GitHub.com (server version 2.22.9)"
+    );
+
+    let response = github.repos_get("octocat", "hello-world").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The server version is requested once, and unsupported operations are not sent.
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].uri.path(), "/api/v3/meta");
+    assert_eq!(requests[1].uri.path(), "/api/v3/repos/octocat/hello-world");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_server_version() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _) = serve_recorded(vec![(StatusCode::OK, vec![], r#"{"installed_version": "3.4.2"}"#)]);
+    let github = caller_with(Configuration::enterprise(&base_url));
+    assert_eq!(github.server_version().await?, Some(ServerVersion::new(3, 4, 2)));
+
+    let (base_url, _) = serve_recorded(vec![(StatusCode::OK, vec![], r#"{"verifiable_password_authentication": true}"#)]);
+    let github = caller(base_url);
+    assert_eq!(github.server_version().await?, None);
+    Ok(())
+}
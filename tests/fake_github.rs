@@ -0,0 +1,168 @@
+#![cfg(feature = "testing")]
+
+use http::StatusCode;
+use jinxapi_github::types::Sort;
+use jinxapi_github::v1_1_4 as github_api;
+
+use github_api::request::{issues_create, issues_get, issues_list_for_repo, issues_update, pulls_create, rate_limit_get, users_list};
+use github_api::testing::{operation_for, operations, FakeGitHub};
+
+#[tokio::test]
+async fn test_issues() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let server = FakeGitHub::start()?;
+    server.create_repo("octocat", "Hello-World");
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), server.config(), tokio::time::sleep);
+
+    let issue = issues_create::body::Json {
+        title: "Found a bug".into(),
+        body: Some("I'm having a problem with this.".into()),
+        assignee: None,
+        milestone: None,
+        labels: Some(vec!["bug".into()].into()),
+        assignees: None,
+        additionalProperties: Default::default(),
+    };
+    let response = github.issues_create("octocat", "hello-world", &issue).await?;
+    let number = match issues_create::response::hyper_response(response).await? {
+        issues_create::response::Response::Created201(issue) => {
+            assert_eq!(issue.title, "Found a bug");
+            assert_eq!(issue.labels[0]["name"], "bug");
+            issue.number
+        }
+        response => panic!("Unexpected response {:?}", response),
+    };
+
+    let update = issues_update::body::Json {
+        state: Some("closed".into()),
+        ..Default::default()
+    };
+    github.issues_update("octocat", "hello-world", number, &update).await?;
+
+    let response = github.issues_get("octocat", "hello-world", number).await?;
+    match issues_get::response::hyper_response(response).await? {
+        issues_get::response::Response::Ok200(issue) => assert_eq!(issue.state, "closed"),
+        response => panic!("Unexpected response {:?}", response),
+    }
+
+    let pull = pulls_create::body::Json {
+        title: Some("Amazing new feature".into()),
+        head: "octocat:new-feature".into(),
+        base: "main".into(),
+        ..Default::default()
+    };
+    github.pulls_create("octocat", "hello-world", &pull).await?;
+
+    // Open issues include pull requests.
+    let response = github
+        .issues_list_for_repo("octocat", "hello-world", None, None, None, None, None, None, &Sort::Default, None, None, None)
+        .await?;
+    match issues_list_for_repo::response::hyper_response(response).await? {
+        issues_list_for_repo::response::Response::Ok200(issues) => {
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].title, "Amazing new feature");
+            assert!(issues[0].pull_request.is_some());
+        }
+        response => panic!("Unexpected response {:?}", response),
+    }
+
+    let operations = server.requests().iter().map(|request| request.operation).collect::<Vec<_>>();
+    assert_eq!(
+        operations,
+        [
+            Some("issues_create"),
+            Some("issues_update"),
+            Some("issues_get"),
+            Some("pulls_create"),
+            Some("issues_list_for_repo")
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_not_found() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let server = FakeGitHub::start()?;
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), server.config(), tokio::time::sleep);
+
+    let response = github.issues_get("octocat", "hello-world", 1).await?;
+    assert!(matches!(
+        github_api::hyper::error_for_status(response).await,
+        Err(github_api::ApiError::NotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_example_response() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let server = FakeGitHub::start()?;
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), server.config(), tokio::time::sleep);
+
+    let response = github.rate_limit_get().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(matches!(
+        rate_limit_get::response::hyper_response(response).await?,
+        rate_limit_get::response::Response::Ok200(_)
+    ));
+
+    // Examples come from the API description, rather than empty strings and zeroes.
+    let response = github.users_list(None, None).await?;
+    match users_list::response::hyper_response(response).await? {
+        users_list::response::Response::Ok200(users) => {
+            assert_eq!(users[0].login, "octocat");
+            assert_eq!(users[0].id, 1);
+        }
+        response => panic!("Unexpected response {:?}", response),
+    }
+
+    let response = github.repos_delete_file("octocat", "hello-world", "src/lib.rs", &Default::default()).await?;
+    assert_eq!(server.requests()[2].operation, Some("repos_delete_file"));
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let server = FakeGitHub::start()?;
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), server.config(), tokio::time::sleep);
+
+    let asset = github_api::hyper::Asset::new(futures_util::io::Cursor::new(b"notes".to_vec()), 5);
+    let response = github
+        .upload_release_asset("octocat", "hello-world", 1, "NOTES.txt", None, asset)
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let requests = server.requests();
+    assert_eq!(requests[0].operation, Some("repos_upload_release_asset"));
+    assert_eq!(requests[0].body, b"notes");
+
+    Ok(())
+}
+
+#[test]
+fn test_routes() {
+    // Every operation is reachable, even when another template with a parameter in place
+    // of a literal segment also matches its path.
+    for (operation, method, path) in operations() {
+        let path = path
+            .split('/')
+            .map(|segment| if segment.starts_with('{') { "1" } else { segment })
+            .collect::<Vec<_>>()
+            .join("/");
+        assert_eq!(operation_for(method, &path), Some(operation), "{} {}", method, path);
+    }
+
+    assert_eq!(
+        operation_for(&http::Method::GET, "/repos/octocat/hello-world/issues/comments"),
+        Some("issues_list_comments_for_repo")
+    );
+    assert_eq!(
+        operation_for(&http::Method::GET, "/repos/octocat/hello-world/issues/1347"),
+        Some("issues_get")
+    );
+    assert_eq!(
+        operation_for(&http::Method::GET, "/repos/octocat/hello-world/contents/src/lib.rs"),
+        Some("repos_get_content")
+    );
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "reqwest-async")]
+
+use std::sync::Arc;
+
+use http::{Method, StatusCode};
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::serve_recorded;
+use github_api::config::{Authentication, Configuration};
+use github_api::installation::InstallationCredential;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("data/app-private-key.pem");
+
+#[tokio::test]
+async fn test_installation_token() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::CREATED, vec![], r#"{"token": "ghs_first", "expires_at": "2099-01-01T00:00:00Z"}"#),
+        (StatusCode::OK, vec![], "Design for failure."),
+    ]);
+
+    let credential = InstallationCredential::new(12345, PRIVATE_KEY, 42)?;
+    let config = Configuration {
+        authentication: Authentication::Installation(Arc::new(credential)),
+        base_url: base_url.into(),
+        ..Default::default()
+    };
+    let github = github_api::reqwest::Caller::new(reqwest::Client::new(), config, tokio::time::sleep);
+
+    assert_eq!(github.meta_get_zen().await?.status(), StatusCode::OK);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[1].headers["authorization"], "token ghs_first");
+
+    Ok(())
+}
@@ -35,7 +35,7 @@ fn test_markdown_render_json() -> Result<(), Box<dyn std::error::Error>> {
         ..Default::default()
     };
 
-    let mut response = github.markdown_render(&json)?;
+    let mut response = github.call(|github| github.markdown_render(&json))?;
 
     assert_eq!(response.status(), StatusCode::OK);
 
@@ -0,0 +1,123 @@
+#![cfg(feature = "hyper-client")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{caller, serve_recorded};
+use github_api::media::{Diff, HtmlJson, Json, Raw, TextMatch};
+use github_api::request::{issues_get, repos_get_content};
+
+#[tokio::test]
+async fn test_diff() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let diff = "diff --git a/README b/README\n";
+    let (base_url, requests) = serve_recorded(vec![(StatusCode::OK, vec![], diff)]);
+    let github = caller(base_url);
+
+    let response: String = github.pulls_get_as("octocat", "hello-world", 1347, Diff).await?;
+    assert_eq!(response, diff);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].uri.path(), "/repos/octocat/hello-world/pulls/1347");
+    assert_eq!(requests[0].headers[http::header::ACCEPT], "application/vnd.github.v3.diff");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_and_json() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::OK, vec![], "Hello World!\n"),
+        (StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#),
+        (StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#),
+    ]);
+    let github = caller(base_url);
+
+    let raw: Vec<u8> = github.repos_get_content_as("octocat", "hello-world", "README", None, Raw).await?;
+    assert_eq!(raw, b"Hello World!\n");
+
+    // The raw representation returns error responses as errors.
+    match github.repos_get_content_as("octocat", "hello-world", "MISSING", None, Raw).await {
+        Err(github_api::ApiError::NotFound { message, .. }) => assert_eq!(message, "Not Found"),
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    // The JSON representation decodes the responses described for the operation.
+    match github.repos_get_content_as("octocat", "hello-world", "MISSING", None, Json).await? {
+        repos_get_content::response::Response::NotFound404(error) => assert_eq!(error.message.as_deref(), Some("Not Found")),
+        response => panic!("Unexpected response {:?}", response),
+    }
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers[http::header::ACCEPT], "application/vnd.github.v3.raw");
+    assert_eq!(requests[2].headers[http::header::ACCEPT], "application/vnd.github.v3+json");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_text_match() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let body = r#"{
+        "total_count": 1,
+        "incomplete_results": false,
+        "items": [{
+            "name": "hello-world",
+            "text_matches": [{
+                "object_url": "https://api.github.com/repositories/1296269",
+                "object_type": "Repository",
+                "property": "name",
+                "fragment": "hello-world",
+                "matches": [{"text": "hello", "indices": [0, 5]}]
+            }]
+        }]
+    }"#;
+    let (base_url, requests) = serve_recorded(vec![(StatusCode::OK, vec![], body)]);
+    let github = caller(base_url);
+
+    let results = github.search_repos_as("hello", None, None, None, None, TextMatch).await?;
+    assert_eq!(results.total_count, 1);
+    let item = &results.items[0];
+    assert_eq!(item.item["name"], "hello-world");
+    assert_eq!(item.text_matches[0].property.as_deref(), Some("name"));
+    assert_eq!(item.text_matches[0].matches[0].indices, vec![0, 5]);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers[http::header::ACCEPT], "application/vnd.github.v3.text-match+json");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_body_media_types() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_recorded(vec![
+        (StatusCode::OK, vec![], r#"{"id": 1, "body_html": "<p>Me too</p>"}"#),
+        (StatusCode::OK, vec![], "blob contents"),
+    ]);
+    let github = caller(base_url);
+
+    let comment = github.issues_get_comment_as("octocat", "hello-world", 1, HtmlJson).await?;
+    assert_eq!(comment["body_html"], "<p>Me too</p>");
+
+    let blob: Vec<u8> = github.git_get_blob_as("octocat", "hello-world", "3a0f86fb", Raw).await?;
+    assert_eq!(blob, b"blob contents");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers[http::header::ACCEPT], "application/vnd.github.v3.html+json");
+    assert_eq!(requests[1].uri.path(), "/repos/octocat/hello-world/git/blobs/3a0f86fb");
+    assert_eq!(requests[1].headers[http::header::ACCEPT], "application/vnd.github.v3.raw");
+    Ok(())
+}
+
+#[test]
+fn test_body_media_type_output() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    use issues_get::media::MediaType;
+
+    // The body media types decode into the same response type as `Json`.
+    let mut issue = serde_json::to_value(github_api::schema::Issue::default())?;
+    issue["body_html"] = "<p>I'm having a problem</p>".into();
+    match <HtmlJson as MediaType>::decode(StatusCode::OK, &http::HeaderMap::new(), &serde_json::to_vec(&issue)?) {
+        Ok(issues_get::response::Response::Ok200(issue)) => {
+            assert_eq!(issue.body_html.as_deref(), Some("<p>I'm having a problem</p>"))
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+    Ok(())
+}
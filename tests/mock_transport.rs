@@ -0,0 +1,119 @@
+#![cfg(feature = "testing")]
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+use jinxapi_github::types::Sort;
+use serde_json::json;
+
+use github_api::request::{issues_create, issues_get};
+use github_api::testing::{Expectation, MockTransport};
+
+fn issue() -> issues_create::body::Json<'static> {
+    issues_create::body::Json {
+        title: "Found a bug".into(),
+        body: None,
+        assignee: None,
+        milestone: None,
+        labels: None,
+        assignees: None,
+        additionalProperties: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_scripted_response() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("issues_create")
+            .param("owner", "octocat")
+            .param("repo", "hello-world")
+            .body(json!({"title": "Found a bug"}))
+            .respond(422, json!({
+                "message": "Validation Failed",
+                "documentation_url": "https://docs.github.com/rest/reference/issues#create-an-issue",
+                "errors": [],
+            })),
+    );
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    let response = github.issues_create("octocat", "hello-world", &issue()).await?;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    match issues_create::response::hyper_response(response).await? {
+        issues_create::response::Response::UnprocessableEntity422(error) => {
+            assert_eq!(error.message, "Validation Failed")
+        }
+        response => panic!("Unexpected response {:?}", response),
+    }
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].operation, Some("issues_create"));
+    assert_eq!(requests[0].uri.path(), "/repos/octocat/hello-world/issues");
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_example_response() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("issues_get").param("issue_number", 1347).times(2));
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    for _ in 0..2 {
+        let response = github.issues_get("octocat", "hello-world", 1347).await?;
+        match issues_get::response::hyper_response(response).await? {
+            issues_get::response::Response::Ok200(_) => (),
+            response => panic!("Unexpected response {:?}", response),
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_literal_route() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    // `/repos/{owner}/{repo}/issues/comments` also matches the template of `issues_get`.
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("issues_list_comments_for_repo")
+            .param("repo", "hello-world")
+            .respond(200, json!([])),
+    );
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    let response = github
+        .issues_list_comments_for_repo("octocat", "hello-world", &Sort::Default, None, None, None)
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(mock.requests()[0].operation, Some("issues_list_comments_for_repo"));
+    mock.verify();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unmet_and_unexpected() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("issues_create").param("owner", "someone-else"));
+    mock.expect(Expectation::new("repos_delete").respond_empty(204));
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    let response = github.issues_create("octocat", "hello-world", &issue()).await?;
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+
+    let problems = mock.check().unwrap_err();
+    assert!(problems.contains("Unmet expectation: issues_create owner=someone-else (called 0 of 1 times)"));
+    assert!(problems.contains("Unmet expectation: repos_delete"));
+    assert!(problems.contains("Unexpected request: POST /repos/octocat/hello-world/issues (issues_create)"));
+
+    let response = github.repos_delete("octocat", "hello-world").await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    // The issues_create expectation is still unmet, so dropping the mock would panic.
+    std::mem::forget(mock);
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "Unmet expectation: repos_get")]
+async fn test_drop_reports_unmet() {
+    let mock = MockTransport::new();
+    mock.expect(Expectation::new("repos_get"));
+}
@@ -0,0 +1,41 @@
+use jinxapi_github::v1_1_4::pagination::{link_url, next_url};
+
+#[test]
+fn test_link_url() {
+    let link = "<https://api.github.com/user/repos?page=3&per_page=100>; rel=\"next\", \
+                <https://api.github.com/user/repos?page=50&per_page=100>; rel=\"last\"";
+    assert_eq!(
+        link_url(link, "next"),
+        Some("https://api.github.com/user/repos?page=3&per_page=100")
+    );
+    assert_eq!(
+        link_url(link, "last"),
+        Some("https://api.github.com/user/repos?page=50&per_page=100")
+    );
+    assert_eq!(link_url(link, "prev"), None);
+}
+
+#[test]
+fn test_next_url() {
+    let mut headers = http::HeaderMap::new();
+    assert_eq!(next_url(&headers).unwrap(), None);
+
+    headers.insert(
+        http::header::LINK,
+        "<https://api.github.com/user/repos?page=1>; rel=\"prev\", \
+         <https://api.github.com/user/repos?per_page=100&page=3>; rel=\"next\""
+            .parse()
+            .unwrap(),
+    );
+    assert_eq!(
+        next_url(&headers).unwrap().as_deref(),
+        Some("https://api.github.com/user/repos?per_page=100&page=3")
+    );
+
+    // Links without a page number are followed as given.
+    headers.insert(
+        http::header::LINK,
+        "<https://api.github.com/users?since=135>; rel=\"next\"".parse().unwrap(),
+    );
+    assert_eq!(next_url(&headers).unwrap().as_deref(), Some("https://api.github.com/users?since=135"));
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "hyper-client")]
+
+use futures_util::{pin_mut, TryStreamExt};
+use http::StatusCode;
+
+mod common;
+
+use common::{caller, serve_with_bodies};
+
+#[derive(Debug, serde::Deserialize)]
+struct User {
+    id: i64,
+}
+
+#[tokio::test]
+async fn test_paginate_since() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    // `users_list` pages using the `since` parameter rather than `page`.
+    let (base_url, requests) = serve_with_bodies(vec![
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/users?per_page=2&since=2>; rel=\"next\"".to_owned())],
+            r#"[{"id": 1}, {"id": 2}]"#,
+        ),
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/users?per_page=2&since=4>; rel=\"next\"".to_owned())],
+            r#"[{"id": 3}, {"id": 4}]"#,
+        ),
+        (StatusCode::OK, vec![], r#"[{"id": 5}]"#),
+    ]);
+    let github = caller(base_url);
+
+    let users = github.paginate(github.users_list(None, Some(2))).items::<User>();
+    pin_mut!(users);
+    let mut ids = Vec::new();
+    while let Some(user) = users.try_next().await? {
+        ids.push(user.id);
+    }
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+
+    let requests = requests.lock().unwrap();
+    let queries: Vec<_> = requests.iter().map(|request| request.uri().query()).collect();
+    assert_eq!(queries, [Some("per_page=2"), Some("per_page=2&since=2"), Some("per_page=2&since=4")]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_max_items() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/events?per_page=2&after=Y3Vyc29y>; rel=\"next\"".to_owned())],
+            r#"[{"id": 1}, {"id": 2}]"#,
+        ),
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/events?per_page=2&after=bmV4dA>; rel=\"next\"".to_owned())],
+            r#"{"total_count": 5, "items": [{"id": 3}, {"id": 4}]}"#,
+        ),
+    ]);
+    let github = caller(base_url);
+
+    let pages = github
+        .paginate(github.activity_list_public_events(Some(2), None))
+        .max_items(3)
+        .pages::<User>();
+    let pages: Vec<Vec<User>> = pages.try_collect().await?;
+    let sizes: Vec<_> = pages.iter().map(Vec::len).collect();
+    assert_eq!(sizes, [2, 1]);
+
+    // The third page is not requested.
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].uri().query(), Some("per_page=2&after=Y3Vyc29y"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_error() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _requests) = serve_with_bodies(vec![
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/users?since=1>; rel=\"next\"".to_owned())],
+            r#"[{"id": 1}]"#,
+        ),
+        (StatusCode::NOT_FOUND, vec![], r#"{"message": "Not Found"}"#),
+    ]);
+    let github = caller(base_url);
+
+    let users = github.paginate(github.users_list(None, None)).items::<User>();
+    pin_mut!(users);
+    assert_eq!(users.try_next().await?.map(|user| user.id), Some(1));
+    assert!(users.try_next().await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_other_host() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests) = serve_with_bodies(vec![(
+        StatusCode::OK,
+        vec![("link", "<http://example.com/users?since=1>; rel=\"next\"".to_owned())],
+        r#"[{"id": 1}]"#,
+    )]);
+    let github = caller(base_url);
+
+    let users = github.paginate(github.users_list(None, None)).items::<User>();
+    pin_mut!(users);
+    assert_eq!(users.try_next().await?.map(|user| user.id), Some(1));
+    assert!(users.try_next().await.is_err());
+
+    // The next link is not requested, with or without credentials.
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    Ok(())
+}
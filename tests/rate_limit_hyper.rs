@@ -0,0 +1,89 @@
+#![cfg(feature = "hyper-client")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::serve;
+
+fn rate_limit_headers(remaining: i64) -> Vec<(&'static str, String)> {
+    vec![
+        ("x-ratelimit-limit", "5000".to_owned()),
+        ("x-ratelimit-remaining", remaining.to_string()),
+        ("x-ratelimit-used", (5000 - remaining).to_string()),
+        ("x-ratelimit-reset", "1372700873".to_owned()),
+        ("x-ratelimit-resource", "core".to_owned()),
+    ]
+}
+
+#[tokio::test]
+async fn test_secondary_rate_limit_retry() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let base_url = serve(vec![
+        (
+            StatusCode::FORBIDDEN,
+            rate_limit_headers(4999),
+            r#"{"message": "You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#,
+        ),
+        (StatusCode::FORBIDDEN, vec![("retry-after", "30".to_owned())], "{}"),
+        (StatusCode::OK, rate_limit_headers(4998), "Keep it logically awesome."),
+    ]);
+
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let sleep = {
+        let slept = slept.clone();
+        move |duration| {
+            slept.lock().unwrap().push(duration);
+            std::future::ready(())
+        }
+    };
+
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, sleep)
+        .with_rate_limit_policy(github_api::rate_limit::RateLimitPolicy::default());
+
+    let response = github.meta_get_zen().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        *slept.lock().unwrap(),
+        vec![Duration::from_secs(60), Duration::from_secs(30)]
+    );
+
+    let rate_limit = github.rate_limit().unwrap();
+    assert_eq!(rate_limit.remaining, 4998);
+    assert_eq!(rate_limit.used, Some(2));
+    assert_eq!(rate_limit.resource.as_deref(), Some("core"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_forbidden_without_rate_limit() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let base_url = serve(vec![(
+        StatusCode::FORBIDDEN,
+        rate_limit_headers(4999),
+        r#"{"message": "Must have admin rights to Repository."}"#,
+    )]);
+
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, |_| async {
+        panic!("unexpected sleep")
+    })
+    .with_rate_limit_policy(github_api::rate_limit::RateLimitPolicy::default());
+
+    let response = github.meta_get_zen().await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    assert_eq!(&body[..], br#"{"message": "Must have admin rights to Repository."}"#);
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "reqwest-blocking")]
+
+use std::time::{Duration, Instant};
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{serve_with_bodies, Canned, Received};
+use github_api::checks::{Annotation, AnnotationLevel};
+use github_api::code_scanning::SarifOptions;
+use github_api::config::Configuration;
+use github_api::rate_limit::RateLimitPolicy;
+use github_api::reqwest::blocking::{Asset, Caller, DownloadOptions};
+
+/// Serve `responses` from a runtime of its own, which must be kept alive while the blocking
+/// client sends requests.
+fn serve(responses: Vec<Canned>) -> (String, Received, tokio::runtime::Runtime) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (base_url, requests) = {
+        let _guard = runtime.enter();
+        serve_with_bodies(responses)
+    };
+    (base_url, requests, runtime)
+}
+
+fn caller(config: Configuration) -> Caller<fn(Duration)> {
+    Caller::new(reqwest::blocking::Client::new(), config, std::thread::sleep)
+}
+
+fn base_config(base_url: String) -> Configuration {
+    Configuration {
+        base_url: base_url.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_paginate() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests, _runtime) = serve(vec![
+        (
+            StatusCode::OK,
+            vec![("link", "<{base_url}/events?per_page=2&page=2>; rel=\"next\"".to_owned())],
+            r#"[{"id": "1"}, {"id": "2"}]"#,
+        ),
+        (StatusCode::OK, vec![], r#"[{"id": "3"}]"#),
+    ]);
+    let github = caller(base_config(base_url));
+
+    #[derive(serde::Deserialize)]
+    struct Event {
+        id: String,
+    }
+
+    let ids = github
+        .paginate(github.call(|github| github.activity_list_public_events(Some(2), None)))
+        .blocking_items::<Event>()
+        .map(|event| event.map(|event| event.id))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(ids, ["1", "2", "3"]);
+    assert_eq!(requests.lock().unwrap()[1].uri().query(), Some("per_page=2&page=2"));
+    Ok(())
+}
+
+#[test]
+fn test_rate_limit_sleeps() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    // The blocking caller waits using a sleep function that really sleeps.
+    let (base_url, _requests, _runtime) = serve(vec![
+        (StatusCode::TOO_MANY_REQUESTS, vec![("retry-after", "1".to_owned())], "{}"),
+        (StatusCode::OK, vec![], "Keep it logically awesome."),
+    ]);
+    let github = caller(base_config(base_url)).with_rate_limit_policy(RateLimitPolicy::default());
+
+    let start = Instant::now();
+    let response = github.call(|github| github.meta_get_zen())?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    Ok(())
+}
+
+#[test]
+fn test_check_run() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests, _runtime) = serve(vec![
+        (StatusCode::CREATED, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+        (StatusCode::OK, vec![], r#"{"id": 4}"#),
+    ]);
+    let github = caller(base_config(base_url));
+
+    let mut run = github.start_check_run("octocat", "hello-world", "lint", "ce587453")?;
+    run.annotate_all((1..=60).map(|line| Annotation {
+        path: "src/lib.rs".into(),
+        start_line: line,
+        end_line: line,
+        annotation_level: AnnotationLevel::Warning.as_str().into(),
+        message: format!("finding {}", line).into(),
+        ..Default::default()
+    }))?;
+    run.finish()?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    let finish: serde_json::Value = serde_json::from_slice(requests[2].body())?;
+    assert_eq!(finish["conclusion"], "neutral");
+    assert_eq!(finish["output"]["annotations"].as_array().unwrap().len(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_read_file() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _requests, _runtime) = serve(vec![(
+        StatusCode::OK,
+        vec![],
+        r#"{"type": "file", "encoding": "base64", "size": 12, "name": "README.md", "path": "README.md",
+            "content": "SGVsbG8gd29ybGQh", "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+            "url": "", "git_url": null, "html_url": null, "download_url": null, "_links": {"self": ""}}"#,
+    )]);
+    let github = caller(base_config(base_url));
+
+    let file = github.read_file("octocat", "hello-world", "README.md", None)?;
+    assert_eq!(file.content, b"Hello world!");
+    Ok(())
+}
+
+#[test]
+fn test_upload_release_asset() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests, _runtime) = serve(vec![(StatusCode::CREATED, vec![], r#"{"id": 1}"#)]);
+    let github = caller(Configuration {
+        base_url: "http://api.invalid".into(),
+        uploads_base_url: uploads_url.into(),
+        ..Default::default()
+    });
+
+    let contents = b"PK release".to_vec();
+    let asset = Asset::new(std::io::Cursor::new(contents.clone()), contents.len() as u64);
+    let response = github.upload_release_asset("octocat", "hello-world", 1, "app-1.0.zip", None, asset)?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(*requests.lock().unwrap()[0].body(), contents);
+    Ok(())
+}
+
+#[test]
+fn test_download() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (storage_url, _storage_requests, _storage_runtime) = serve(vec![(
+        StatusCode::OK,
+        vec![("content-length", "11".to_owned())],
+        "PK artifact",
+    )]);
+    let (base_url, _requests, _runtime) = serve(vec![(
+        StatusCode::FOUND,
+        vec![("location", format!("{}/artifacts/1", storage_url))],
+        "",
+    )]);
+    let github = caller(base_config(base_url));
+
+    let response = github.call(|github| github.actions_download_artifact("octocat", "hello-world", 1, "zip"))?;
+    let mut file = Vec::new();
+    assert_eq!(github.download(response, &mut file, DownloadOptions::default())?, 11);
+    assert_eq!(file, b"PK artifact");
+    Ok(())
+}
+
+#[test]
+fn test_upload_sarif() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, requests, _runtime) = serve(vec![
+        (StatusCode::ACCEPTED, vec![], r#"{"id": "47177e22-5596-11eb-80a1-c1e54ef945c6"}"#),
+        (StatusCode::OK, vec![], r#"{"processing_status": "complete"}"#),
+    ]);
+    let github = caller(base_config(base_url));
+
+    let sarif = br#"{"version": "2.1.0", "runs": [{"tool": {"driver": {"name": "clippy"}}, "results": []}]}"#;
+    let options = SarifOptions::new().with_poll_interval(Duration::from_millis(1));
+    let status = github.upload_sarif("octocat", "hello-world", "ce587453", "refs/heads/main", &sarif[..], &options)?;
+    assert_eq!(status.processing_status, "complete");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_server_version() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (base_url, _requests, _runtime) = serve(vec![(
+        StatusCode::OK,
+        vec![("x-github-enterprise-version", "3.4.2".to_owned())],
+        r#"{"verifiable_password_authentication": true}"#,
+    )]);
+    let github = caller(Configuration::enterprise(&base_url));
+
+    let version = github.server_version()?;
+    assert_eq!(version, Some(github_api::enterprise::ServerVersion::new(3, 4, 2)));
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+#[test]
+fn test_decode_documented_status() -> Result<(), Box<dyn std::error::Error>> {
+    use github_api::request::rate_limit_get::response::Response;
+
+    let body = br#"{
+        "resources": {
+            "core": {"limit": 5000, "remaining": 4999, "reset": 1372700873, "used": 1},
+            "search": {"limit": 30, "remaining": 18, "reset": 1372697452, "used": 12}
+        },
+        "rate": {"limit": 5000, "remaining": 4999, "reset": 1372700873, "used": 1}
+    }"#;
+
+    match Response::from_parts(StatusCode::OK, body)? {
+        Response::Ok200(overview) => {
+            assert_eq!(overview.rate.remaining, 4999);
+            assert_eq!(overview.resources.search.used, 12);
+            assert!(overview.resources.graphql.is_none());
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_error_and_undocumented_status() -> Result<(), Box<dyn std::error::Error>> {
+    use github_api::request::orgs_list_members::response::Response;
+
+    let body = br#"{
+        "message": "Validation Failed",
+        "documentation_url": "https://docs.github.com/rest",
+        "errors": [{"resource": "Member", "field": "filter", "code": "invalid"}]
+    }"#;
+
+    match Response::from_parts(StatusCode::UNPROCESSABLE_ENTITY, body)? {
+        Response::UnprocessableEntity422(error) => {
+            let errors = error.errors.unwrap_or_default();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].code, "invalid");
+            assert_eq!(errors[0].field.as_deref(), Some("filter"));
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+
+    assert_eq!(
+        Response::from_parts(StatusCode::IM_A_TEAPOT, b"short and stout")?,
+        Response::Other(StatusCode::IM_A_TEAPOT, b"short and stout".to_vec()),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_component_schema() -> Result<(), Box<dyn std::error::Error>> {
+    use github_api::request::actions_list_workflow_runs_for_repo::response::Response;
+
+    let body = br#"{
+        "total_count": 1,
+        "workflow_runs": [{
+            "id": 30433642,
+            "name": "Build",
+            "node_id": "MDEyOldvcmtmbG93IFJ1bjI2OTI4OQ==",
+            "head_branch": "master",
+            "head_sha": "acb5820ced9479c074f688cc328bf03f341a511d",
+            "run_number": 562,
+            "event": "push",
+            "status": "queued",
+            "conclusion": null,
+            "workflow_id": 159038,
+            "url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642",
+            "html_url": "https://github.com/octo-org/octo-repo/actions/runs/30433642",
+            "pull_requests": [],
+            "created_at": "2020-01-22T19:33:08Z",
+            "updated_at": "2020-01-22T19:33:08Z",
+            "jobs_url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642/jobs",
+            "logs_url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642/logs",
+            "check_suite_url": "https://api.github.com/repos/octo-org/octo-repo/check-suites/414944374",
+            "artifacts_url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642/artifacts",
+            "cancel_url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642/cancel",
+            "rerun_url": "https://api.github.com/repos/octo-org/octo-repo/actions/runs/30433642/rerun",
+            "workflow_url": "https://api.github.com/repos/octo-org/octo-repo/actions/workflows/159038",
+            "head_commit": null,
+            "repository": {},
+            "head_repository": {}
+        }]
+    }"#;
+
+    // Nested required fields are checked, so a truncated repository fails to decode.
+    assert!(Response::from_parts(StatusCode::OK, body).is_err());
+
+    let body = std::str::from_utf8(body)?.replace(
+        "{}",
+        r#"{
+            "id": 1296269, "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5", "name": "octo-repo",
+            "full_name": "octo-org/octo-repo", "private": false, "fork": false, "description": null,
+            "html_url": "https://github.com/octo-org/octo-repo",
+            "url": "https://api.github.com/repos/octo-org/octo-repo",
+            "owner": {
+                "login": "octo-org", "id": 1, "node_id": "MDQ6VXNlcjE=", "gravatar_id": "",
+                "avatar_url": "", "url": "", "html_url": "", "followers_url": "", "following_url": "",
+                "gists_url": "", "starred_url": "", "subscriptions_url": "", "organizations_url": "",
+                "repos_url": "", "events_url": "", "received_events_url": "", "type": "Organization",
+                "site_admin": false
+            },
+            "archive_url": "", "assignees_url": "", "blobs_url": "", "branches_url": "",
+            "collaborators_url": "", "comments_url": "", "commits_url": "", "compare_url": "",
+            "contents_url": "", "contributors_url": "", "deployments_url": "", "downloads_url": "",
+            "events_url": "", "forks_url": "", "git_commits_url": "", "git_refs_url": "",
+            "git_tags_url": "", "issue_comment_url": "", "issue_events_url": "", "issues_url": "",
+            "keys_url": "", "labels_url": "", "languages_url": "", "merges_url": "",
+            "milestones_url": "", "notifications_url": "", "pulls_url": "", "releases_url": "",
+            "stargazers_url": "", "statuses_url": "", "subscribers_url": "", "subscription_url": "",
+            "tags_url": "", "teams_url": "", "trees_url": "", "hooks_url": ""
+        }"#,
+    );
+
+    match Response::from_parts(StatusCode::OK, body.as_bytes())? {
+        Response::Ok200(runs) => {
+            assert_eq!(runs.total_count, 1);
+            let run = &runs.workflow_runs[0];
+            assert_eq!(run.status.as_deref(), Some("queued"));
+            assert_eq!(run.conclusion, None);
+            assert_eq!(run.head_repository.full_name, "octo-org/octo-repo");
+            assert_eq!(run.head_repository.owner.r#type, "Organization");
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_error_response() {
+    use github_api::schema::validation_error::Errors;
+    use github_api::{ApiError, ValidationError};
+
+    let headers = http::HeaderMap::new();
+    let body = br#"{
+        "message": "Validation Failed",
+        "errors": [
+            {"resource": "Issue", "field": "title", "code": "missing_field", "index": 0},
+            "Label does not exist",
+            {"resource": "Issue", "field": "body"}
+        ],
+        "documentation_url": "https://docs.github.com/rest/reference/issues#create-an-issue"
+    }"#;
+    match ApiError::from_response(StatusCode::UNPROCESSABLE_ENTITY, &headers, body) {
+        ApiError::Validation { message, documentation_url, errors } => {
+            assert_eq!(message, "Validation Failed");
+            assert!(documentation_url.unwrap().ends_with("#create-an-issue"));
+            assert_eq!(
+                errors,
+                vec![
+                    ValidationError::Detail(Errors {
+                        resource: Some("Issue".into()),
+                        field: Some("title".into()),
+                        code: "missing_field".into(),
+                        index: Some(0),
+                        ..Default::default()
+                    }),
+                    ValidationError::Message("Label does not exist".to_owned()),
+                    // Without a `code`, the entry is kept as sent.
+                    ValidationError::Other(serde_json::json!({"resource": "Issue", "field": "body"})),
+                ]
+            );
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    let body = br#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#;
+    assert!(matches!(
+        ApiError::from_response(StatusCode::NOT_FOUND, &headers, body),
+        ApiError::NotFound { .. }
+    ));
+
+    let body = br#"{"message": "Must have admin rights to Repository."}"#;
+    assert!(matches!(
+        ApiError::from_response(StatusCode::FORBIDDEN, &headers, body),
+        ApiError::GitHub { status: StatusCode::FORBIDDEN, .. }
+    ));
+
+    let body = br#"{"message": "You have exceeded a secondary rate limit."}"#;
+    assert!(matches!(
+        ApiError::from_response(StatusCode::FORBIDDEN, &headers, body),
+        ApiError::RateLimited { rate_limit: None, .. }
+    ));
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-ratelimit-limit", "60".parse().unwrap());
+    headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+    headers.insert("x-ratelimit-reset", "1372700873".parse().unwrap());
+    let body = br#"{"message": "API rate limit exceeded for 127.0.0.1."}"#;
+    match ApiError::from_response(StatusCode::FORBIDDEN, &headers, body) {
+        ApiError::RateLimited { rate_limit: Some(rate_limit), .. } => {
+            assert_eq!(rate_limit.limit, 60);
+            assert_eq!(rate_limit.remaining, 0);
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+
+    match ApiError::from_response(StatusCode::BAD_GATEWAY, &http::HeaderMap::new(), b"") {
+        ApiError::GitHub { message, .. } => assert_eq!(message, "Bad Gateway"),
+        other => panic!("unexpected error {:?}", other),
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "hyper-client")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{recording_sleep, serve};
+
+#[tokio::test]
+async fn test_retry_idempotent() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let base_url = serve(vec![
+        (StatusCode::SERVICE_UNAVAILABLE, vec![], ""),
+        (StatusCode::BAD_GATEWAY, vec![], ""),
+        (StatusCode::OK, vec![], "Keep it logically awesome."),
+    ]);
+
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        retry_policy: Some(github_api::config::RetryPolicy {
+            initial_backoff: Duration::from_secs(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, recording_sleep(&slept));
+
+    let response = github.meta_get_zen().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let slept = slept.lock().unwrap();
+    assert_eq!(slept.len(), 2);
+    assert!(slept[0] >= Duration::from_millis(500) && slept[0] <= Duration::from_secs(1));
+    assert!(slept[1] >= Duration::from_secs(1) && slept[1] <= Duration::from_secs(2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_non_idempotent() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let base_url = serve(vec![(StatusCode::SERVICE_UNAVAILABLE, vec![], "")]);
+
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        retry_policy: Some(github_api::config::RetryPolicy::default()),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, recording_sleep(&slept));
+
+    let json = github_api::request::markdown_render::body::Json {
+        text: "Jinx!".into(),
+        ..Default::default()
+    };
+    let response = github.markdown_render(&json).await?;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(slept.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_connection_error() {
+    // Find a port with no server listening.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let config = github_api::config::Configuration {
+        base_url: base_url.into(),
+        retry_policy: Some(github_api::config::RetryPolicy {
+            max_retries: 2,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let github = github_api::hyper::Caller::new(hyper::Client::new(), config, recording_sleep(&slept));
+
+    assert!(matches!(
+        github.meta_get_zen().await,
+        Err(github_api::ApiError::Hyper { .. })
+    ));
+    assert_eq!(slept.lock().unwrap().len(), 2);
+}
@@ -0,0 +1,101 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+#[cfg(feature = "testing")]
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+#[cfg(feature = "testing")]
+use serde_json::json;
+
+use github_api::secrets::seal;
+#[cfg(feature = "testing")]
+use github_api::secrets::{SecretScope, Visibility};
+#[cfg(feature = "testing")]
+use github_api::testing::{Expectation, MockTransport};
+
+fn public_key(secret_key: &crypto_box::SecretKey) -> String {
+    STANDARD.encode(secret_key.public_key().as_bytes())
+}
+
+fn unseal(secret_key: &crypto_box::SecretKey, value: &serde_json::Value) -> Vec<u8> {
+    let sealed = STANDARD.decode(value.as_str().unwrap()).unwrap();
+    secret_key.unseal(&sealed).unwrap()
+}
+
+#[test]
+fn test_seal() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+    let sealed = seal(&public_key(&secret_key), b"hunter2")?;
+    assert_eq!(unseal(&secret_key, &sealed.into()), b"hunter2");
+
+    assert!(seal("not a key", b"hunter2").is_err());
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_set_repo_secret() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+    let mock = MockTransport::new();
+    // The public key is fetched once.
+    mock.expect(
+        Expectation::new("actions_get_repo_public_key")
+            .param("owner", "octocat")
+            .param("repo", "hello-world")
+            .respond(200, json!({"key_id": "568250167242549743", "key": public_key(&secret_key)})),
+    );
+    mock.expect(
+        Expectation::new("actions_create_or_update_repo_secret")
+            .param("secret_name", "DEPLOY_TOKEN")
+            .respond_empty(201),
+    );
+    mock.expect(
+        Expectation::new("actions_create_or_update_repo_secret")
+            .param("secret_name", "DEPLOY_TOKEN")
+            .respond_empty(204),
+    );
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    let scope = SecretScope::Repository { owner: "octocat", repo: "hello-world" };
+    let response = github.set_secret(&scope, "DEPLOY_TOKEN", b"hunter2").await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = github.set_secret(&scope, "DEPLOY_TOKEN", b"hunter3").await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let requests = mock.requests();
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body)?;
+    assert_eq!(body["key_id"], "568250167242549743");
+    assert_eq!(unseal(&secret_key, &body["encrypted_value"]), b"hunter2");
+    let body: serde_json::Value = serde_json::from_slice(&requests[2].body)?;
+    assert_eq!(unseal(&secret_key, &body["encrypted_value"]), b"hunter3");
+    mock.verify();
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_set_org_secret() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+    let mock = MockTransport::new();
+    mock.expect(
+        Expectation::new("actions_get_org_public_key")
+            .param("org", "octo-org")
+            .respond(200, json!({"key_id": "012345678912345678", "key": public_key(&secret_key)})),
+    );
+    mock.expect(
+        Expectation::new("actions_create_or_update_org_secret")
+            .param("org", "octo-org")
+            .param("secret_name", "NPM_TOKEN")
+            .respond_empty(201),
+    );
+    let github = github_api::hyper::Caller::new(mock.client(), mock.config(), tokio::time::sleep);
+
+    let scope = SecretScope::Organization { org: "octo-org", visibility: Visibility::Selected(&[1296269, 1296270]) };
+    github.set_secret(&scope, "NPM_TOKEN", b"hunter2").await?;
+
+    let body: serde_json::Value = serde_json::from_slice(&mock.requests()[1].body)?;
+    assert_eq!(body["visibility"], "selected");
+    assert_eq!(body["selected_repository_ids"], json!([1296269, 1296270]));
+    assert_eq!(unseal(&secret_key, &body["encrypted_value"]), b"hunter2");
+    mock.verify();
+    Ok(())
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "tower")]
+
+use std::collections::VecDeque;
+use std::future::{ready, Ready};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use authentic::credential::TokenCredential;
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+use tower::ServiceBuilder;
+
+mod common;
+
+use common::recording_sleep;
+use github_api::caller::Caller;
+use github_api::config::{Authentication, Configuration};
+use github_api::rate_limit::RateLimitPolicy;
+use github_api::tower::{AuthenticationLayer, DefaultHeadersLayer, RateLimitLayer, TransportService};
+use github_api::transport::Transport;
+use github_api::ApiError;
+
+/// Transport returning canned responses, and recording each request.
+#[derive(Clone, Default)]
+struct Canned {
+    responses: Arc<Mutex<VecDeque<http::Response<Vec<u8>>>>>,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl Canned {
+    fn respond(&self, status: StatusCode, headers: &[(&str, &str)], body: &str) {
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        let response = builder.body(body.as_bytes().to_vec()).unwrap();
+        self.responses.lock().unwrap().push_back(response);
+    }
+}
+
+impl Transport for Canned {
+    type Response = http::Response<Vec<u8>>;
+    type Future = Ready<Result<Self::Response, ApiError>>;
+
+    fn send(&self, request: http::Request<Vec<u8>>) -> Self::Future {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().pop_front();
+        ready(response.ok_or_else(|| ApiError::Other("No response left".to_owned())))
+    }
+}
+
+#[tokio::test]
+async fn test_service_builder() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let canned = Canned::default();
+    canned.respond(
+        StatusCode::TOO_MANY_REQUESTS,
+        &[("retry-after", "30"), ("x-ratelimit-remaining", "0")],
+        "",
+    );
+    canned.respond(
+        StatusCode::OK,
+        &[("x-ratelimit-limit", "5000"), ("x-ratelimit-remaining", "4999"), ("x-ratelimit-reset", "1650000000")],
+        "Keep it logically awesome.",
+    );
+
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let authenticated = Configuration {
+        authentication: Authentication::AccessToken(Arc::new(TokenCredential::new(b"secret".to_vec()))),
+        ..Default::default()
+    };
+    let rate_limit = RateLimitLayer::new(RateLimitPolicy::default(), recording_sleep(&slept));
+    let service = ServiceBuilder::new()
+        .layer(AuthenticationLayer::new(authenticated, recording_sleep(&slept)))
+        .layer(rate_limit.clone())
+        .service(TransportService::new(canned.clone()));
+    let github = Caller::from_service(service, Configuration::default(), recording_sleep(&slept));
+
+    let response = github.meta_get_zen().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), b"Keep it logically awesome.");
+    assert_eq!(*slept.lock().unwrap(), vec![Duration::from_secs(30)]);
+    assert_eq!(rate_limit.rate_limit().map(|state| state.remaining), Some(4999));
+
+    let requests = canned.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    for request in requests.iter() {
+        assert_eq!(request.uri(), "https://api.github.com/zen");
+        assert_eq!(request.headers()[http::header::AUTHORIZATION], "token secret");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_headers() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    use tower::ServiceExt;
+
+    let canned = Canned::default();
+    canned.respond(StatusCode::OK, &[], "");
+    canned.respond(StatusCode::OK, &[], "");
+    let config = Configuration {
+        user_agent: "octocat-bot".into(),
+        ..Default::default()
+    };
+    let service = ServiceBuilder::new()
+        .layer(DefaultHeadersLayer::new(&config)?)
+        .service(TransportService::new(canned.clone()));
+
+    let request = http::Request::get("https://api.github.com/zen").body(Vec::new())?;
+    service.clone().oneshot(request).await?;
+    let request = http::Request::get("https://api.github.com/zen")
+        .header(http::header::ACCEPT, "application/vnd.github.v3.raw")
+        .body(Vec::new())?;
+    service.oneshot(request).await?;
+
+    let requests = canned.requests.lock().unwrap();
+    assert_eq!(requests[0].headers()[http::header::USER_AGENT], "octocat-bot");
+    assert_eq!(requests[0].headers()[http::header::ACCEPT], "application/vnd.github.v3+json");
+    assert_eq!(requests[1].headers()[http::header::ACCEPT], "application/vnd.github.v3.raw");
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+use std::future::{ready, Ready};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use authentic::credential::TokenCredential;
+use futures_executor::block_on;
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+use github_api::caller::Caller;
+use github_api::config::{Authentication, Configuration, RetryPolicy};
+use github_api::request::issues_create;
+use github_api::transport::Transport;
+use github_api::ApiError;
+
+/// Transport returning canned responses, and recording each request.
+#[derive(Default)]
+struct Canned {
+    responses: Mutex<VecDeque<http::Response<Vec<u8>>>>,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl Canned {
+    fn new(responses: Vec<(StatusCode, &str)>) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| {
+                http::Response::builder()
+                    .status(status)
+                    .body(body.as_bytes().to_vec())
+                    .unwrap()
+            })
+            .collect();
+        Canned {
+            responses: Mutex::new(responses),
+            requests: Default::default(),
+        }
+    }
+}
+
+impl Transport for Canned {
+    type Response = http::Response<Vec<u8>>;
+    type Future = Ready<Result<Self::Response, ApiError>>;
+
+    fn send(&self, request: http::Request<Vec<u8>>) -> Self::Future {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().pop_front();
+        ready(response.ok_or_else(|| ApiError::Other("No response left".to_owned())))
+    }
+}
+
+fn no_sleep(_: Duration) -> Ready<()> {
+    ready(())
+}
+
+#[test]
+fn test_custom_transport() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transport = Canned::new(vec![(StatusCode::CREATED, r#"{"number": 1347}"#)]);
+    let requests = transport.requests.clone();
+    let config = Configuration {
+        authentication: Authentication::AccessToken(Arc::new(TokenCredential::new(b"secret".to_vec()))),
+        ..Default::default()
+    };
+    let github = Caller::new(transport, config, no_sleep);
+
+    let issue = issues_create::body::Json {
+        title: "Found a bug".into(),
+        body: None,
+        assignee: None,
+        milestone: None,
+        labels: None,
+        assignees: None,
+        additionalProperties: Default::default(),
+    };
+    let response = block_on(github.issues_create("octocat", "hello-world", &issue))?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.body(), br#"{"number": 1347}"#);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.method(), http::Method::POST);
+    assert_eq!(request.uri(), "https://api.github.com/repos/octocat/hello-world/issues");
+    assert_eq!(request.headers()[http::header::AUTHORIZATION], "token secret");
+    assert_eq!(request.headers()[http::header::CONTENT_TYPE], "application/json");
+    let body: serde_json::Value = serde_json::from_slice(request.body())?;
+    assert_eq!(body["title"], "Found a bug");
+    Ok(())
+}
+
+#[test]
+fn test_custom_transport_retry() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let transport = Canned::new(vec![
+        (StatusCode::SERVICE_UNAVAILABLE, ""),
+        (StatusCode::OK, "Keep it logically awesome."),
+    ]);
+    let requests = transport.requests.clone();
+    let config = Configuration {
+        retry_policy: Some(RetryPolicy::default()),
+        ..Default::default()
+    };
+    let github = Caller::new(transport, config, no_sleep);
+
+    let response = block_on(github.meta_get_zen())?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), b"Keep it logically awesome.");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    Ok(())
+}
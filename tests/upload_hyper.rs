@@ -0,0 +1,145 @@
+#![cfg(feature = "hyper-client")]
+
+use std::time::Duration;
+
+use http::StatusCode;
+use jinxapi_github::v1_1_4 as github_api;
+
+mod common;
+
+use common::{caller_with, serve_with_bodies};
+use github_api::config::{Configuration, RetryPolicy};
+use github_api::hyper::Asset;
+use github_api::request::repos_upload_release_asset;
+
+#[tokio::test]
+async fn test_upload_release_asset() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests) = serve_with_bodies(vec![(StatusCode::CREATED, vec![], r#"{"id": 1}"#)]);
+    let github = caller_with(Configuration {
+        base_url: "http://api.invalid".into(),
+        uploads_base_url: uploads_url.into(),
+        ..Default::default()
+    });
+
+    // Several chunks of the reader.
+    let contents: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+    let asset = Asset::new(futures_util::io::Cursor::new(contents.clone()), contents.len() as u64);
+    let response = github
+        .upload_release_asset("octocat", "hello-world", 1, "app-1.0.zip", Some("App"), asset)
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let requests = requests.lock().unwrap();
+    let request = &requests[0];
+    assert_eq!(request.method(), http::Method::POST);
+    assert_eq!(request.uri(), "/repos/octocat/hello-world/releases/1/assets?name=app-1.0.zip&label=App");
+    assert_eq!(request.headers()[http::header::CONTENT_TYPE], "application/zip");
+    assert_eq!(request.headers()[http::header::CONTENT_LENGTH], "200000");
+    assert_eq!(*request.body(), contents);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_content_type() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests) = serve_with_bodies(vec![
+        (StatusCode::CREATED, vec![], "{}"),
+        (StatusCode::CREATED, vec![], "{}"),
+    ]);
+    let github = caller_with(Configuration {
+        uploads_base_url: uploads_url.into(),
+        ..Default::default()
+    });
+
+    let asset = Asset::new(futures_util::io::Cursor::new(b"binary".to_vec()), 6);
+    github.upload_release_asset("octocat", "hello-world", 1, "app", None, asset).await?;
+    let asset = Asset::new(futures_util::io::Cursor::new(b"{}".to_vec()), 2).with_content_type("application/vnd.app+json");
+    github.upload_release_asset("octocat", "hello-world", 1, "app.json", None, asset).await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers()[http::header::CONTENT_TYPE], "application/octet-stream");
+    assert_eq!(requests[1].headers()[http::header::CONTENT_TYPE], "application/vnd.app+json");
+    Ok(())
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_upload_file() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests) = serve_with_bodies(vec![(StatusCode::CREATED, vec![], "{}")]);
+    let github = caller_with(Configuration {
+        uploads_base_url: uploads_url.into(),
+        ..Default::default()
+    });
+
+    let path = std::env::temp_dir().join(format!("upload-{}.json", std::process::id()));
+    std::fs::write(&path, br#"{"version": "1.0"}"#)?;
+    let asset = Asset::open_async(&path).await?;
+    assert_eq!(asset.len(), 18);
+    github.upload_release_asset("octocat", "hello-world", 1, "manifest", None, asset).await?;
+    std::fs::remove_file(&path)?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].headers()[http::header::CONTENT_TYPE], "application/json");
+    assert_eq!(requests[0].body(), br#"{"version": "1.0"}"#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_uploads_base_url() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests) = serve_with_bodies(vec![(StatusCode::CREATED, vec![], "{}")]);
+    let github = caller_with(Configuration {
+        base_url: "http://api.invalid".into(),
+        uploads_base_url: uploads_url.into(),
+        ..Default::default()
+    });
+
+    let content = repos_upload_release_asset::Content::new(b"notes".to_vec()).with_content_type(&b"text/plain"[..]);
+    github
+        .repos_upload_release_asset("octocat", "hello-world", 1, "NOTES.txt", None, &content)
+        .await?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].uri().path(), "/repos/octocat/hello-world/releases/1/assets");
+    assert_eq!(requests[0].body(), b"notes");
+    Ok(())
+}
+
+#[test]
+fn test_uploads_url() {
+    assert_eq!(Configuration::default().uploads_url(), "https://uploads.github.com");
+    let config = Configuration {
+        base_url: "https://github.example.com/api/v3/".into(),
+        ..Default::default()
+    };
+    assert_eq!(config.uploads_url(), "https://github.example.com/api/uploads");
+
+    // A proxy receives uploads at its API URL, unless `uploads_base_url` is set.
+    let config = Configuration {
+        base_url: "http://proxy.example.com".into(),
+        ..Default::default()
+    };
+    assert_eq!(config.uploads_url(), "http://proxy.example.com");
+}
+
+#[tokio::test]
+async fn test_upload_not_retried() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let (uploads_url, requests) = serve_with_bodies(vec![
+        (StatusCode::BAD_GATEWAY, vec![], r#"{"message": "Bad Gateway"}"#),
+        (StatusCode::CREATED, vec![], r#"{"id": 1}"#),
+    ]);
+    let github = caller_with(Configuration {
+        uploads_base_url: uploads_url.into(),
+        retry_policy: Some(RetryPolicy {
+            initial_backoff: Duration::ZERO,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let asset = Asset::new(futures_util::io::Cursor::new(b"notes".to_vec()), 5);
+    let response = github
+        .upload_release_asset("octocat", "hello-world", 1, "NOTES.txt", None, asset)
+        .await?;
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use hmac::{Hmac, Mac};
+use http::HeaderMap;
+use jinxapi_github::v1_1_4::webhooks::{WebhookError, WebhookEvent, WebhookSecret};
+use sha2::Sha256;
+
+const PUSH: &[u8] = include_bytes!("data/webhooks/push.json");
+const INSTALLATION: &[u8] = include_bytes!("data/webhooks/installation.json");
+const WORKFLOW_JOB: &[u8] = include_bytes!("data/webhooks/workflow_job.json");
+const DEPLOYMENT_STATUS: &[u8] = include_bytes!("data/webhooks/deployment_status.json");
+const STATUS: &[u8] = include_bytes!("data/webhooks/status.json");
+
+fn headers(event: &str, signature: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-github-event", event.parse().unwrap());
+    headers.insert("x-hub-signature-256", signature.parse().unwrap());
+    headers
+}
+
+fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[test]
+fn test_verify_signature() {
+    // Example from https://docs.github.com/webhooks/using-webhooks/validating-webhook-deliveries
+    let secret = WebhookSecret::new("It's a Secret to Everybody");
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    assert!(secret.verify(signature, b"Hello, World!").is_ok());
+
+    for signature in [
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e18",
+        "sha256=757107ea",
+        "sha256=not hex",
+        "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+    ] {
+        assert!(matches!(
+            secret.verify(signature, b"Hello, World!"),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+    assert!(matches!(
+        secret.verify(signature, b"Hello, World?"),
+        Err(WebhookError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn test_push_event() -> Result<(), WebhookError> {
+    let secret = WebhookSecret::new("It's a Secret to Everybody");
+    let signature = sign(b"It's a Secret to Everybody", PUSH);
+
+    let event = secret.event(&headers("push", &signature), PUSH)?;
+    assert_eq!(event.name(), "push");
+    match event {
+        WebhookEvent::Push(push) => {
+            assert_eq!(push.r#ref, "refs/heads/main");
+            assert_eq!(push.repository.full_name, "Codertocat/Hello-World");
+            assert_eq!(push.commits.len(), 1);
+            assert_eq!(push.commits[0].modified, ["README.md"]);
+            assert_eq!(push.installation.map(|installation| installation.id), Some(2311213));
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_signature() {
+    let secret = WebhookSecret::new("It's a Secret to Everybody");
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    assert!(matches!(
+        secret.event(&headers("push", signature), PUSH),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        secret.event(&HeaderMap::new(), PUSH),
+        Err(WebhookError::MissingHeader("x-hub-signature-256"))
+    ));
+}
+
+#[test]
+fn test_installation_event() -> Result<(), WebhookError> {
+    match WebhookEvent::parse("installation", INSTALLATION)? {
+        WebhookEvent::Installation(event) => {
+            assert_eq!(event.action, "deleted");
+            assert_eq!(event.installation.id, 2311213);
+            assert_eq!(event.installation.permissions.issues.as_deref(), Some("write"));
+            assert_eq!(event.repositories.unwrap()[0].full_name, "Codertocat/Hello-World");
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_workflow_job_event() -> Result<(), WebhookError> {
+    let event = WebhookEvent::parse("workflow_job", WORKFLOW_JOB)?;
+    assert_eq!(event.name(), "workflow_job");
+    match event {
+        WebhookEvent::WorkflowJob(event) => {
+            assert_eq!(event.action, "completed");
+            assert_eq!(event.workflow_job.run_id, 940463255);
+            assert_eq!(event.workflow_job.conclusion.as_deref(), Some("success"));
+            assert_eq!(event.workflow_job.steps[1].name, "Run tests");
+            assert_eq!(event.workflow_job.labels, ["ubuntu-latest"]);
+            assert!(event.deployment.is_none());
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_deployment_status_event() -> Result<(), WebhookError> {
+    match WebhookEvent::parse("deployment_status", DEPLOYMENT_STATUS)? {
+        WebhookEvent::DeploymentStatus(event) => {
+            assert_eq!(event.deployment_status.state, "success");
+            assert_eq!(event.deployment_status.environment_url.as_deref(), Some("https://lab.example.com"));
+            assert_eq!(event.deployment.id, 326191728);
+            assert_eq!(event.deployment.r#ref, "main");
+            assert_eq!(event.deployment.payload["approved"], true);
+            assert!(event.deployment.description.is_none());
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_status_event() -> Result<(), WebhookError> {
+    match WebhookEvent::parse("status", STATUS)? {
+        WebhookEvent::Status(event) => {
+            assert_eq!(event.state, "success");
+            assert_eq!(event.context, "default");
+            assert!(event.target_url.is_none());
+            assert_eq!(event.commit.sha, event.sha);
+            assert_eq!(event.branches[0].name, "main");
+            assert!(event.branches[0].protected);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_star_event() -> Result<(), WebhookError> {
+    let mut payload: serde_json::Value = serde_json::from_slice(STATUS)?;
+    let payload = serde_json::json!({
+        "action": "deleted",
+        "starred_at": null,
+        "repository": payload["repository"].take(),
+        "sender": payload["sender"].take(),
+    });
+    match WebhookEvent::parse("star", payload.to_string().as_bytes())? {
+        WebhookEvent::Star(event) => {
+            assert_eq!(event.action, "deleted");
+            assert!(event.starred_at.is_none());
+            assert_eq!(event.repository.full_name, "Codertocat/Hello-World");
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_other_event() -> Result<(), WebhookError> {
+    let event = WebhookEvent::parse("copilot_seat", br#"{"action": "assigned"}"#)?;
+    assert_eq!(event.name(), "copilot_seat");
+    match event {
+        WebhookEvent::Other { payload, .. } => assert_eq!(payload["action"], "assigned"),
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    Ok(())
+}